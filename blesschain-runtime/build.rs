@@ -1,12 +1,11 @@
 fn main() {
+    // Compiles this crate to WASM and writes `WASM_BINARY` to `OUT_DIR`; that blob
+    // is the genesis `:code`. `SKIP_WASM_BUILD=1` builds native only and leaves
+    // `WASM_BINARY` as `None`.
     #[cfg(feature = "std")]
-    {
-        let wasm_binary = std::fs::read("blesschain_runtime.compact.wasm")
-            .expect("WASM file should exist in standard builds");
-        let out_dir = std::env::var("OUT_DIR").unwrap();
-        std::fs::write(std::path::Path::new(&out_dir).join("wasm_binary.rs"), format!(
-            "pub const WASM_BINARY: Option<&[u8]> = Some(&{:?});",
-            wasm_binary
-        )).unwrap();
-    }
+    substrate_wasm_builder::WasmBuilder::new()
+        .with_current_project()
+        .export_heap_base()
+        .import_memory()
+        .build();
 }
@@ -1,16 +1,29 @@
-//! Benchmarking placeholder (required by construct_runtime).
+//! Pallets benchmarked by `blesschain-node benchmark pallet`.
 
-#![allow(dead_code)]
-
-#[cfg(feature = "runtime-benchmarks")]
-mod benchmarking {
-    use frame_benchmarking::v2::{benchmarks, BenchmarkError};
-
-    benchmarks! {
-        dummy_benchmark {
-            let x = 1;
-        }: {
-            assert_eq!(x, 1);
-        }
-    }
-}
+frame_benchmarking::define_benchmarks!(
+    [frame_benchmarking, BaselineBench::<Runtime>]
+    [frame_system, SystemBench::<Runtime>]
+    [pallet_balances, Balances]
+    [pallet_timestamp, Timestamp]
+    [pallet_validator_set, ValidatorSet]
+    [pallet_metadata, Metadata]
+    [pallet_call_filter, CallFilter]
+    [pallet_multisig, Multisig]
+    [pallet_proxy, Proxy]
+    [pallet_preimage, Preimage]
+    [pallet_scheduler, Scheduler]
+    [pallet_conviction_voting, ConvictionVoting]
+    [pallet_referenda, Referenda]
+    [pallet_treasury, Treasury]
+    [pallet_bounties, Bounties]
+    [pallet_child_bounties, ChildBounties]
+    [pallet_assets, Assets]
+    [pallet_contributor_points, ContributorPoints]
+    [pallet_homecdn, HomeCdn]
+    [pallet_storage_challenges, StorageChallenges]
+    [pallet_payment_channels, PaymentChannels]
+    [pallet_compute_jobs, ComputeJobs]
+    [pallet_randomness, RandomnessSource]
+    [pallet_fee_assets, FeeAssets]
+    [pallet_content_nfts, ContentNfts]
+);
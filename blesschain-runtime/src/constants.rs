@@ -14,3 +14,20 @@ pub const SECS_PER_BLOCK: u64 = MILLISECS_PER_BLOCK / 1000;
 /// Number of blocks per minute (approximation).
 pub const MINUTES: u64 = 60 / SECS_PER_BLOCK;
 
+/// Number of blocks per hour (approximation).
+pub const HOURS: u64 = MINUTES * 60;
+
+/// Number of blocks per day (approximation).
+pub const DAYS: u64 = HOURS * 24;
+
+/// One BBTC, the native token (12 decimals).
+pub const UNITS: u128 = 1_000_000_000_000;
+
+/// One thousandth of a BBTC.
+pub const MILLI_UNITS: u128 = UNITS / 1_000;
+
+/// Deposit for keeping `items` storage entries totalling `bytes` bytes on chain,
+/// returned when they are removed.
+pub const fn deposit(items: u32, bytes: u32) -> u128 {
+    items as u128 * 100 * MILLI_UNITS + bytes as u128 * MILLI_UNITS
+}
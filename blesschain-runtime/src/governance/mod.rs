@@ -0,0 +1,17 @@
+//! On-chain governance: referenda on per-origin tracks, decided by BBTC holders
+//! voting with conviction.
+//!
+//! A referendum asks for a call to be dispatched with some origin. The origin
+//! picks the track (see [`tracks`]); `Root` is the only way to root, replacing
+//! sudo. Custom origins ([`origins`]) cover narrower powers with faster tracks.
+
+use super::*;
+
+pub mod origins;
+pub use origins::{
+    pallet_custom_origins, EmergencyPause, ReferendumCanceller, ReferendumKiller, Spender,
+    Treasurer, ValidatorAdmin,
+};
+
+mod tracks;
+pub use tracks::TracksInfo;
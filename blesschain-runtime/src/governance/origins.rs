@@ -0,0 +1,87 @@
+//! Origins that referenda on the non-root tracks dispatch with.
+
+pub use pallet_custom_origins::*;
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use crate::{constants::UNITS, Balance};
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {}
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Adds and removes validators.
+        ValidatorAdmin,
+        /// Pauses calls and switches safe mode, on a fast track.
+        EmergencyPause,
+        /// Cancels an ongoing referendum, refunding its deposits.
+        ReferendumCanceller,
+        /// Kills an ongoing referendum, slashing its deposits.
+        ReferendumKiller,
+        /// Approves and rejects treasury proposals and unassigns bounty curators.
+        Treasurer,
+        /// Spends up to [`SMALL_SPEND`] from the treasury.
+        SmallSpender,
+        /// Spends up to [`BIG_SPEND`] from the treasury.
+        BigSpender,
+    }
+
+    /// Most a `SmallSpender` referendum can pay out or fund a bounty with.
+    pub const SMALL_SPEND: Balance = 1_000 * UNITS;
+    /// Most a `BigSpender` referendum can pay out or fund a bounty with.
+    pub const BIG_SPEND: Balance = 10_000 * UNITS;
+
+    macro_rules! decl_unit_ensures {
+        ( $name:ident ) => {
+            pub struct $name;
+            impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for $name {
+                type Success = ();
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    o.into().and_then(|o| match o {
+                        Origin::$name => Ok(()),
+                        r => Err(O::from(r)),
+                    })
+                }
+                #[cfg(feature = "runtime-benchmarks")]
+                fn try_successful_origin() -> Result<O, ()> {
+                    Ok(O::from(Origin::$name))
+                }
+            }
+        };
+        ( $name:ident, $( $rest:tt )* ) => {
+            decl_unit_ensures! { $name }
+            decl_unit_ensures! { $( $rest )* }
+        };
+        () => {}
+    }
+    decl_unit_ensures!(
+        ValidatorAdmin,
+        EmergencyPause,
+        ReferendumCanceller,
+        ReferendumKiller,
+        Treasurer,
+    );
+
+    /// Either spender origin, yielding how much it may spend.
+    pub struct Spender;
+    impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for Spender {
+        type Success = Balance;
+        fn try_origin(o: O) -> Result<Self::Success, O> {
+            o.into().and_then(|o| match o {
+                Origin::SmallSpender => Ok(SMALL_SPEND),
+                Origin::BigSpender => Ok(BIG_SPEND),
+                r => Err(O::from(r)),
+            })
+        }
+        #[cfg(feature = "runtime-benchmarks")]
+        fn try_successful_origin() -> Result<O, ()> {
+            Ok(O::from(Origin::BigSpender))
+        }
+    }
+}
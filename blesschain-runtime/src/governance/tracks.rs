@@ -0,0 +1,180 @@
+//! Referendum tracks: which origin a referendum asks for decides its deposit,
+//! timing and the approval and support it needs.
+//!
+//! Support is measured against total BBTC issuance, approval against the votes
+//! cast, both weighted by conviction.
+
+use super::*;
+use crate::constants::{DAYS, HOURS, MINUTES, UNITS};
+use pallet_referenda::Curve;
+
+const fn percent(x: i32) -> sp_runtime::FixedI64 {
+    sp_runtime::FixedI64::from_rational(x as u128, 100)
+}
+
+const APP_ROOT: Curve = Curve::make_reciprocal(4, 14, percent(80), percent(50), percent(100));
+const SUP_ROOT: Curve = Curve::make_linear(14, 14, percent(0), percent(50));
+const APP_EMERGENCY_PAUSE: Curve = Curve::make_linear(1, 1, percent(75), percent(100));
+const SUP_EMERGENCY_PAUSE: Curve = Curve::make_linear(1, 1, percent(10), percent(50));
+const APP_VALIDATOR_ADMIN: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_VALIDATOR_ADMIN: Curve =
+    Curve::make_reciprocal(5, 7, percent(1), percent(0), percent(25));
+const APP_REFERENDUM_CANCELLER: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_REFERENDUM_CANCELLER: Curve =
+    Curve::make_reciprocal(5, 7, percent(1), percent(0), percent(25));
+const APP_REFERENDUM_KILLER: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_REFERENDUM_KILLER: Curve =
+    Curve::make_reciprocal(5, 7, percent(1), percent(0), percent(25));
+const APP_TREASURER: Curve = Curve::make_reciprocal(4, 7, percent(80), percent(50), percent(100));
+const SUP_TREASURER: Curve = Curve::make_linear(7, 7, percent(0), percent(50));
+const APP_SMALL_SPENDER: Curve = Curve::make_linear(7, 7, percent(50), percent(100));
+const SUP_SMALL_SPENDER: Curve = Curve::make_reciprocal(4, 7, percent(2), percent(0), percent(25));
+const APP_BIG_SPENDER: Curve = Curve::make_linear(14, 14, percent(50), percent(100));
+const SUP_BIG_SPENDER: Curve = Curve::make_reciprocal(8, 14, percent(5), percent(0), percent(50));
+
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 8] = [
+    (
+        0,
+        pallet_referenda::TrackInfo {
+            name: "root",
+            max_deciding: 1,
+            decision_deposit: 1_000 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (14 * DAYS) as BlockNumber,
+            confirm_period: DAYS as BlockNumber,
+            min_enactment_period: DAYS as BlockNumber,
+            min_approval: APP_ROOT,
+            min_support: SUP_ROOT,
+        },
+    ),
+    (
+        1,
+        pallet_referenda::TrackInfo {
+            name: "emergency_pause",
+            max_deciding: 10,
+            decision_deposit: 100 * UNITS,
+            prepare_period: (10 * MINUTES) as BlockNumber,
+            decision_period: DAYS as BlockNumber,
+            confirm_period: (10 * MINUTES) as BlockNumber,
+            min_enactment_period: 1,
+            min_approval: APP_EMERGENCY_PAUSE,
+            min_support: SUP_EMERGENCY_PAUSE,
+        },
+    ),
+    (
+        10,
+        pallet_referenda::TrackInfo {
+            name: "validator_admin",
+            max_deciding: 10,
+            decision_deposit: 100 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (7 * DAYS) as BlockNumber,
+            confirm_period: (3 * HOURS) as BlockNumber,
+            min_enactment_period: HOURS as BlockNumber,
+            min_approval: APP_VALIDATOR_ADMIN,
+            min_support: SUP_VALIDATOR_ADMIN,
+        },
+    ),
+    (
+        11,
+        pallet_referenda::TrackInfo {
+            name: "treasurer",
+            max_deciding: 10,
+            decision_deposit: 500 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (14 * DAYS) as BlockNumber,
+            confirm_period: DAYS as BlockNumber,
+            min_enactment_period: DAYS as BlockNumber,
+            min_approval: APP_TREASURER,
+            min_support: SUP_TREASURER,
+        },
+    ),
+    (
+        20,
+        pallet_referenda::TrackInfo {
+            name: "referendum_canceller",
+            max_deciding: 100,
+            decision_deposit: 100 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (7 * DAYS) as BlockNumber,
+            confirm_period: (3 * HOURS) as BlockNumber,
+            min_enactment_period: (10 * MINUTES) as BlockNumber,
+            min_approval: APP_REFERENDUM_CANCELLER,
+            min_support: SUP_REFERENDUM_CANCELLER,
+        },
+    ),
+    (
+        21,
+        pallet_referenda::TrackInfo {
+            name: "referendum_killer",
+            max_deciding: 100,
+            decision_deposit: 500 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (7 * DAYS) as BlockNumber,
+            confirm_period: (3 * HOURS) as BlockNumber,
+            min_enactment_period: (10 * MINUTES) as BlockNumber,
+            min_approval: APP_REFERENDUM_KILLER,
+            min_support: SUP_REFERENDUM_KILLER,
+        },
+    ),
+    (
+        30,
+        pallet_referenda::TrackInfo {
+            name: "small_spender",
+            max_deciding: 50,
+            decision_deposit: 100 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (7 * DAYS) as BlockNumber,
+            confirm_period: (12 * HOURS) as BlockNumber,
+            min_enactment_period: DAYS as BlockNumber,
+            min_approval: APP_SMALL_SPENDER,
+            min_support: SUP_SMALL_SPENDER,
+        },
+    ),
+    (
+        31,
+        pallet_referenda::TrackInfo {
+            name: "big_spender",
+            max_deciding: 20,
+            decision_deposit: 500 * UNITS,
+            prepare_period: (2 * HOURS) as BlockNumber,
+            decision_period: (14 * DAYS) as BlockNumber,
+            confirm_period: (2 * DAYS) as BlockNumber,
+            min_enactment_period: DAYS as BlockNumber,
+            min_approval: APP_BIG_SPENDER,
+            min_support: SUP_BIG_SPENDER,
+        },
+    ),
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+
+    fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+        &TRACKS_DATA[..]
+    }
+
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(0),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::EmergencyPause => Ok(1),
+                origins::Origin::ValidatorAdmin => Ok(10),
+                origins::Origin::Treasurer => Ok(11),
+                origins::Origin::ReferendumCanceller => Ok(20),
+                origins::Origin::ReferendumKiller => Ok(21),
+                origins::Origin::SmallSpender => Ok(30),
+                origins::Origin::BigSpender => Ok(31),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
+pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
@@ -1,53 +1,167 @@
 //! BlessChain minimal runtime that compiles with current Substrate SDK
 #![cfg_attr(not(feature = "std"), no_std)]
+// `construct_runtime!` does a lot of recursion and requires us to increase the limit.
+#![recursion_limit = "256"]
 
-use sp_core::H256;
+// Make the WASM binary built by `build.rs` available for the node's chain spec.
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+extern crate frame_benchmarking;
+
+use sp_api::impl_runtime_apis;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H256};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_runtime::{
-    generic,
-    traits::{BlakeTwo256, IdentityLookup},
-    AccountId32,
+    create_runtime_str, generic, impl_opaque_keys,
+    traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, NumberFor, OpaqueKeys},
+    transaction_validity::{TransactionSource, TransactionValidity},
+    ApplyExtrinsicResult, Perbill, Permill, RuntimeDebug,
 };
+use sp_std::prelude::*;
+#[cfg(feature = "std")]
+use sp_version::NativeVersion;
+use sp_version::RuntimeVersion;
 use frame_support::{
-    construct_runtime, parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64, Everything},
+    construct_runtime,
+    dispatch::DispatchClass,
+    parameter_types,
+    traits::{
+        fungibles, AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Contains,
+        EitherOf, EqualPrivilegeOnly, FindAuthor, Get, InstanceFilter,
+    },
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, IdentityFee, Weight},
+    PalletId,
 };
 use frame_system as system;
+use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureSigned};
+use pallet_transaction_payment::CurrencyAdapter;
 
 // --- Declare constants module before use ---
 pub mod constants;
-use crate::constants::SLOT_DURATION;
+use crate::constants::{deposit, DAYS, HOURS, MINUTES, SLOT_DURATION, UNITS};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod governance;
+pub mod migrations;
+pub mod offchain;
+pub mod types;
+pub mod weights;
 
 // ---------------- Types ----------------
-pub type BlockNumber = u32;
-pub type Index = u32;
-pub type Balance = u128;
-pub type AccountId = AccountId32;
+pub use crate::types::{
+    AccountId, Address, Balance, Block, BlockNumber, Hash, Header, Index, Signature, SignedExtra,
+    SignedPayload, UncheckedExtrinsic,
+};
+
+/// Opaque types used by the node. They know nothing about the runtime's
+/// `RuntimeCall`, so the CLI can be generic over them.
+pub mod opaque {
+    use super::*;
+
+    pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
+
+    pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+    pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+    pub type BlockId = generic::BlockId<Block>;
+
+    impl_opaque_keys! {
+        pub struct SessionKeys {
+            pub aura: Aura,
+            pub grandpa: Grandpa,
+        }
+    }
+}
+
+// ---------------- Version ----------------
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+    spec_name: create_runtime_str!("blesschain"),
+    impl_name: create_runtime_str!("blesschain"),
+    authoring_version: 1,
+    spec_version: 2,
+    impl_version: 1,
+    apis: RUNTIME_API_VERSIONS,
+    transaction_version: 2,
+    state_version: 1,
+};
+
+/// The version information used to identify this runtime when compiled natively.
+#[cfg(feature = "std")]
+pub fn native_version() -> NativeVersion {
+    NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
+}
 
 // ---------------- System ----------------
+/// Share of the block that `Normal` extrinsics may fill; the rest is kept for
+/// `Operational` ones.
+const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+/// Share of the block `on_initialize` hooks are expected to take.
+const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(10);
+/// 2 seconds of compute per 7 second slot, so a home server on a slow uplink
+/// still has time to author, gossip and import the block within the slot.
+const MAXIMUM_BLOCK_WEIGHT: Weight =
+    Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2), u64::MAX);
+/// 5 MiB blocks.
+const MAXIMUM_BLOCK_LENGTH: u32 = 5 * 1024 * 1024;
+
+parameter_types! {
+    pub const Version: RuntimeVersion = VERSION;
+    pub RuntimeBlockWeights: BlockWeights = BlockWeights::builder()
+        .base_block(weights::BlockExecutionWeight::get())
+        .for_class(DispatchClass::all(), |weights| {
+            weights.base_extrinsic = weights::ExtrinsicBaseWeight::get();
+        })
+        .for_class(DispatchClass::Normal, |weights| {
+            weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+        })
+        .for_class(DispatchClass::Operational, |weights| {
+            weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+            // Operational transactions always fit, even in a block full of normal ones.
+            weights.reserved =
+                Some(MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+        })
+        .avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
+        .build_or_panic();
+    pub RuntimeBlockLength: BlockLength =
+        BlockLength::max_with_normal_ratio(MAXIMUM_BLOCK_LENGTH, NORMAL_DISPATCH_RATIO);
+}
+
 impl system::Config for Runtime {
-    type BaseCallFilter = Everything;
-    type BlockWeights = ();
-    type BlockLength = ();
-    type DbWeight = ();
+    /// Applies to every non-root dispatch, including calls made through `Proxy`
+    /// and `Multisig`; proxies are further limited by their `ProxyType`.
+    type BaseCallFilter = CallFilter;
+    type BlockWeights = RuntimeBlockWeights;
+    type BlockLength = RuntimeBlockLength;
+    type DbWeight = weights::RocksDbWeight;
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
     type AccountId = AccountId;
-    type Lookup = IdentityLookup<Self::AccountId>;
+    type Lookup = AccountIdLookup<AccountId, ()>;
     type Index = Index;
     type BlockNumber = BlockNumber;
     type Hash = H256;
     type Hashing = BlakeTwo256;
-    type Header = generic::Header<BlockNumber, BlakeTwo256>;
-    type Version = ();
+    type Header = Header;
+    type Version = Version;
     type PalletInfo = PalletInfo;
     type AccountData = pallet_balances::AccountData<Balance>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
-    type SystemWeightInfo = ();
+    type SystemWeightInfo = frame_system::weights::SubstrateWeight<Runtime>;
     type BlockHashCount = ConstU32<2400>;
     type MaxConsumers = ConstU32<16>;
     type SS58Prefix = ConstU16<42>;
+    /// `set_code` writes the new runtime straight to `:code`; it takes effect
+    /// from the next block. Fine for a solo chain, there is no relay to notify.
     type OnSetCode = ();
 }
 
@@ -61,7 +175,7 @@ impl pallet_balances::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
-    type WeightInfo = ();
+    type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type MaxLocks = ConstU32<10>;
     type MaxReserves = ConstU32<50>;
     type ReserveIdentifier = [u8; 8];
@@ -71,41 +185,962 @@ impl pallet_balances::Config for Runtime {
     type MaxFreezes = ConstU32<0>;
 }
 
+// ---------------- Transaction Payment ----------------
+impl pallet_transaction_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Fees and tips go to the treasury.
+    type OnChargeTransaction = CurrencyAdapter<Balances, Treasury>;
+    type OperationalFeeMultiplier = ConstU8<5>;
+    type WeightToFee = IdentityFee<Balance>;
+    type LengthToFee = IdentityFee<Balance>;
+    type FeeMultiplierUpdate = ();
+}
+
 // ---------------- Timestamp ----------------
 impl pallet_timestamp::Config for Runtime {
     type Moment = u64;
-    type OnTimestampSet = ();
+    /// Aura checks that the timestamp matches the slot announced in the block header.
+    type OnTimestampSet = Aura;
     /// Minimum period between blocks, set to half of the slot duration (3.5 seconds)
     type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
-    type WeightInfo = ();
+    type WeightInfo = pallet_timestamp::weights::SubstrateWeight<Runtime>;
 }
 
 // ---------------- Aura ----------------
-use sp_consensus_aura::sr25519;
 parameter_types! {
     pub const MaxAuthorities: u32 = 32;
 }
 impl pallet_aura::Config for Runtime {
-    type AuthorityId = sr25519::AuthorityId;
+    type AuthorityId = AuraId;
     type DisabledValidators = ();
     type MaxAuthorities = MaxAuthorities;
 }
 
+// ---------------- Grandpa ----------------
+impl pallet_grandpa::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxAuthorities = MaxAuthorities;
+    type MaxSetIdSessionEntries = ConstU64<0>;
+    // Equivocation reporting needs historical session proofs; not wired yet.
+    type KeyOwnerProof = sp_core::Void;
+    type EquivocationReportSystem = ();
+}
+
+// ---------------- Validator Set ----------------
+parameter_types! {
+    pub const MaxValidators: u32 = 32;
+    pub const MinValidators: u32 = 1;
+}
+impl pallet_validator_set::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type AddRemoveOrigin = EitherOf<EnsureRoot<AccountId>, governance::ValidatorAdmin>;
+    type MaxValidators = MaxValidators;
+    type MinValidators = MinValidators;
+    type WeightInfo = pallet_validator_set::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Metadata ----------------
+parameter_types! {
+    pub const MaxMetadataKeyLength: u32 = 64;
+    pub const MaxMetadataValueLength: u32 = 1024;
+}
+impl pallet_metadata::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxKeyLength = MaxMetadataKeyLength;
+    type MaxValueLength = MaxMetadataValueLength;
+    type WeightInfo = pallet_metadata::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Multisig ----------------
+parameter_types! {
+    /// Held from the first approver until the call executes or is cancelled.
+    pub const MultisigDepositBase: Balance = deposit(1, 88);
+    /// Additional deposit per signatory, for the stored approvals.
+    pub const MultisigDepositFactor: Balance = deposit(0, 32);
+}
+impl pallet_multisig::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type DepositBase = MultisigDepositBase;
+    type DepositFactor = MultisigDepositFactor;
+    type MaxSignatories = ConstU32<100>;
+    type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Proxy ----------------
+/// What a proxy may do on behalf of the account that registered it.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum ProxyType {
+    /// Any call.
+    Any,
+    /// Only calls that cannot move funds or content NFTs out of the account:
+    /// `System`, session keys, governance votes and proposals, HomeCDN provider
+    /// upkeep and storage proofs. Calls that wrap other calls are not
+    /// allowed, so the restriction cannot be escaped through them.
+    NonTransfer,
+    /// Validator operation only: setting and purging session keys.
+    Staking,
+}
+
+impl Default for ProxyType {
+    fn default() -> Self {
+        Self::Any
+    }
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+    fn filter(&self, c: &RuntimeCall) -> bool {
+        match self {
+            ProxyType::Any => true,
+            ProxyType::NonTransfer => matches!(
+                c,
+                RuntimeCall::System(..) |
+                    RuntimeCall::Session(..) |
+                    RuntimeCall::Preimage(..) |
+                    RuntimeCall::ConvictionVoting(..) |
+                    RuntimeCall::Referenda(..) |
+                    RuntimeCall::HomeCdn(..) |
+                    RuntimeCall::StorageChallenges(..)
+            ),
+            ProxyType::Staking => matches!(c, RuntimeCall::Session(..)),
+        }
+    }
+
+    fn is_superset(&self, o: &Self) -> bool {
+        match (self, o) {
+            (x, y) if x == y => true,
+            (ProxyType::Any, _) => true,
+            (_, ProxyType::Any) => false,
+            (ProxyType::NonTransfer, _) => true,
+            _ => false,
+        }
+    }
+}
+
+parameter_types! {
+    /// One `ProxyDefinitions` entry per delegator.
+    pub const ProxyDepositBase: Balance = deposit(1, 8);
+    /// Per proxy: account id, `ProxyType` and delay.
+    pub const ProxyDepositFactor: Balance = deposit(0, 33);
+    pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+    /// Per announcement: account id, call hash and block number.
+    pub const AnnouncementDepositFactor: Balance = deposit(0, 68);
+}
+impl pallet_proxy::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type ProxyType = ProxyType;
+    type ProxyDepositBase = ProxyDepositBase;
+    type ProxyDepositFactor = ProxyDepositFactor;
+    type MaxProxies = ConstU32<32>;
+    type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+    type MaxPending = ConstU32<32>;
+    type CallHasher = BlakeTwo256;
+    type AnnouncementDepositBase = AnnouncementDepositBase;
+    type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+// ---------------- Call Filter ----------------
+/// Calls that cannot be paused: the timestamp inherent, which every block
+/// needs, `System`, and the filter's own calls, so a pause can always be lifted.
+pub struct UnpausableCalls;
+impl Contains<RuntimeCall> for UnpausableCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::System(..) | RuntimeCall::Timestamp(..) | RuntimeCall::CallFilter(..)
+        )
+    }
+}
+
+/// Calls dispatched in safe mode besides [`UnpausableCalls`]: only governance,
+/// so the chain can still be voted out of safe mode. Nothing that wraps other
+/// calls, like `Multisig` or `Proxy`, is let through.
+pub struct SafeModeCalls;
+impl Contains<RuntimeCall> for SafeModeCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::Preimage(..) |
+                RuntimeCall::Referenda(..) |
+                RuntimeCall::ConvictionVoting(..)
+        )
+    }
+}
+
+parameter_types! {
+    pub const MaxCallNameLength: u32 = 64;
+}
+impl pallet_call_filter::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PauseOrigin = EitherOf<EnsureRoot<AccountId>, governance::EmergencyPause>;
+    type WhitelistedCalls = UnpausableCalls;
+    type SafeModeCalls = SafeModeCalls;
+    type MaxNameLength = MaxCallNameLength;
+    type WeightInfo = pallet_call_filter::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Governance ----------------
+// Root and the custom origins come from referenda, see `governance`.
+impl governance::pallet_custom_origins::Config for Runtime {}
+
+parameter_types! {
+    /// Noted preimages of proposed calls.
+    pub const PreimageBaseDeposit: Balance = deposit(2, 64);
+    pub const PreimageByteDeposit: Balance = deposit(0, 1);
+}
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type BaseDeposit = PreimageBaseDeposit;
+    type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+    /// Enacted referenda may use up to 80% of a block.
+    pub MaximumSchedulerWeight: Weight =
+        Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
+parameter_types! {
+    /// Base lock period; conviction `LockedNx` multiplies it by up to 32.
+    pub const VoteLockingPeriod: BlockNumber = (7 * DAYS) as BlockNumber;
+}
+/// Votes lock BBTC under a single balances lock per account whatever the
+/// number of tracks voted on, so it counts once against `MaxLocks`.
+impl pallet_conviction_voting::Config for Runtime {
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type VoteLockingPeriod = VoteLockingPeriod;
+    type MaxVotes = ConstU32<512>;
+    type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, AccountId>;
+    type Polls = Referenda;
+}
+
+parameter_types! {
+    pub const SubmissionDeposit: Balance = UNITS;
+    pub const UndecidingTimeout: BlockNumber = (14 * DAYS) as BlockNumber;
+}
+impl pallet_referenda::Config for Runtime {
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Currency = Balances;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type CancelOrigin = EitherOf<EnsureRoot<AccountId>, governance::ReferendumCanceller>;
+    type KillOrigin = EitherOf<EnsureRoot<AccountId>, governance::ReferendumKiller>;
+    /// Deposits of killed referenda go to the treasury.
+    type Slash = Treasury;
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type MaxQueued = ConstU32<100>;
+    type UndecidingTimeout = UndecidingTimeout;
+    type AlarmInterval = ConstU32<1>;
+    type Tracks = governance::TracksInfo;
+    type Preimages = Preimage;
+}
+
+// ---------------- Treasury ----------------
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    /// Bond of a rejected spend proposal, slashed to the treasury.
+    pub const ProposalBond: Permill = Permill::from_percent(5);
+    pub const ProposalBondMinimum: Balance = 10 * UNITS;
+    pub const ProposalBondMaximum: Option<Balance> = Some(500 * UNITS);
+    pub const SpendPeriod: BlockNumber = (7 * DAYS) as BlockNumber;
+    /// Nothing is burned: fees are the only inflow, there is no inflation.
+    pub const Burn: Permill = Permill::zero();
+    pub const MaxBalance: Balance = Balance::MAX;
+}
+impl pallet_treasury::Config for Runtime {
+    type PalletId = TreasuryPalletId;
+    type Currency = Balances;
+    type ApproveOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type RejectOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type RuntimeEvent = RuntimeEvent;
+    type OnSlash = Treasury;
+    type ProposalBond = ProposalBond;
+    type ProposalBondMinimum = ProposalBondMinimum;
+    type ProposalBondMaximum = ProposalBondMaximum;
+    type SpendPeriod = SpendPeriod;
+    type Burn = Burn;
+    type BurnDestination = ();
+    /// Approved bounties are funded from the pot each spend period, after
+    /// approved proposals.
+    type SpendFunds = Bounties;
+    type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+    type MaxApprovals = ConstU32<100>;
+    type SpendOrigin = EitherOf<EnsureRootWithSuccess<AccountId, MaxBalance>, governance::Spender>;
+}
+
+parameter_types! {
+    pub const BountyDepositBase: Balance = UNITS;
+    pub const BountyValueMinimum: Balance = 10 * UNITS;
+    /// Review window between awarding a bounty and the payout being claimable.
+    pub const BountyDepositPayoutDelay: BlockNumber = (4 * DAYS) as BlockNumber;
+    /// Curators must report progress at least this often or may be unassigned.
+    pub const BountyUpdatePeriod: BlockNumber = (90 * DAYS) as BlockNumber;
+    pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+    pub const CuratorDepositMin: Balance = UNITS;
+    pub const CuratorDepositMax: Option<Balance> = Some(200 * UNITS);
+    pub const DataDepositPerByte: Balance = deposit(0, 1);
+}
+impl pallet_bounties::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type BountyDepositBase = BountyDepositBase;
+    type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+    type BountyUpdatePeriod = BountyUpdatePeriod;
+    type CuratorDepositMultiplier = CuratorDepositMultiplier;
+    type CuratorDepositMin = CuratorDepositMin;
+    type CuratorDepositMax = CuratorDepositMax;
+    type BountyValueMinimum = BountyValueMinimum;
+    type DataDepositPerByte = DataDepositPerByte;
+    type MaximumReasonLength = ConstU32<16384>;
+    type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
+    type ChildBountyManager = ChildBounties;
+}
+
+/// Milestones of a bounty: its curator splits the value into child bounties,
+/// each with its own curator and payout.
+impl pallet_child_bounties::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxActiveChildBountyCount = ConstU32<100>;
+    type ChildBountyValueMinimum = ConstU128<UNITS>;
+    type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Contributor Points ----------------
+parameter_types! {
+    pub const ContributorPointsPalletId: PalletId = PalletId(*b"bc/point");
+    /// Revenue is shared out monthly.
+    pub const ContributorEpochLength: BlockNumber = (30 * DAYS) as BlockNumber;
+    pub const MaxAwardReasonLength: u32 = 256;
+}
+impl pallet_contributor_points::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    /// Awards are granted like treasury spends, by a Treasurer referendum.
+    type AwardOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type PalletId = ContributorPointsPalletId;
+    type EpochLength = ContributorEpochLength;
+    type MaxReasonLength = MaxAwardReasonLength;
+    type WeightInfo = pallet_contributor_points::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- HomeCDN ----------------
+parameter_types! {
+    pub const HomeCdnMinBond: Balance = 100 * UNITS;
+    /// Long enough for storage challenges issued before retiring to resolve.
+    pub const HomeCdnRetirementPeriod: BlockNumber = (7 * DAYS) as BlockNumber;
+}
+impl pallet_homecdn::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type OnSlash = Treasury;
+    type SlashOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type MinBond = HomeCdnMinBond;
+    type RetirementPeriod = HomeCdnRetirementPeriod;
+    type MaxProviders = ConstU32<10_000>;
+    type MaxRegionLength = ConstU32<32>;
+    type MaxEndpointLength = ConstU32<256>;
+    type WeightInfo = pallet_homecdn::weights::SubstrateWeight<Runtime>;
+}
+
+/// A provider's listing in the shape `HomeCdnApi` returns it.
+fn homecdn_listing(
+    provider: AccountId,
+    info: pallet_homecdn::ProviderOf<Runtime>,
+) -> pallet_homecdn_runtime_api::ProviderListing<AccountId, Balance> {
+    pallet_homecdn_runtime_api::ProviderListing {
+        active: HomeCdn::is_routable(&info),
+        provider,
+        capacity_gib: info.listing.capacity_gib,
+        bandwidth_mbps: info.listing.bandwidth_mbps,
+        region: info.listing.region.into_inner(),
+        endpoint: info.listing.endpoint.into_inner(),
+        bond: info.bond,
+    }
+}
+
+// ---------------- Randomness ----------------
+/// The Aura key of the current block's author, which signs its VRF output.
+pub struct AuraAuthorKey;
+impl FindAuthor<sp_core::sr25519::Public> for AuraAuthorKey {
+    fn find_author<'a, I>(digests: I) -> Option<sp_core::sr25519::Public>
+    where
+        I: 'a + IntoIterator<Item = (sp_runtime::ConsensusEngineId, &'a [u8])>,
+    {
+        let index = <Aura as FindAuthor<u32>>::find_author(digests)?;
+        Aura::authorities().get(index as usize).map(|authority| authority.clone().into())
+    }
+}
+
+impl pallet_randomness::Config for Runtime {
+    type FindAuthor = AuraAuthorKey;
+    type WeightInfo = pallet_randomness::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Storage Challenges ----------------
+parameter_types! {
+    pub const ChallengeInterval: BlockNumber = (10 * MINUTES) as BlockNumber;
+    pub const ChallengeResponsePeriod: BlockNumber = (30 * MINUTES) as BlockNumber;
+    pub const ChallengeFailureSlash: Balance = 10 * UNITS;
+}
+impl pallet_storage_challenges::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type Providers = HomeCdn;
+    type Randomness = RandomnessSource;
+    type ChallengeOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type ChallengeInterval = ChallengeInterval;
+    type AutoChallenges = ConstU32<4>;
+    type ChunksPerChallenge = ConstU32<4>;
+    type ResponsePeriod = ChallengeResponsePeriod;
+    type MaxChallengesPerBlock = ConstU32<64>;
+    /// 4 KiB chunks keep a full response under 20 KiB.
+    type MaxChunkSize = ConstU32<4096>;
+    type MaxProofDepth = ConstU32<32>;
+    type ReputationReward = ConstU8<1>;
+    type ReputationPenalty = ConstU8<10>;
+    type SlashThreshold = ConstU8<50>;
+    type FailureSlash = ChallengeFailureSlash;
+    type WeightInfo = pallet_storage_challenges::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Payment Channels ----------------
+parameter_types! {
+    pub const ChannelMinDeposit: Balance = UNITS;
+    /// A day for the recipient to redeem its latest voucher after the sender
+    /// asks to close.
+    pub const ChannelChallengePeriod: BlockNumber = DAYS as BlockNumber;
+}
+impl pallet_payment_channels::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as sp_runtime::traits::Verify>::Signer;
+    type MinDeposit = ChannelMinDeposit;
+    type ChallengePeriod = ChannelChallengePeriod;
+    type WeightInfo = pallet_payment_channels::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = PaymentChannelsBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PaymentChannelsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_payment_channels::BenchmarkHelper<sp_runtime::MultiSigner, AccountId, Signature>
+    for PaymentChannelsBenchmarkHelper
+{
+    fn signer() -> (sp_runtime::MultiSigner, AccountId) {
+        use sp_runtime::traits::IdentifyAccount;
+        let public: sp_runtime::MultiSigner =
+            sp_io::crypto::sr25519_generate(0.into(), None).into();
+        (public.clone(), public.into_account())
+    }
+
+    fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> Signature {
+        let sp_runtime::MultiSigner::Sr25519(public) = signer else {
+            panic!("benchmark signers are sr25519")
+        };
+        sp_io::crypto::sr25519_sign(0.into(), public, message)
+            .expect("the key was generated in the keystore")
+            .into()
+    }
+}
+
+// ---------------- Compute Jobs ----------------
+parameter_types! {
+    pub const ComputeMinReward: Balance = UNITS;
+    pub const ComputeProviderDeposit: Balance = 10 * UNITS;
+    pub const ComputeMaxJobDuration: BlockNumber = (7 * DAYS) as BlockNumber;
+    pub const ComputeReviewPeriod: BlockNumber = DAYS as BlockNumber;
+}
+impl pallet_compute_jobs::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Providers = HomeCdn;
+    type ArbitrationOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type MinReward = ComputeMinReward;
+    type ProviderDeposit = ComputeProviderDeposit;
+    type MaxJobDuration = ComputeMaxJobDuration;
+    type ReviewPeriod = ComputeReviewPeriod;
+    type MaxBids = ConstU32<32>;
+    type WeightInfo = pallet_compute_jobs::weights::SubstrateWeight<Runtime>;
+}
+
+// ---------------- Assets ----------------
+pub type AssetId = u32;
+
+parameter_types! {
+    /// Reserved from the creator until the asset is destroyed.
+    pub const AssetDeposit: Balance = 100 * UNITS;
+    /// Per holder of an asset that is not sufficient.
+    pub const AssetAccountDeposit: Balance = deposit(1, 16);
+    pub const AssetMetadataDepositBase: Balance = deposit(1, 68);
+    pub const AssetMetadataDepositPerByte: Balance = deposit(0, 1);
+    /// Per approval to spend on the holder's behalf.
+    pub const AssetApprovalDeposit: Balance = deposit(1, 32);
+}
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = AssetId;
+    type AssetIdParameter = codec::Compact<AssetId>;
+    type Currency = Balances;
+    /// Anyone may create an asset, becoming its owner, admin, issuer and freezer.
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    /// Creates sufficient assets, which accounts may hold without BBTC, and
+    /// overrides owners and roles.
+    type ForceOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = AssetMetadataDepositBase;
+    type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+    type ApprovalDeposit = AssetApprovalDeposit;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+// ---------------- Asset Fees ----------------
+/// Hands fees paid in an asset to the treasury. A fee the treasury cannot
+/// take, being below the asset's minimum balance for a new holder, is burned.
+pub struct AssetFeesToTreasury;
+impl pallet_asset_tx_payment::HandleCredit<AccountId, Assets> for AssetFeesToTreasury {
+    fn handle_credit(credit: fungibles::Credit<AccountId, Assets>) {
+        let _ = <Assets as fungibles::Balanced<AccountId>>::resolve(&Treasury::account_id(), credit);
+    }
+}
+
+/// Transactions choose their fee asset in `ChargeAssetTxPayment`; without one
+/// they pay in BBTC through `pallet_transaction_payment`.
+impl pallet_asset_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Fungibles = Assets;
+    type OnChargeAssetTransaction =
+        pallet_asset_tx_payment::FungiblesAdapter<FeeAssets, AssetFeesToTreasury>;
+}
+
+impl pallet_fee_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Assets = Assets;
+    type ApproveOrigin = EitherOf<EnsureRoot<AccountId>, governance::Treasurer>;
+    type WeightInfo = pallet_fee_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = FeeAssetsBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FeeAssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_fee_assets::BenchmarkHelper<AssetId> for FeeAssetsBenchmarkHelper {
+    fn create_asset() -> AssetId {
+        let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+        Assets::force_create(RuntimeOrigin::root(), 1.into(), owner.into(), true, 1)
+            .expect("root creates assets");
+        1
+    }
+}
+
+// ---------------- Content NFTs ----------------
+parameter_types! {
+    /// The collection: owner, deposit, royalty, recipient and item counters.
+    pub const NftCollectionDeposit: Balance = deposit(1, 92);
+    /// The item (owner, content hash, license, deposit and price) and its
+    /// content index entry.
+    pub const NftItemDeposit: Balance = deposit(2, 173);
+    pub const NftMaxRoyalty: Perbill = Perbill::from_percent(25);
+}
+impl pallet_content_nfts::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type CollectionDeposit = NftCollectionDeposit;
+    type ItemDeposit = NftItemDeposit;
+    type MaxRoyalty = NftMaxRoyalty;
+    type WeightInfo = pallet_content_nfts::weights::SubstrateWeight<Runtime>;
+}
+
+/// An item in the shape `ContentNftsApi` returns it.
+fn content_nft(
+    collection: pallet_content_nfts::CollectionId,
+    item: pallet_content_nfts::ItemId,
+) -> Option<pallet_content_nfts_runtime_api::ItemDetails<AccountId, Balance>> {
+    let info = ContentNfts::item(collection, item)?;
+    let royalty = ContentNfts::collection(collection)?;
+    Some(pallet_content_nfts_runtime_api::ItemDetails {
+        collection,
+        item,
+        owner: info.owner,
+        content_hash: info.content_hash,
+        license: content_license(info.license),
+        price: info.price,
+        royalty: royalty.royalty,
+        royalty_recipient: royalty.royalty_recipient,
+    })
+}
+
+fn content_license(
+    license: pallet_content_nfts::License,
+) -> pallet_content_nfts_runtime_api::LicenseTerms {
+    pallet_content_nfts_runtime_api::LicenseTerms {
+        commercial_use: license.commercial_use,
+        derivatives: license.derivatives,
+        attribution: license.attribution,
+        terms: license.terms,
+    }
+}
+
+// ---------------- Session ----------------
+parameter_types! {
+    /// Authority set changes are applied once per session (one hour).
+    pub const SessionPeriod: BlockNumber = HOURS as BlockNumber;
+    pub const SessionOffset: BlockNumber = 0;
+}
+impl pallet_session::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type SessionManager = ValidatorSet;
+    type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+    type Keys = opaque::SessionKeys;
+    type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+}
+
 // ---------------- Construct Runtime ----------------
 construct_runtime!(
     pub enum Runtime where
         Block = Block,
-        NodeBlock = Block,
+        NodeBlock = opaque::Block,
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system,
-        Balances: pallet_balances,
         Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        TransactionPayment: pallet_transaction_payment,
+        // `ValidatorSet` must come before `Session` so its genesis is built first.
+        ValidatorSet: pallet_validator_set,
+        Session: pallet_session,
         Aura: pallet_aura,
+        Grandpa: pallet_grandpa,
+        Metadata: pallet_metadata,
+        Multisig: pallet_multisig,
+        Proxy: pallet_proxy,
+        CallFilter: pallet_call_filter,
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler,
+        ConvictionVoting: pallet_conviction_voting,
+        Referenda: pallet_referenda,
+        Origins: governance::pallet_custom_origins::{Origin},
+        Treasury: pallet_treasury,
+        Bounties: pallet_bounties,
+        ChildBounties: pallet_child_bounties,
+        ContributorPoints: pallet_contributor_points,
+        HomeCdn: pallet_homecdn,
+        StorageChallenges: pallet_storage_challenges,
+        PaymentChannels: pallet_payment_channels,
+        ComputeJobs: pallet_compute_jobs,
+        RandomnessSource: pallet_randomness,
+        Assets: pallet_assets,
+        AssetTxPayment: pallet_asset_tx_payment,
+        FeeAssets: pallet_fee_assets,
+        ContentNfts: pallet_content_nfts,
     }
 );
 
-// ---------------- Mock types for minimal chain ----------------
-pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
-pub type Block = frame_system::mocking::MockBlock<Runtime>;
+// ---------------- Executive ----------------
+pub type Executive = frame_executive::Executive<
+    Runtime,
+    Block,
+    frame_system::ChainContext<Runtime>,
+    Runtime,
+    AllPalletsWithSystem,
+    migrations::Unreleased,
+>;
+
+// ---------------- Runtime APIs ----------------
+impl_runtime_apis! {
+    impl sp_api::Core<Block> for Runtime {
+        fn version() -> RuntimeVersion {
+            VERSION
+        }
+
+        fn execute_block(block: Block) {
+            Executive::execute_block(block);
+        }
+
+        fn initialize_block(header: &<Block as BlockT>::Header) {
+            Executive::initialize_block(header)
+        }
+    }
+
+    impl sp_api::Metadata<Block> for Runtime {
+        fn metadata() -> OpaqueMetadata {
+            OpaqueMetadata::new(Runtime::metadata().into())
+        }
+
+        fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+            Runtime::metadata_at_version(version)
+        }
+
+        fn metadata_versions() -> Vec<u32> {
+            Runtime::metadata_versions()
+        }
+    }
+
+    impl sp_block_builder::BlockBuilder<Block> for Runtime {
+        fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
+            Executive::apply_extrinsic(extrinsic)
+        }
+
+        fn finalize_block() -> <Block as BlockT>::Header {
+            Executive::finalize_block()
+        }
+
+        fn inherent_extrinsics(data: sp_inherents::InherentData) -> Vec<<Block as BlockT>::Extrinsic> {
+            data.create_extrinsics()
+        }
+
+        fn check_inherents(
+            block: Block,
+            data: sp_inherents::InherentData,
+        ) -> sp_inherents::CheckInherentsResult {
+            data.check_extrinsics(&block)
+        }
+    }
+
+    impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
+        fn validate_transaction(
+            source: TransactionSource,
+            tx: <Block as BlockT>::Extrinsic,
+            block_hash: <Block as BlockT>::Hash,
+        ) -> TransactionValidity {
+            Executive::validate_transaction(source, tx, block_hash)
+        }
+    }
+
+    impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
+        fn offchain_worker(header: &<Block as BlockT>::Header) {
+            Executive::offchain_worker(header)
+        }
+    }
+
+    impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+        fn slot_duration() -> sp_consensus_aura::SlotDuration {
+            sp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
+        }
+
+        fn authorities() -> Vec<AuraId> {
+            Aura::authorities().into_inner()
+        }
+    }
+
+    impl sp_session::SessionKeys<Block> for Runtime {
+        fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
+            opaque::SessionKeys::generate(seed)
+        }
+
+        fn decode_session_keys(encoded: Vec<u8>) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
+            opaque::SessionKeys::decode_into_raw_public_keys(&encoded)
+        }
+    }
+
+    impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
+        fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
+            Grandpa::grandpa_authorities()
+        }
+
+        fn current_set_id() -> sp_consensus_grandpa::SetId {
+            Grandpa::current_set_id()
+        }
+
+        fn submit_report_equivocation_unsigned_extrinsic(
+            _equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+                <Block as BlockT>::Hash,
+                NumberFor<Block>,
+            >,
+            _key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+        ) -> Option<()> {
+            None
+        }
+
+        fn generate_key_ownership_proof(
+            _set_id: sp_consensus_grandpa::SetId,
+            _authority_id: GrandpaId,
+        ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+            // Equivocation reporting is disabled, see `KeyOwnerProof` above.
+            None
+        }
+    }
+
+    impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index> for Runtime {
+        fn account_nonce(account: AccountId) -> Index {
+            System::account_nonce(account)
+        }
+    }
+
+    impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
+        fn query_info(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
+            TransactionPayment::query_info(uxt, len)
+        }
+
+        fn query_fee_details(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment::FeeDetails<Balance> {
+            TransactionPayment::query_fee_details(uxt, len)
+        }
+
+        fn query_weight_to_fee(weight: Weight) -> Balance {
+            TransactionPayment::weight_to_fee(weight)
+        }
+
+        fn query_length_to_fee(length: u32) -> Balance {
+            TransactionPayment::length_to_fee(length)
+        }
+    }
+
+    impl pallet_call_filter_runtime_api::CallFilterApi<Block> for Runtime {
+        fn paused_calls() -> Vec<(Vec<u8>, Option<Vec<u8>>)> {
+            CallFilter::paused_calls()
+        }
+
+        fn safe_mode() -> bool {
+            CallFilter::safe_mode()
+        }
+    }
+
+    impl pallet_homecdn_runtime_api::HomeCdnApi<Block, AccountId, Balance> for Runtime {
+        fn active_providers(
+            region: Option<Vec<u8>>,
+        ) -> Vec<pallet_homecdn_runtime_api::ProviderListing<AccountId, Balance>> {
+            HomeCdn::active_providers(region)
+                .into_iter()
+                .map(|(provider, info)| homecdn_listing(provider, info))
+                .collect()
+        }
+
+        fn provider(
+            who: AccountId,
+        ) -> Option<pallet_homecdn_runtime_api::ProviderListing<AccountId, Balance>> {
+            HomeCdn::provider(&who).map(|info| homecdn_listing(who, info))
+        }
+    }
+
+    impl pallet_content_nfts_runtime_api::ContentNftsApi<Block, AccountId, Balance> for Runtime {
+        fn item(
+            collection: u32,
+            item: u32,
+        ) -> Option<pallet_content_nfts_runtime_api::ItemDetails<AccountId, Balance>> {
+            content_nft(collection, item)
+        }
+
+        fn item_by_content(
+            content_hash: H256,
+        ) -> Option<pallet_content_nfts_runtime_api::ItemDetails<AccountId, Balance>> {
+            let (collection, item) = ContentNfts::content_item(content_hash)?;
+            content_nft(collection, item)
+        }
 
+        fn license(
+            collection: u32,
+            item: u32,
+        ) -> Option<pallet_content_nfts_runtime_api::LicenseTerms> {
+            ContentNfts::item(collection, item).map(|details| content_license(details.license))
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use baseline::Pallet as BaselineBench;
+            use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
+            use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+
+            (list, storage_info)
+        }
+
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use baseline::Pallet as BaselineBench;
+            use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch, TrackedStorageKey};
+            use frame_support::traits::WhitelistedStorageKeys;
+            use frame_system_benchmarking::Pallet as SystemBench;
+
+            impl frame_system_benchmarking::Config for Runtime {}
+            impl baseline::Config for Runtime {}
+
+            let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    impl frame_try_runtime::TryRuntime<Block> for Runtime {
+        fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
+            // Panic on failure: try-runtime reports it and we want the backtrace.
+            let weight = Executive::try_runtime_upgrade(checks).unwrap();
+            (weight, RuntimeBlockWeights::get().max_block)
+        }
+
+        fn execute_block(
+            block: Block,
+            state_root_check: bool,
+            signature_check: bool,
+            select: frame_try_runtime::TryStateSelect,
+        ) -> Weight {
+            Executive::try_execute_block(block, state_root_check, signature_check, select)
+                .expect("execute-block failed")
+        }
+    }
+}
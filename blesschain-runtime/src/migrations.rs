@@ -0,0 +1,16 @@
+//! Storage migrations run by `Executive` on the first block of a new runtime.
+//!
+//! Migrations are executed in tuple order and must check the pallet's on-chain
+//! `StorageVersion` themselves, so they stay harmless if a release is applied on
+//! a chain that already ran them. Once every network has upgraded past a release,
+//! its migrations can be dropped from [`Unreleased`].
+//!
+//! Check them against live state before proposing `set_code`:
+//!
+//! ```text
+//! cargo build --release -p blesschain-node --features try-runtime
+//! blesschain-node try-runtime --runtime <new runtime wasm> on-runtime-upgrade live --uri ws://127.0.0.1:9944
+//! ```
+
+/// Migrations not yet applied on every BlessChain network, oldest first.
+pub type Unreleased = ();
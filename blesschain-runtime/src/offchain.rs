@@ -0,0 +1,96 @@
+//! Off-chain worker support.
+//!
+//! The node runs every pallet's `offchain_worker` hook after importing a block
+//! (through `OffchainWorkerApi`), with local storage kept in the node database
+//! and HTTP access to the outside world. Workers report back to the chain with
+//! signed transactions, signed by the node's [`KEY_TYPE`] key: insert one with
+//! `author_insertKey`, or start a dev node with `--alice` and friends, which
+//! insert the dev key.
+
+use super::*;
+use frame_system::offchain::{
+    AppCrypto, CreateSignedTransaction, SendTransactionTypes, SigningTypes,
+};
+use sp_runtime::{
+    generic::Era,
+    offchain::{http, Duration},
+    traits::{Extrinsic as ExtrinsicT, Verify},
+    MultiSignature, MultiSigner, SaturatedConversion,
+};
+
+/// Key type of the keys off-chain workers sign transactions with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"bocw");
+
+/// Blocks an off-chain worker transaction stays valid for.
+pub const TRANSACTION_ERA_PERIOD: u64 = 64;
+
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::app_crypto::{app_crypto, sr25519};
+
+    app_crypto!(sr25519, KEY_TYPE);
+}
+
+/// Signs off-chain worker transactions with the node's [`KEY_TYPE`] key.
+pub struct OffchainAuthId;
+impl AppCrypto<MultiSigner, MultiSignature> for OffchainAuthId {
+    type RuntimeAppPublic = crypto::Public;
+    type GenericSignature = sp_core::sr25519::Signature;
+    type GenericPublic = sp_core::sr25519::Public;
+}
+
+impl SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    /// Sign `call` like a wallet would: mortal, tipless, with the same
+    /// `SignedExtra` as any other transaction.
+    fn create_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Index,
+    ) -> Option<(RuntimeCall, <UncheckedExtrinsic as ExtrinsicT>::SignaturePayload)> {
+        let current = System::block_number().saturated_into::<u64>().saturating_sub(1);
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(Era::mortal(TRANSACTION_ERA_PERIOD, current)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
+        );
+        let payload = SignedPayload::new(call, extra).ok()?;
+        let signature = payload.using_encoded(|payload| C::sign(payload, public))?;
+        let (call, extra, _) = payload.deconstruct();
+        Some((call, (Address::Id(account), signature, extra)))
+    }
+}
+
+/// GET `url` from an off-chain worker and return the body of a `200 OK`
+/// response, giving up after `timeout`.
+pub fn http_get(url: &str, timeout: Duration) -> Result<Vec<u8>, http::Error> {
+    let deadline = sp_io::offchain::timestamp().add(timeout);
+    let pending =
+        http::Request::get(url).deadline(deadline).send().map_err(|_| http::Error::IoError)?;
+    let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+    if response.code != 200 {
+        return Err(http::Error::Unknown)
+    }
+    Ok(response.body().collect())
+}
@@ -1,7 +0,0 @@
-use super::*;
-use frame_support::{assert_ok, assert_noop};
-
-#[test]
-fn it_works() {
-    assert_eq!(2 + 2, 4);
-}
@@ -1,20 +1,22 @@
-// #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
 // Runtime types used across BlessChain.
 
-use sp_runtime::MultiSignature;
-use codec::{Decode, Encode};
-use scale_info::TypeInfo;
+use sp_runtime::{generic, traits::BlakeTwo256, MultiAddress, MultiSignature};
 use sp_core::crypto::AccountId32;
-use sp_std::prelude::*;
-use frame_support::dispatch::Dispatchable;
 use frame_system::{
     CheckNonZeroSender, CheckSpecVersion, CheckTxVersion, CheckGenesis,
     CheckEra, CheckNonce, CheckWeight,
 };
-use pallet_transaction_payment::ChargeTransactionPayment;
+use pallet_asset_tx_payment::ChargeAssetTxPayment;
 
 pub type Signature = MultiSignature;
 pub type AccountId = AccountId32;
+pub type Address = MultiAddress<AccountId, ()>;
+pub type BlockNumber = u32;
+pub type Index = u32;
+pub type Balance = u128;
+pub type Hash = sp_core::H256;
+
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
 
 pub type SignedExtra = (
     CheckNonZeroSender<crate::Runtime>,
@@ -24,5 +26,10 @@ pub type SignedExtra = (
     CheckEra<crate::Runtime>,
     CheckNonce<crate::Runtime>,
     CheckWeight<crate::Runtime>,
-    ChargeTransactionPayment<crate::Runtime>,
+    ChargeAssetTxPayment<crate::Runtime>,
 );
+
+pub type UncheckedExtrinsic =
+    generic::UncheckedExtrinsic<Address, crate::RuntimeCall, Signature, SignedExtra>;
+pub type SignedPayload = generic::SignedPayload<crate::RuntimeCall, SignedExtra>;
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
@@ -1,6 +1,15 @@
-//! Minimal weight file for BlessChain runtime
-#[allow(unused_imports)]
-#[allow(dead_code)]
+//! The block, extrinsic and database constants behind `RuntimeBlockWeights` and
+//! `DbWeight`.
+//!
+//! FRAME pallets are charged with their upstream `SubstrateWeight`s, our own
+//! pallets with the `SubstrateWeight` in `pallets/*/src/weights.rs`. The
+//! numbers in those are placeholders until `scripts/benchmark-weights.sh` is
+//! run on the reference machine.
 
-pub mod constants;
-pub use constants::time::*;
+pub mod block_weights;
+pub mod extrinsic_weights;
+pub mod rocksdb_weights;
+
+pub use block_weights::constants::BlockExecutionWeight;
+pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
+pub use rocksdb_weights::constants::RocksDbWeight;
@@ -0,0 +1,236 @@
+//! Shared operator wallets: multisig approvals and proxies.
+
+mod common;
+
+use blesschain_runtime::{
+    AccountId, Balances, Multisig, ProxyType, Runtime, RuntimeCall, RuntimeEvent,
+};
+use codec::Encode;
+use common::*;
+use frame_support::dispatch::GetDispatchInfo;
+use sp_core::H256;
+use sp_keyring::Sr25519Keyring::{self, Alice, Bob, Charlie, Dave};
+
+const AMOUNT: u128 = 10 * blesschain_runtime::constants::UNITS;
+
+fn transfer(to: AccountId, value: u128) -> RuntimeCall {
+    pallet_balances::Call::transfer_keep_alive { dest: to.into(), value }.into()
+}
+
+fn remark() -> RuntimeCall {
+    frame_system::Call::remark { remark: b"hello".to_vec() }.into()
+}
+
+/// Alice, Bob and Charlie, sorted as the multisig pallet requires.
+fn signatories() -> Vec<AccountId> {
+    let mut all = vec![account(Alice), account(Bob), account(Charlie)];
+    all.sort();
+    all
+}
+
+fn others(who: Sr25519Keyring) -> Vec<AccountId> {
+    signatories().into_iter().filter(|a| a != &account(who)).collect()
+}
+
+fn as_multi(
+    who: Sr25519Keyring,
+    call: RuntimeCall,
+    maybe_timepoint: Option<pallet_multisig::Timepoint<u32>>,
+) -> RuntimeCall {
+    pallet_multisig::Call::as_multi {
+        threshold: 2,
+        other_signatories: others(who),
+        maybe_timepoint,
+        max_weight: call.get_dispatch_info().weight,
+        call: Box::new(call),
+    }
+    .into()
+}
+
+fn proxy(real: Sr25519Keyring, call: RuntimeCall) -> RuntimeCall {
+    pallet_proxy::Call::proxy {
+        real: account(real).into(),
+        force_proxy_type: None,
+        call: Box::new(call),
+    }
+    .into()
+}
+
+fn add_proxy(delegate: Sr25519Keyring, proxy_type: ProxyType) -> RuntimeCall {
+    pallet_proxy::Call::add_proxy { delegate: account(delegate).into(), proxy_type, delay: 0 }
+        .into()
+}
+
+fn last_proxy_result() -> Option<sp_runtime::DispatchResult> {
+    events().into_iter().rev().find_map(|e| match e {
+        RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result }) => Some(result),
+        _ => None,
+    })
+}
+
+#[test]
+fn two_of_three_multisig_transfers_after_second_approval() {
+    new_test_ext().execute_with(|| {
+        let multi = Multisig::multi_account_id(&signatories(), 2);
+        assert_eq!(apply(sign(Alice, transfer(multi.clone(), 5 * AMOUNT), 0)), Ok(Ok(())));
+
+        let call = transfer(account(Dave), AMOUNT);
+        let call_hash = sp_io::hashing::blake2_256(&call.encode());
+        assert_eq!(apply(sign(Alice, as_multi(Alice, call.clone(), None), 1)), Ok(Ok(())));
+
+        // One approval: the deposit is held and nothing moves yet.
+        let pending = pallet_multisig::Multisigs::<Runtime>::get(&multi, call_hash).unwrap();
+        assert_eq!(Balances::reserved_balance(account(Alice)), pending.deposit);
+        assert_eq!(Balances::free_balance(account(Dave)), 0);
+
+        assert_eq!(apply(sign(Bob, as_multi(Bob, call, Some(pending.when)), 0)), Ok(Ok(())));
+
+        assert!(events().iter().any(|e| matches!(
+            e,
+            RuntimeEvent::Multisig(pallet_multisig::Event::MultisigExecuted { result: Ok(()), .. })
+        )));
+        assert_eq!(Balances::free_balance(account(Dave)), AMOUNT);
+        assert_eq!(Balances::free_balance(&multi), 4 * AMOUNT);
+        assert_eq!(Balances::reserved_balance(account(Alice)), 0);
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_move_funds() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(apply(sign(Alice, add_proxy(Bob, ProxyType::NonTransfer), 0)), Ok(Ok(())));
+
+        assert_eq!(apply(sign(Bob, proxy(Alice, transfer(account(Dave), AMOUNT)), 0)), Ok(Ok(())));
+        assert_eq!(
+            last_proxy_result(),
+            Some(Err(frame_system::Error::<Runtime>::CallFiltered.into())),
+        );
+        assert_eq!(Balances::free_balance(account(Dave)), 0);
+
+        assert_eq!(apply(sign(Bob, proxy(Alice, remark()), 1)), Ok(Ok(())));
+        assert_eq!(last_proxy_result(), Some(Ok(())));
+    });
+}
+
+/// Bob, Alice's `NonTransfer` proxy, has `call` filtered out and Alice keeps
+/// her funds.
+fn assert_non_transfer_proxy_filters(call: RuntimeCall) {
+    assert_eq!(apply(sign(Alice, add_proxy(Bob, ProxyType::NonTransfer), 0)), Ok(Ok(())));
+    let alice = Balances::free_balance(account(Alice));
+
+    assert_eq!(apply(sign(Bob, proxy(Alice, call), 0)), Ok(Ok(())));
+    assert_eq!(last_proxy_result(), Some(Err(frame_system::Error::<Runtime>::CallFiltered.into())));
+    assert_eq!(Balances::free_balance(account(Alice)), alice);
+}
+
+#[test]
+fn non_transfer_proxy_cannot_post_compute_jobs() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_compute_jobs::Call::post {
+                spec: H256::repeat_byte(1),
+                resources: Default::default(),
+                reward: AMOUNT,
+                timeout: 100,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_open_payment_channels() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_payment_channels::Call::open { recipient: account(Dave), deposit: AMOUNT }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_deposit_contributor_revenue() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_contributor_points::Call::deposit_revenue { amount: AMOUNT }.into(),
+        );
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_propose_treasury_spends() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_treasury::Call::propose_spend {
+                value: AMOUNT,
+                beneficiary: account(Dave).into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_propose_bounties() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_bounties::Call::propose_bounty { value: AMOUNT, description: b"cdn".to_vec() }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_transfer_assets() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_assets::Call::transfer { id: 1.into(), target: account(Dave).into(), amount: 1 }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn non_transfer_proxy_cannot_transfer_content_nfts() {
+    new_test_ext().execute_with(|| {
+        assert_non_transfer_proxy_filters(
+            pallet_content_nfts::Call::transfer { collection: 0, item: 0, dest: account(Dave) }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn any_proxy_acts_fully_for_its_delegator() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(apply(sign(Alice, add_proxy(Bob, ProxyType::Any), 0)), Ok(Ok(())));
+
+        assert_eq!(apply(sign(Bob, proxy(Alice, transfer(account(Dave), AMOUNT)), 0)), Ok(Ok(())));
+
+        assert_eq!(last_proxy_result(), Some(Ok(())));
+        assert_eq!(Balances::free_balance(account(Dave)), AMOUNT);
+    });
+}
+
+#[test]
+fn staking_proxy_is_limited_to_session_keys() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(apply(sign(Alice, add_proxy(Bob, ProxyType::Staking), 0)), Ok(Ok(())));
+
+        assert_eq!(apply(sign(Bob, proxy(Alice, remark()), 0)), Ok(Ok(())));
+        assert_eq!(
+            last_proxy_result(),
+            Some(Err(frame_system::Error::<Runtime>::CallFiltered.into())),
+        );
+
+        // A restricted proxy cannot hand out broader permissions.
+        assert_eq!(
+            apply(sign(Bob, proxy(Alice, add_proxy(Charlie, ProxyType::Any)), 1)),
+            Ok(Ok(())),
+        );
+        assert_eq!(
+            last_proxy_result(),
+            Some(Err(frame_system::Error::<Runtime>::CallFiltered.into())),
+        );
+    });
+}
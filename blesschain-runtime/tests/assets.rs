@@ -0,0 +1,124 @@
+//! Ecosystem assets on the assembled runtime: deposits, roles and metadata,
+//! and transaction fees paid in an approved asset.
+
+mod common;
+
+use blesschain_runtime::{
+    constants::UNITS, AssetDeposit, AssetId, AssetMetadataDepositBase, AssetMetadataDepositPerByte,
+    Assets, Balances, FeeAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Treasury,
+};
+use codec::Encode;
+use common::*;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use pallet_asset_tx_payment::Event::AssetTxFeePaid;
+use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie};
+use sp_runtime::{
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    FixedU128,
+};
+
+const POINTS: AssetId = 1;
+const NAME: &[u8] = b"BlessBooking Points";
+const SYMBOL: &[u8] = b"BBP";
+
+/// Alice creates `POINTS` with Charlie as freezer and mints `amount` to Bob.
+fn create_points(amount: u128) {
+    let alice = RuntimeOrigin::signed(account(Alice));
+    assert_ok!(Assets::create(alice.clone(), POINTS.into(), account(Alice).into(), 1));
+    assert_ok!(Assets::set_metadata(alice.clone(), POINTS.into(), NAME.into(), SYMBOL.into(), 0));
+    assert_ok!(Assets::set_team(
+        alice.clone(),
+        POINTS.into(),
+        account(Alice).into(),
+        account(Alice).into(),
+        account(Charlie).into(),
+    ));
+    assert_ok!(Assets::mint(alice, POINTS.into(), account(Bob).into(), amount));
+}
+
+#[test]
+fn creators_reserve_deposits_and_hand_out_roles() {
+    new_test_ext().execute_with(|| {
+        create_points(1_000);
+
+        let metadata_deposit = AssetMetadataDepositBase::get()
+            + AssetMetadataDepositPerByte::get() * (NAME.len() + SYMBOL.len()) as u128;
+        assert_eq!(
+            Balances::reserved_balance(account(Alice)),
+            AssetDeposit::get() + metadata_deposit
+        );
+        let metadata = pallet_assets::Metadata::<Runtime>::get(POINTS);
+        assert_eq!(
+            (metadata.name.into_inner(), metadata.symbol.into_inner(), metadata.decimals),
+            (NAME.to_vec(), SYMBOL.to_vec(), 0)
+        );
+        assert_eq!(Assets::balance(POINTS, account(Bob)), 1_000);
+
+        // Only the freezer freezes.
+        assert_noop!(
+            Assets::freeze(RuntimeOrigin::signed(account(Bob)), POINTS.into(), account(Bob).into()),
+            pallet_assets::Error::<Runtime>::NoPermission
+        );
+        assert_ok!(Assets::freeze(
+            RuntimeOrigin::signed(account(Charlie)),
+            POINTS.into(),
+            account(Bob).into()
+        ));
+        assert_noop!(
+            Assets::transfer(
+                RuntimeOrigin::signed(account(Bob)),
+                POINTS.into(),
+                account(Charlie).into(),
+                10
+            ),
+            pallet_assets::Error::<Runtime>::Frozen
+        );
+    });
+}
+
+#[test]
+fn fees_are_paid_in_an_approved_asset() {
+    new_test_ext().execute_with(|| {
+        create_points(1_000 * UNITS);
+        let remark = || RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 32] });
+
+        assert_eq!(
+            apply(sign_paying_in(Bob, remark(), 0, POINTS)),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+        );
+
+        assert_ok!(FeeAssets::approve(RuntimeOrigin::root(), POINTS, FixedU128::from_u32(2)));
+        let xt = sign_paying_in(Bob, remark(), 0, POINTS);
+        let native_fee = pallet_transaction_payment::Pallet::<Runtime>::query_info(
+            xt.clone(),
+            xt.encoded_size() as u32,
+        )
+        .partial_fee;
+        let bbtc = Balances::free_balance(account(Bob));
+        let points = Assets::balance(POINTS, account(Bob));
+        assert_eq!(apply(xt), Ok(Ok(())));
+
+        let paid = events()
+            .into_iter()
+            .find_map(|e| match e {
+                RuntimeEvent::AssetTxPayment(AssetTxFeePaid { who, actual_fee, .. })
+                    if who == account(Bob) =>
+                    Some(actual_fee),
+                _ => None,
+            })
+            .expect("fee paid in POINTS");
+        assert_eq!(paid, 2 * native_fee);
+        assert_eq!(Assets::balance(POINTS, account(Bob)), points - paid);
+        assert_eq!(Balances::free_balance(account(Bob)), bbtc);
+        assert_eq!(Assets::balance(POINTS, Treasury::account_id()), paid);
+
+        // Without the approval Bob is back to paying in BBTC.
+        assert_ok!(FeeAssets::revoke(RuntimeOrigin::root(), POINTS));
+        assert_eq!(
+            apply(sign_paying_in(Bob, remark(), 1, POINTS)),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+        );
+        assert_eq!(apply(sign(Bob, remark(), 1)), Ok(Ok(())));
+        assert!(Balances::free_balance(account(Bob)) < bbtc);
+    });
+}
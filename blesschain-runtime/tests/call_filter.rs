@@ -0,0 +1,91 @@
+//! Pausing calls and safe mode on the assembled runtime.
+
+mod common;
+
+use blesschain_runtime::{Balances, CallFilter, ProxyType, Runtime, RuntimeCall, RuntimeOrigin};
+use common::*;
+use frame_support::assert_ok;
+use sp_keyring::Sr25519Keyring::{Alice, Bob};
+use sp_runtime::DispatchError;
+
+const AMOUNT: u128 = 10 * blesschain_runtime::constants::UNITS;
+
+fn transfer() -> RuntimeCall {
+    pallet_balances::Call::transfer_keep_alive { dest: account(Bob).into(), value: AMOUNT }.into()
+}
+
+fn remark() -> RuntimeCall {
+    frame_system::Call::remark { remark: vec![] }.into()
+}
+
+fn filtered() -> DispatchError {
+    frame_system::Error::<Runtime>::CallFiltered.into()
+}
+
+#[test]
+fn paused_call_is_rejected_but_still_pays_fee() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallFilter::pause(
+            RuntimeOrigin::root(),
+            b"Balances".to_vec(),
+            Some(b"transfer_keep_alive".to_vec()),
+        ));
+
+        assert_eq!(apply(sign(Alice, transfer(), 0)), Ok(Err(filtered())));
+        assert!(fee_paid(&account(Alice)).is_some());
+        assert_eq!(Balances::free_balance(account(Bob)), ENDOWMENT);
+
+        assert_ok!(CallFilter::unpause(
+            RuntimeOrigin::root(),
+            b"Balances".to_vec(),
+            Some(b"transfer_keep_alive".to_vec()),
+        ));
+        assert_eq!(apply(sign(Alice, transfer(), 1)), Ok(Ok(())));
+    });
+}
+
+#[test]
+fn safe_mode_keeps_blocks_and_system_calls_going() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallFilter::enter_safe_mode(RuntimeOrigin::root()));
+
+        assert_eq!(apply(sign(Alice, transfer(), 0)), Ok(Err(filtered())));
+        assert_eq!(apply(sign(Alice, remark(), 1)), Ok(Ok(())));
+        // The timestamp inherent is unpausable, so blocks are still produced.
+        run_to_block(3);
+
+        assert_ok!(CallFilter::exit_safe_mode(RuntimeOrigin::root()));
+        assert_eq!(apply(sign(Alice, transfer(), 2)), Ok(Ok(())));
+    });
+}
+
+#[test]
+fn safe_mode_blocks_transfers_wrapped_in_a_proxy_call() {
+    new_test_ext().execute_with(|| {
+        let add_proxy = pallet_proxy::Call::add_proxy {
+            delegate: account(Bob).into(),
+            proxy_type: ProxyType::Any,
+            delay: 0,
+        };
+        assert_eq!(apply(sign(Alice, add_proxy.into(), 0)), Ok(Ok(())));
+        assert_ok!(CallFilter::enter_safe_mode(RuntimeOrigin::root()));
+        let alice = Balances::free_balance(account(Alice));
+
+        let proxied = pallet_proxy::Call::proxy {
+            real: account(Alice).into(),
+            force_proxy_type: None,
+            call: Box::new(transfer()),
+        };
+        assert_eq!(apply(sign(Bob, proxied.into(), 0)), Ok(Err(filtered())));
+        assert_eq!(Balances::free_balance(account(Alice)), alice);
+    });
+}
+
+#[test]
+fn pausing_timestamp_does_not_stop_block_production() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallFilter::pause(RuntimeOrigin::root(), b"Timestamp".to_vec(), None));
+
+        run_to_block(3);
+    });
+}
@@ -0,0 +1,189 @@
+//! Test harness that drives the runtime the way a node does: blocks are opened
+//! and closed through `Executive`, with an Aura pre-digest and a timestamp
+//! inherent, and transactions are signed with dev keys over the real
+//! `SignedExtra`.
+
+#![allow(dead_code)]
+
+use blesschain_runtime::{
+    constants::{SLOT_DURATION, UNITS},
+    opaque::SessionKeys,
+    AccountId, Address, AssetId, BalancesConfig, BlockNumber, Executive, GenesisConfig, Hash,
+    Header, Index, Runtime, RuntimeCall, RuntimeEvent, SessionConfig, Signature, SignedExtra,
+    SignedPayload, System, UncheckedExtrinsic, ValidatorSetConfig, VERSION,
+};
+use codec::Encode;
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+use sp_runtime::{
+    generic::{Digest, DigestItem, Era},
+    traits::Header as HeaderT,
+    ApplyExtrinsicResult, BuildStorage,
+};
+
+/// Free balance of every endowed dev account at genesis.
+pub const ENDOWMENT: u128 = 1_000_000 * UNITS;
+
+/// Mortality of transactions signed by [`sign`].
+pub const ERA_PERIOD: u64 = 64;
+
+/// Dev accounts endowed at genesis; Alice is also the only validator.
+pub const ENDOWED: [Sr25519Keyring; 3] =
+    [Sr25519Keyring::Alice, Sr25519Keyring::Bob, Sr25519Keyring::Charlie];
+
+pub fn account(who: Sr25519Keyring) -> AccountId {
+    who.to_account_id()
+}
+
+/// Genesis state shaped like the dev chain spec: Alice validates, the
+/// [`ENDOWED`] accounts hold [`ENDOWMENT`] each.
+pub fn genesis() -> sp_runtime::Storage {
+    GenesisConfig {
+        balances: BalancesConfig {
+            balances: ENDOWED.iter().map(|k| (k.to_account_id(), ENDOWMENT)).collect(),
+        },
+        validator_set: ValidatorSetConfig {
+            initial_validators: vec![account(Sr25519Keyring::Alice)],
+        },
+        session: SessionConfig {
+            keys: vec![(
+                account(Sr25519Keyring::Alice),
+                account(Sr25519Keyring::Alice),
+                SessionKeys {
+                    aura: Sr25519Keyring::Alice.public().into(),
+                    grandpa: Ed25519Keyring::Alice.public().into(),
+                },
+            )],
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+}
+
+/// Externalities at [`genesis`] with block 1 already opened.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = sp_io::TestExternalities::new(genesis());
+    ext.execute_with(|| initialize_block(1, System::block_hash(0)));
+    ext
+}
+
+/// Open block `number` on top of `parent_hash`, authored in slot `number`.
+pub fn initialize_block(number: BlockNumber, parent_hash: Hash) {
+    let slot = Slot::from(number as u64);
+    let digest = Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())] };
+    Executive::initialize_block(&Header::new(
+        number,
+        Default::default(),
+        Default::default(),
+        parent_hash,
+        digest,
+    ));
+
+    let now = number as u64 * SLOT_DURATION;
+    let timestamp = UncheckedExtrinsic::new_unsigned(pallet_timestamp::Call::set { now }.into());
+    assert_eq!(Executive::apply_extrinsic(timestamp), Ok(Ok(())));
+}
+
+/// Close the current block and open the next one until block `n` is open.
+pub fn run_to_block(n: BlockNumber) {
+    while System::block_number() < n {
+        let header = Executive::finalize_block();
+        initialize_block(header.number() + 1, header.hash());
+    }
+}
+
+/// Close the current block and open block `n` right on top of it, for waits
+/// measured in days. Hooks of the skipped blocks never run, and transactions
+/// signed by [`sign`] are invalid until [`ERA_PERIOD`] more blocks have run.
+pub fn jump_to_block(n: BlockNumber) {
+    let header = Executive::finalize_block();
+    initialize_block(n, header.hash());
+}
+
+/// Sign `call` as `signer` against the best block, like a wallet would.
+pub fn sign(signer: Sr25519Keyring, call: RuntimeCall, nonce: Index) -> UncheckedExtrinsic {
+    let best = System::block_number() as u64 - 1;
+    sign_with_era(signer, call, nonce, Era::mortal(ERA_PERIOD, best))
+}
+
+/// Sign `call` as `signer` against the best block, paying the fee in `asset`.
+pub fn sign_paying_in(
+    signer: Sr25519Keyring,
+    call: RuntimeCall,
+    nonce: Index,
+    asset: AssetId,
+) -> UncheckedExtrinsic {
+    let best = System::block_number() as u64 - 1;
+    sign_with_fee_asset(signer, call, nonce, Era::mortal(ERA_PERIOD, best), Some(asset))
+}
+
+/// Sign `call` as `signer`, valid during `era`.
+pub fn sign_with_era(
+    signer: Sr25519Keyring,
+    call: RuntimeCall,
+    nonce: Index,
+    era: Era,
+) -> UncheckedExtrinsic {
+    sign_with_fee_asset(signer, call, nonce, era, None)
+}
+
+/// Sign `call` as `signer`, valid during `era`, paying the fee in
+/// `fee_asset` or else in BBTC.
+pub fn sign_with_fee_asset(
+    signer: Sr25519Keyring,
+    call: RuntimeCall,
+    nonce: Index,
+    era: Era,
+    fee_asset: Option<AssetId>,
+) -> UncheckedExtrinsic {
+    // `CheckEra` resolves the birth block from the block the transaction lands in.
+    let birth = era.birth(System::block_number() as u64) as BlockNumber;
+    let extra: SignedExtra = (
+        frame_system::CheckNonZeroSender::<Runtime>::new(),
+        frame_system::CheckSpecVersion::<Runtime>::new(),
+        frame_system::CheckTxVersion::<Runtime>::new(),
+        frame_system::CheckGenesis::<Runtime>::new(),
+        frame_system::CheckEra::<Runtime>::from(era),
+        frame_system::CheckNonce::<Runtime>::from(nonce),
+        frame_system::CheckWeight::<Runtime>::new(),
+        pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, fee_asset),
+    );
+    let payload = SignedPayload::from_raw(
+        call.clone(),
+        extra.clone(),
+        (
+            (),
+            VERSION.spec_version,
+            VERSION.transaction_version,
+            System::block_hash(0),
+            System::block_hash(birth),
+            (),
+            (),
+            (),
+        ),
+    );
+    let signature: Signature = payload.using_encoded(|e| signer.sign(e)).into();
+
+    UncheckedExtrinsic::new_signed(call, Address::Id(signer.to_account_id()), signature, extra)
+}
+
+/// Apply `xt` to the open block.
+pub fn apply(xt: UncheckedExtrinsic) -> ApplyExtrinsicResult {
+    Executive::apply_extrinsic(xt)
+}
+
+/// Events deposited in the open block so far.
+pub fn events() -> Vec<RuntimeEvent> {
+    System::events().into_iter().map(|r| r.event).collect()
+}
+
+/// Last fee `who` paid in the open block, as reported by `pallet_transaction_payment`.
+pub fn fee_paid(who: &AccountId) -> Option<u128> {
+    events().into_iter().rev().find_map(|e| match e {
+        RuntimeEvent::TransactionPayment(
+            pallet_transaction_payment::Event::TransactionFeePaid { who: payer, actual_fee, .. },
+        ) if &payer == who => Some(actual_fee),
+        _ => None,
+    })
+}
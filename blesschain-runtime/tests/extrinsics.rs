@@ -0,0 +1,133 @@
+//! Signed extrinsics applied through `Executive`: transfers, nonces, mortality
+//! and fees.
+
+mod common;
+
+use blesschain_runtime::{Balances, RuntimeCall, System, TransactionPayment, Treasury};
+use codec::Encode;
+use common::*;
+use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie};
+use sp_runtime::{
+    generic::Era,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const AMOUNT: u128 = 10 * blesschain_runtime::constants::UNITS;
+
+fn transfer(to: sp_keyring::Sr25519Keyring, value: u128) -> RuntimeCall {
+    pallet_balances::Call::transfer_keep_alive { dest: account(to).into(), value }.into()
+}
+
+#[test]
+fn transfer_moves_funds_and_pays_fee_to_treasury() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+        let pot = Treasury::pot();
+
+        assert_eq!(apply(sign(Alice, transfer(Bob, AMOUNT), 0)), Ok(Ok(())));
+
+        System::assert_has_event(
+            pallet_balances::Event::Transfer {
+                from: account(Alice),
+                to: account(Bob),
+                amount: AMOUNT,
+            }
+            .into(),
+        );
+        let fee = fee_paid(&account(Alice)).expect("fee charged");
+        assert!(fee > 0);
+        assert_eq!(Balances::free_balance(account(Alice)), ENDOWMENT - AMOUNT - fee);
+        assert_eq!(Balances::free_balance(account(Bob)), ENDOWMENT + AMOUNT);
+        assert_eq!(Treasury::pot(), pot + fee);
+        assert_eq!(Balances::total_issuance(), issuance);
+    });
+}
+
+#[test]
+fn charged_fee_matches_fee_estimate() {
+    new_test_ext().execute_with(|| {
+        let xt = sign(Alice, transfer(Bob, AMOUNT), 0);
+        let estimate = TransactionPayment::query_info(xt.clone(), xt.encoded_size() as u32);
+
+        assert_eq!(apply(xt), Ok(Ok(())));
+
+        assert_eq!(fee_paid(&account(Alice)), Some(estimate.partial_fee));
+    });
+}
+
+#[test]
+fn failed_dispatch_still_pays_fee_and_bumps_nonce() {
+    new_test_ext().execute_with(|| {
+        let result = apply(sign(Charlie, transfer(Bob, 2 * ENDOWMENT), 0));
+
+        assert!(matches!(result, Ok(Err(_))), "{result:?}");
+        let fee = fee_paid(&account(Charlie)).expect("fee charged");
+        assert_eq!(Balances::free_balance(account(Charlie)), ENDOWMENT - fee);
+        assert_eq!(Balances::free_balance(account(Bob)), ENDOWMENT);
+        assert_eq!(System::account_nonce(account(Charlie)), 1);
+    });
+}
+
+#[test]
+fn nonce_must_match_account_nonce() {
+    new_test_ext().execute_with(|| {
+        let first = sign(Alice, transfer(Bob, AMOUNT), 0);
+        assert_eq!(apply(first.clone()), Ok(Ok(())));
+        assert_eq!(System::account_nonce(account(Alice)), 1);
+
+        assert_eq!(
+            apply(first),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)),
+        );
+        assert_eq!(
+            apply(sign(Alice, transfer(Bob, AMOUNT), 2)),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Future)),
+        );
+        assert_eq!(apply(sign(Alice, transfer(Bob, AMOUNT), 1)), Ok(Ok(())));
+        assert_eq!(Balances::free_balance(account(Bob)), ENDOWMENT + 2 * AMOUNT);
+    });
+}
+
+#[test]
+fn mortal_transaction_expires_with_its_era() {
+    new_test_ext().execute_with(|| {
+        run_to_block(3);
+        let era = Era::mortal(4, System::block_number() as u64 - 1);
+        let xt = sign_with_era(Alice, transfer(Bob, AMOUNT), 0, era);
+
+        // Still inside the era a few blocks later.
+        run_to_block(5);
+        assert_eq!(apply(xt.clone()), Ok(Ok(())));
+
+        // Once the era has rolled over, the birth block the signature commits
+        // to is no longer the one `CheckEra` resolves.
+        let expiring = sign_with_era(Alice, transfer(Bob, AMOUNT), 1, era);
+        run_to_block(8);
+        assert_eq!(
+            apply(expiring),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::BadProof)),
+        );
+    });
+}
+
+#[test]
+fn immortal_transaction_outlives_any_era() {
+    new_test_ext().execute_with(|| {
+        let xt = sign_with_era(Alice, transfer(Bob, AMOUNT), 0, Era::Immortal);
+        run_to_block(ERA_PERIOD as u32 + 2);
+
+        assert_eq!(apply(xt), Ok(Ok(())));
+    });
+}
+
+#[test]
+fn signature_must_come_from_the_sender() {
+    new_test_ext().execute_with(|| {
+        let mut xt = sign(Alice, transfer(Bob, AMOUNT), 0);
+        let (_, signature, extra) = xt.signature.take().unwrap();
+        xt.signature = Some((account(Charlie).into(), signature, extra));
+
+        assert_eq!(apply(xt), Err(TransactionValidityError::Invalid(InvalidTransaction::BadProof)));
+        assert_eq!(Balances::free_balance(account(Bob)), ENDOWMENT);
+    });
+}
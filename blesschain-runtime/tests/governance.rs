@@ -0,0 +1,164 @@
+//! Referenda on the assembled runtime: tracks, conviction-locked votes and
+//! enactment through the scheduler.
+
+mod common;
+
+use blesschain_runtime::{
+    constants::{MINUTES, UNITS},
+    governance::{pallet_custom_origins::Origin, TracksInfo},
+    Balance, Balances, BlockNumber, CallFilter, OriginCaller, Preimage, Runtime, RuntimeCall,
+    RuntimeOrigin, System,
+};
+use common::*;
+use frame_support::{
+    assert_ok,
+    traits::{schedule::DispatchTime, StorePreimage},
+};
+use pallet_conviction_voting::{AccountVote, Conviction, Vote};
+use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie};
+
+/// Enough for Alice and Bob together to pass the support threshold of any
+/// track, small enough to stay within their free balance after deposits.
+const VOTE: u128 = 800_000 * UNITS;
+
+fn submit(origin: OriginCaller, call: RuntimeCall) -> RuntimeCall {
+    pallet_referenda::Call::submit {
+        proposal_origin: Box::new(origin),
+        proposal: Preimage::bound(call).unwrap(),
+        enactment_moment: DispatchTime::After(0),
+    }
+    .into()
+}
+
+fn place_decision_deposit(index: u32) -> RuntimeCall {
+    pallet_referenda::Call::place_decision_deposit { index }.into()
+}
+
+fn vote_aye(poll_index: u32, balance: u128) -> RuntimeCall {
+    pallet_conviction_voting::Call::vote {
+        poll_index,
+        vote: AccountVote::Standard {
+            vote: Vote { aye: true, conviction: Conviction::Locked1x },
+            balance,
+        },
+    }
+    .into()
+}
+
+fn enter_safe_mode() -> RuntimeCall {
+    pallet_call_filter::Call::enter_safe_mode {}.into()
+}
+
+fn track_of(index: u32) -> u16 {
+    match pallet_referenda::ReferendumInfoFor::<Runtime>::get(index) {
+        Some(pallet_referenda::ReferendumInfo::Ongoing(status)) => status.track,
+        other => panic!("referendum {index} is not ongoing: {other:?}"),
+    }
+}
+
+#[test]
+fn emergency_pause_referendum_enters_safe_mode() {
+    new_test_ext().execute_with(|| {
+        let origin = OriginCaller::Origins(Origin::EmergencyPause);
+        assert_eq!(apply(sign(Alice, submit(origin, enter_safe_mode()), 0)), Ok(Ok(())));
+        assert_eq!(track_of(0), 1);
+        assert_eq!(apply(sign(Alice, place_decision_deposit(0), 1)), Ok(Ok(())));
+        assert_eq!(apply(sign(Alice, vote_aye(0, VOTE), 2)), Ok(Ok(())));
+        assert_eq!(apply(sign(Bob, vote_aye(0, VOTE), 0)), Ok(Ok(())));
+        assert!(!CallFilter::safe_mode());
+
+        // Ten minutes preparing, ten confirming, then enacted a block later.
+        run_to_block((20 * MINUTES) as u32 + 5);
+
+        assert!(CallFilter::safe_mode());
+    });
+}
+
+#[test]
+fn chain_is_voted_out_of_safe_mode() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallFilter::enter_safe_mode(RuntimeOrigin::root()));
+        let exit: RuntimeCall = pallet_call_filter::Call::exit_safe_mode {}.into();
+        let origin = OriginCaller::Origins(Origin::EmergencyPause);
+        assert_eq!(apply(sign(Alice, submit(origin, exit), 0)), Ok(Ok(())));
+        assert_eq!(apply(sign(Alice, place_decision_deposit(0), 1)), Ok(Ok(())));
+        assert_eq!(apply(sign(Alice, vote_aye(0, VOTE), 2)), Ok(Ok(())));
+        assert_eq!(apply(sign(Bob, vote_aye(0, VOTE), 0)), Ok(Ok(())));
+
+        run_to_block((20 * MINUTES) as u32 + 5);
+
+        assert!(!CallFilter::safe_mode());
+    });
+}
+
+/// A root referendum passes on a chain endowed like the dev one. Each wait
+/// jumps straight to the referendum's next scheduler alarm.
+#[test]
+fn dev_accounts_dispatch_a_root_call_through_the_root_track() {
+    new_test_ext().execute_with(|| {
+        let key = b":blesschain:root_test".to_vec();
+        let set_storage: RuntimeCall =
+            frame_system::Call::set_storage { items: vec![(key.clone(), vec![1])] }.into();
+        let root = OriginCaller::system(frame_system::RawOrigin::Root);
+        assert_eq!(apply(sign(Alice, submit(root, set_storage), 0)), Ok(Ok(())));
+        assert_eq!(apply(sign(Alice, place_decision_deposit(0), 1)), Ok(Ok(())));
+        assert_eq!(apply(sign(Alice, vote_aye(0, VOTE), 2)), Ok(Ok(())));
+        assert_eq!(apply(sign(Bob, vote_aye(0, VOTE), 0)), Ok(Ok(())));
+
+        let track =
+            <TracksInfo as pallet_referenda::TracksInfo<Balance, BlockNumber>>::info(0).unwrap();
+        let deciding = System::block_number() + track.prepare_period;
+        let approved = deciding + track.confirm_period;
+        let enacted = approved + track.min_enactment_period;
+        for block in [deciding, approved] {
+            jump_to_block(block);
+            assert_eq!(frame_support::storage::unhashed::get_raw(&key), None);
+        }
+        jump_to_block(enacted);
+
+        assert_eq!(frame_support::storage::unhashed::get_raw(&key), Some(vec![1]));
+    });
+}
+
+#[test]
+fn proposal_origin_picks_the_track() {
+    new_test_ext().execute_with(|| {
+        let root = OriginCaller::system(frame_system::RawOrigin::Root);
+        assert_eq!(apply(sign(Alice, submit(root, enter_safe_mode()), 0)), Ok(Ok(())));
+        assert_eq!(track_of(0), 0);
+
+        let admin = OriginCaller::Origins(Origin::ValidatorAdmin);
+        assert_eq!(apply(sign(Alice, submit(admin, enter_safe_mode()), 1)), Ok(Ok(())));
+        assert_eq!(track_of(1), 10);
+
+        // No track dispatches as a signed account.
+        let signed = OriginCaller::system(frame_system::RawOrigin::Signed(account(Bob)));
+        assert_eq!(
+            apply(sign(Alice, submit(signed, enter_safe_mode()), 2)),
+            Ok(Err(pallet_referenda::Error::<Runtime>::NoTrack.into())),
+        );
+    });
+}
+
+#[test]
+fn vote_locks_balance_under_a_single_lock() {
+    new_test_ext().execute_with(|| {
+        let root = OriginCaller::system(frame_system::RawOrigin::Root);
+        assert_eq!(apply(sign(Alice, submit(root, enter_safe_mode()), 0)), Ok(Ok(())));
+        let admin = OriginCaller::Origins(Origin::ValidatorAdmin);
+        assert_eq!(apply(sign(Alice, submit(admin, enter_safe_mode()), 1)), Ok(Ok(())));
+
+        assert_eq!(apply(sign(Charlie, vote_aye(0, VOTE), 0)), Ok(Ok(())));
+        assert_eq!(apply(sign(Charlie, vote_aye(1, VOTE), 1)), Ok(Ok(())));
+        assert_eq!(pallet_balances::Locks::<Runtime>::get(account(Charlie)).len(), 1);
+
+        let transfer: RuntimeCall = pallet_balances::Call::transfer_keep_alive {
+            dest: account(Bob).into(),
+            value: ENDOWMENT - VOTE,
+        }
+        .into();
+        let result = apply(sign(Charlie, transfer, 2));
+        assert!(matches!(result, Ok(Err(_))), "{result:?}");
+        assert_eq!(Balances::free_balance(account(Bob)), ENDOWMENT);
+    });
+}
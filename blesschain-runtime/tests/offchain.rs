@@ -0,0 +1,101 @@
+//! Off-chain workers: signed transactions back to the pool, and HTTP against a
+//! local stand-in for the outside world.
+
+mod common;
+
+use blesschain_runtime::{
+    offchain::{self, OffchainAuthId, KEY_TYPE},
+    Executive, Runtime, RuntimeEvent, UncheckedExtrinsic,
+};
+use codec::Decode;
+use common::*;
+use frame_system::offchain::{SendSignedTransaction, Signer};
+use sp_core::offchain::{
+    testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+    Duration, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_keyring::Sr25519Keyring::Alice;
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+/// Genesis externalities with block 1 open and the off-chain extensions a
+/// node registers for its workers.
+fn offchain_ext() -> (sp_io::TestExternalities, TestOffchainExt) {
+    let mut ext = new_test_ext();
+    let (offchain, _) = TestOffchainExt::new();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    (ext, offchain)
+}
+
+#[test]
+fn worker_transactions_are_signed_for_the_next_block() {
+    let (mut ext, _) = offchain_ext();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    ext.register_extension(TransactionPoolExt::new(pool));
+    let keystore = MemoryKeystore::new();
+    keystore.sr25519_generate_new(KEY_TYPE, Some(&Alice.to_seed())).unwrap();
+    ext.register_extension(KeystoreExt::new(keystore));
+
+    ext.execute_with(|| {
+        // The node runs workers on the state right after importing a block.
+        let header = Executive::finalize_block();
+        Executive::offchain_worker(&header);
+
+        let sent =
+            Signer::<Runtime, OffchainAuthId>::all_accounts().send_signed_transaction(|_| {
+                frame_system::Call::remark_with_event { remark: b"worker".to_vec() }
+            });
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0.id, account(Alice));
+        assert_eq!(sent[0].1, Ok(()));
+
+        let pooled = pool_state.read().transactions.clone();
+        assert_eq!(pooled.len(), 1);
+        let xt = UncheckedExtrinsic::decode(&mut &pooled[0][..]).unwrap();
+
+        initialize_block(2, header.hash());
+        assert_eq!(apply(xt), Ok(Ok(())));
+        assert!(events().contains(&RuntimeEvent::System(frame_system::Event::Remarked {
+            sender: account(Alice),
+            hash: BlakeTwo256::hash(b"worker"),
+        })));
+    });
+}
+
+#[test]
+fn workers_without_a_key_send_nothing() {
+    let (mut ext, _) = offchain_ext();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+
+    ext.execute_with(|| {
+        let signer = Signer::<Runtime, OffchainAuthId>::all_accounts();
+        assert!(!signer.can_sign());
+        let sent = signer
+            .send_signed_transaction(|_| frame_system::Call::remark { remark: b"worker".to_vec() });
+        assert!(sent.is_empty());
+        assert!(pool_state.read().transactions.is_empty());
+    });
+}
+
+#[test]
+fn http_get_reads_from_a_local_stand_in() {
+    let (mut ext, offchain) = offchain_ext();
+    let url = "http://localhost:8080/health";
+    offchain.0.write().expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: url.into(),
+        response: Some(br#"{"status":"ok"}"#.to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+
+    ext.execute_with(|| {
+        assert_eq!(
+            offchain::http_get(url, Duration::from_millis(1_000)),
+            Ok(br#"{"status":"ok"}"#.to_vec())
+        );
+    });
+}
@@ -0,0 +1,118 @@
+//! Forkless upgrades through `set_code`, on a chain built like the local one.
+
+mod common;
+
+use blesschain_runtime::{AccountId, Runtime, RuntimeOrigin, System, VERSION, WASM_BINARY};
+use codec::Decode;
+use common::{genesis, initialize_block, run_to_block};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::LastRuntimeUpgradeInfo;
+use sc_executor::WasmExecutor;
+use sp_core::{storage::well_known_keys::CODE, traits::ReadRuntimeVersionExt};
+use sp_runtime::DispatchError;
+use sp_version::RuntimeVersion;
+
+fn wasm_binary() -> &'static [u8] {
+    WASM_BINARY.expect("WASM runtime not built; unset SKIP_WASM_BUILD")
+}
+
+/// The local chain's genesis with `code` as its runtime and block 1 open,
+/// able to read runtime versions out of WASM blobs like the node's executor
+/// does.
+fn new_test_ext(code: Vec<u8>) -> sp_io::TestExternalities {
+    let mut storage = genesis();
+    storage.top.insert(CODE.to_vec(), code);
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.register_extension(ReadRuntimeVersionExt::new(
+        WasmExecutor::<sp_io::SubstrateHostFunctions>::builder().build(),
+    ));
+    ext.execute_with(|| initialize_block(1, System::block_hash(0)));
+    ext
+}
+
+/// The built runtime re-labelled with `spec_version`.
+fn runtime_with_spec_version(spec_version: u32) -> Vec<u8> {
+    sp_version::embed::embed_runtime_version(
+        wasm_binary(),
+        RuntimeVersion { spec_version, ..VERSION },
+    )
+    .unwrap()
+}
+
+/// The version embedded in the runtime at `:code`.
+fn code_version() -> RuntimeVersion {
+    let code = sp_io::storage::get(CODE).expect("genesis sets :code");
+    let encoded = sp_io::misc::runtime_version(&code).expect(":code carries a version");
+    RuntimeVersion::decode(&mut &encoded[..]).unwrap()
+}
+
+#[test]
+fn genesis_code_is_the_built_runtime() {
+    new_test_ext(wasm_binary().to_vec()).execute_with(|| {
+        assert_eq!(sp_io::storage::get(CODE).as_deref(), Some(wasm_binary()));
+        assert_eq!(code_version().spec_version, VERSION.spec_version);
+    });
+}
+
+#[test]
+fn root_upgrades_runtime_to_next_spec_version() {
+    let v2 = runtime_with_spec_version(VERSION.spec_version + 1);
+
+    new_test_ext(wasm_binary().to_vec()).execute_with(|| {
+        assert_ok!(System::set_code(RuntimeOrigin::root(), v2.clone()));
+
+        assert_eq!(sp_io::storage::get(CODE).as_deref(), Some(&v2[..]));
+        assert_eq!(code_version().spec_version, VERSION.spec_version + 1);
+        System::assert_last_event(frame_system::Event::CodeUpdated.into());
+    });
+}
+
+/// A local chain that last migrated under the previous release upgrades: the
+/// first block after `set_code` runs the migrations and records the version.
+///
+/// Blocks keep executing natively, so the new code is the built runtime
+/// relabelled one spec version up, and the chain's record is set one below.
+#[test]
+fn local_chain_upgrade_runs_migrations_on_the_next_block() {
+    let previous = RuntimeVersion { spec_version: VERSION.spec_version - 1, ..VERSION };
+    let next = runtime_with_spec_version(VERSION.spec_version + 1);
+
+    new_test_ext(wasm_binary().to_vec()).execute_with(|| {
+        frame_system::LastRuntimeUpgrade::<Runtime>::put(LastRuntimeUpgradeInfo::from(previous));
+
+        assert_ok!(System::set_code(RuntimeOrigin::root(), next));
+        assert_eq!(code_version().spec_version, VERSION.spec_version + 1);
+        // Nothing migrates within the block that enacts the upgrade.
+        let recorded = frame_system::LastRuntimeUpgrade::<Runtime>::get().unwrap();
+        assert!(recorded.was_upgraded(&VERSION));
+
+        run_to_block(2);
+        let recorded = frame_system::LastRuntimeUpgrade::<Runtime>::get().unwrap();
+        assert!(!recorded.was_upgraded(&VERSION));
+        assert_eq!(recorded, LastRuntimeUpgradeInfo::from(VERSION));
+    });
+}
+
+#[test]
+fn set_code_requires_root() {
+    new_test_ext(wasm_binary().to_vec()).execute_with(|| {
+        assert_noop!(
+            System::set_code(
+                RuntimeOrigin::signed(AccountId::new([1; 32])),
+                runtime_with_spec_version(VERSION.spec_version + 1),
+            ),
+            DispatchError::BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn set_code_rejects_same_or_older_spec_version() {
+    new_test_ext(wasm_binary().to_vec()).execute_with(|| {
+        assert_noop!(
+            System::set_code(RuntimeOrigin::root(), wasm_binary().to_vec()),
+            frame_system::Error::<Runtime>::SpecVersionNeedsToIncrease,
+        );
+    });
+}
@@ -0,0 +1,27 @@
+//! Sudo is gone: the former key holds no special rights and root is only
+//! reached through a root track referendum (see `governance.rs`).
+
+mod common;
+
+use blesschain_runtime::{Runtime, RuntimeCall};
+use common::*;
+use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+fn store_metadata() -> RuntimeCall {
+    pallet_metadata::Call::store_metadata { key: b"rpc".to_vec(), value: b"wss://".to_vec() }.into()
+}
+
+#[test]
+fn former_sudo_key_cannot_dispatch_root_calls() {
+    new_test_ext().execute_with(|| {
+        // Alice held the sudo key on dev and local; she is a plain account now.
+        assert_eq!(
+            apply(sign(Alice, store_metadata(), 0)),
+            Ok(Err(pallet_metadata::Error::<Runtime>::NotAuthorized.into())),
+        );
+        assert_eq!(
+            apply(sign(Bob, store_metadata(), 0)),
+            Ok(Err(pallet_metadata::Error::<Runtime>::NotAuthorized.into())),
+        );
+    });
+}
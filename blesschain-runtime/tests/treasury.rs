@@ -0,0 +1,132 @@
+//! Treasury spends, proposals and milestone bounties on the assembled runtime.
+
+mod common;
+
+use blesschain_runtime::{
+    constants::UNITS,
+    governance::pallet_custom_origins::{Origin, SMALL_SPEND},
+    AccountId, Balances, BlockNumber, Bounties, BountyDepositPayoutDelay, ChildBounties, Runtime,
+    RuntimeOrigin, SpendPeriod, System, Treasury,
+};
+use common::*;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use pallet_bounties::BountyStatus;
+use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie, Dave};
+
+const GRANT: u128 = 100 * UNITS;
+const BOUNTY: u128 = 200 * UNITS;
+const MILESTONE: u128 = 40 * UNITS;
+
+fn fund_treasury(amount: u128) {
+    assert_ok!(Balances::transfer_keep_alive(
+        RuntimeOrigin::signed(account(Alice)),
+        Treasury::account_id().into(),
+        amount,
+    ));
+}
+
+fn bounty_status(index: u32) -> BountyStatus<AccountId, BlockNumber> {
+    pallet_bounties::Bounties::<Runtime>::get(index).expect("bounty exists").get_status()
+}
+
+#[test]
+fn spender_origin_is_capped_and_paid_at_spend_period() {
+    new_test_ext().execute_with(|| {
+        fund_treasury(500 * UNITS);
+
+        assert_noop!(
+            Treasury::spend(Origin::SmallSpender.into(), SMALL_SPEND + 1, account(Dave).into()),
+            pallet_treasury::Error::<Runtime>::InsufficientPermission,
+        );
+        assert_ok!(Treasury::spend(Origin::SmallSpender.into(), GRANT, account(Dave).into()));
+        assert_eq!(Balances::free_balance(account(Dave)), 0);
+
+        jump_to_block(SpendPeriod::get());
+
+        assert_eq!(Balances::free_balance(account(Dave)), GRANT);
+        assert_eq!(Treasury::pot(), 500 * UNITS - GRANT);
+    });
+}
+
+#[test]
+fn treasurer_approves_and_rejects_proposals() {
+    new_test_ext().execute_with(|| {
+        fund_treasury(500 * UNITS);
+        let bob = RuntimeOrigin::signed(account(Bob));
+        assert_ok!(Treasury::propose_spend(bob.clone(), GRANT, account(Dave).into()));
+        assert_ok!(Treasury::propose_spend(bob, GRANT, account(Charlie).into()));
+        // 5% of the value, but at least `ProposalBondMinimum`.
+        let bond = 10 * UNITS;
+        assert_eq!(Balances::reserved_balance(account(Bob)), 2 * bond);
+
+        assert_noop!(
+            Treasury::approve_proposal(RuntimeOrigin::signed(account(Alice)), 0),
+            sp_runtime::DispatchError::BadOrigin,
+        );
+        assert_ok!(Treasury::approve_proposal(Origin::Treasurer.into(), 0));
+        assert_ok!(Treasury::reject_proposal(Origin::Treasurer.into(), 1));
+        // The rejected proposal's bond is slashed into the pot.
+        assert_eq!(Balances::reserved_balance(account(Bob)), bond);
+        assert_eq!(Treasury::pot(), 500 * UNITS + bond);
+
+        jump_to_block(SpendPeriod::get());
+
+        assert_eq!(Balances::free_balance(account(Dave)), GRANT);
+        assert_eq!(Balances::free_balance(account(Charlie)), ENDOWMENT);
+        assert_eq!(Balances::reserved_balance(account(Bob)), 0);
+    });
+}
+
+#[test]
+fn bounty_milestone_is_paid_through_a_child_bounty() {
+    new_test_ext().execute_with(|| {
+        fund_treasury(500 * UNITS);
+        assert_ok!(Bounties::propose_bounty(
+            RuntimeOrigin::signed(account(Bob)),
+            BOUNTY,
+            b"HomeCDN operator dashboard".to_vec(),
+        ));
+        assert_ok!(Bounties::approve_bounty(Origin::SmallSpender.into(), 0));
+
+        jump_to_block(SpendPeriod::get());
+        assert_eq!(bounty_status(0), BountyStatus::Funded);
+
+        assert_ok!(Bounties::propose_curator(
+            Origin::SmallSpender.into(),
+            0,
+            account(Charlie).into(),
+            0,
+        ));
+        let curator = RuntimeOrigin::signed(account(Charlie));
+        assert_ok!(Bounties::accept_curator(curator.clone(), 0));
+
+        // The curator splits off the first milestone and curates it too.
+        assert_ok!(ChildBounties::add_child_bounty(
+            curator.clone(),
+            0,
+            MILESTONE,
+            b"milestone 1".to_vec(),
+        ));
+        assert_ok!(ChildBounties::propose_curator(
+            curator.clone(),
+            0,
+            0,
+            account(Charlie).into(),
+            0,
+        ));
+        assert_ok!(ChildBounties::accept_curator(curator.clone(), 0, 0));
+        assert_ok!(ChildBounties::award_child_bounty(curator.clone(), 0, 0, account(Dave).into()));
+
+        let claimable = System::block_number() + BountyDepositPayoutDelay::get();
+        assert_noop!(
+            ChildBounties::claim_child_bounty(curator.clone(), 0, 0),
+            pallet_bounties::Error::<Runtime>::Premature,
+        );
+
+        jump_to_block(claimable);
+        assert_ok!(ChildBounties::claim_child_bounty(curator, 0, 0));
+
+        assert_eq!(Balances::free_balance(account(Dave)), MILESTONE);
+        assert!(matches!(bounty_status(0), BountyStatus::Active { .. }));
+    });
+}
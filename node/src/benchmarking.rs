@@ -0,0 +1,162 @@
+//! Extrinsics and inherents for `benchmark overhead` and `benchmark extrinsic`,
+//! and the hardware BlessChain validators are measured against.
+
+use std::{sync::Arc, time::Duration};
+
+use blesschain_runtime as runtime;
+use codec::Encode;
+use frame_benchmarking_cli::Requirements;
+use sc_cli::Result;
+use sc_client_api::BlockBackend;
+use sp_core::{sr25519, Get, Pair};
+use sp_inherents::{InherentData, InherentDataProvider};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{OpaqueExtrinsic, SaturatedConversion};
+
+use crate::service::FullClient;
+
+/// Minimum hardware for a BlessChain validator, checked by `benchmark machine`.
+///
+/// Sized for the home servers the network targets (e.g. a Dell R730XD with SATA
+/// SSDs) rather than Substrate's data-center reference machine.
+pub fn reference_hardware() -> Requirements {
+    serde_json::from_slice(include_bytes!("../res/reference_hardware.json"))
+        .expect("Hardcoded data is known good; qed")
+}
+
+/// Generates `System::remark` extrinsics for `benchmark overhead`.
+pub struct RemarkBuilder {
+    client: Arc<FullClient>,
+}
+
+impl RemarkBuilder {
+    pub fn new(client: Arc<FullClient>) -> Self {
+        Self { client }
+    }
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for RemarkBuilder {
+    fn pallet(&self) -> &str {
+        "system"
+    }
+
+    fn extrinsic(&self) -> &str {
+        "remark"
+    }
+
+    fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+        let acc = Sr25519Keyring::Bob.pair();
+        let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+            self.client.as_ref(),
+            acc,
+            frame_system::Call::remark { remark: vec![] }.into(),
+            nonce,
+        )
+        .into();
+
+        Ok(extrinsic)
+    }
+}
+
+/// Generates `Balances::transfer_keep_alive` extrinsics for `benchmark extrinsic`.
+pub struct TransferKeepAliveBuilder {
+    client: Arc<FullClient>,
+    dest: runtime::AccountId,
+    value: runtime::Balance,
+}
+
+impl TransferKeepAliveBuilder {
+    pub fn new(client: Arc<FullClient>, dest: runtime::AccountId, value: runtime::Balance) -> Self {
+        Self { client, dest, value }
+    }
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for TransferKeepAliveBuilder {
+    fn pallet(&self) -> &str {
+        "balances"
+    }
+
+    fn extrinsic(&self) -> &str {
+        "transfer_keep_alive"
+    }
+
+    fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+        let acc = Sr25519Keyring::Bob.pair();
+        let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+            self.client.as_ref(),
+            acc,
+            pallet_balances::Call::transfer_keep_alive {
+                dest: self.dest.clone().into(),
+                value: self.value,
+            }
+            .into(),
+            nonce,
+        )
+        .into();
+
+        Ok(extrinsic)
+    }
+}
+
+/// Create a transaction signed by `sender` with the runtime's signed extensions.
+pub fn create_benchmark_extrinsic(
+    client: &FullClient,
+    sender: sr25519::Pair,
+    call: runtime::RuntimeCall,
+    nonce: u32,
+) -> runtime::UncheckedExtrinsic {
+    let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+    let best_hash = client.chain_info().best_hash;
+    let best_block = client.chain_info().best_number;
+
+    let period = <runtime::Runtime as frame_system::Config>::BlockHashCount::get()
+        .checked_next_power_of_two()
+        .map(|c| c / 2)
+        .unwrap_or(2) as u64;
+    let extra: runtime::SignedExtra = (
+        frame_system::CheckNonZeroSender::<runtime::Runtime>::new(),
+        frame_system::CheckSpecVersion::<runtime::Runtime>::new(),
+        frame_system::CheckTxVersion::<runtime::Runtime>::new(),
+        frame_system::CheckGenesis::<runtime::Runtime>::new(),
+        frame_system::CheckEra::<runtime::Runtime>::from(sp_runtime::generic::Era::mortal(
+            period,
+            best_block.saturated_into(),
+        )),
+        frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
+        frame_system::CheckWeight::<runtime::Runtime>::new(),
+        pallet_asset_tx_payment::ChargeAssetTxPayment::<runtime::Runtime>::from(0, None),
+    );
+
+    let raw_payload = runtime::SignedPayload::from_raw(
+        call.clone(),
+        extra.clone(),
+        (
+            (),
+            runtime::VERSION.spec_version,
+            runtime::VERSION.transaction_version,
+            genesis_hash,
+            best_hash,
+            (),
+            (),
+            (),
+        ),
+    );
+    let signature = raw_payload.using_encoded(|e| sender.sign(e));
+
+    runtime::UncheckedExtrinsic::new_signed(
+        call,
+        sp_runtime::AccountId32::from(sender.public()).into(),
+        runtime::Signature::Sr25519(signature),
+        extra,
+    )
+}
+
+/// Inherent data for a benchmark block: only the timestamp.
+pub fn inherent_benchmark_data() -> Result<InherentData> {
+    let mut inherent_data = InherentData::new();
+    let timestamp = sp_timestamp::InherentDataProvider::new(Duration::from_millis(0).into());
+
+    futures::executor::block_on(timestamp.provide_inherent_data(&mut inherent_data))
+        .map_err(|e| format!("creating inherent data: {:?}", e))?;
+    Ok(inherent_data)
+}
@@ -1,4 +1,144 @@
-pub fn development_config() {
-    println!("⚙️  Using BlessChain development configuration (mock)");
+//! Chain specifications for BlessChain: `dev` (single authority) and `local` (two authorities).
+
+use blesschain_runtime::{
+    constants::UNITS, opaque::SessionKeys, AccountId, AuraConfig, BalancesConfig, GenesisConfig,
+    GrandpaConfig, SessionConfig, Signature, SystemConfig, ValidatorSetConfig, WASM_BINARY,
+};
+use sc_service::{ChainType, Properties};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{sr25519, Pair, Public};
+use sp_runtime::traits::{IdentifyAccount, Verify};
+
+/// Specialized `ChainSpec` for the BlessChain runtime.
+pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
+
+/// Balance given to every endowed account at genesis.
+const ENDOWMENT: u128 = 1_000_000 * UNITS;
+
+type AccountPublic = <Signature as Verify>::Signer;
+
+/// Generate a crypto pair from seed.
+pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
+    TPublic::Pair::from_string(&format!("//{}", seed), None)
+        .expect("static values are valid; qed")
+        .public()
+}
+
+/// Generate an account ID from seed.
+pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
+where
+    AccountPublic: From<<TPublic::Pair as Pair>::Public>,
+{
+    AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+}
+
+/// Generate the stash account and session keys of an authority from seed.
+pub fn authority_keys_from_seed(s: &str) -> (AccountId, AuraId, GrandpaId) {
+    (
+        get_account_id_from_seed::<sr25519::Public>(s),
+        get_from_seed::<AuraId>(s),
+        get_from_seed::<GrandpaId>(s),
+    )
+}
+
+fn session_keys(aura: AuraId, grandpa: GrandpaId) -> SessionKeys {
+    SessionKeys { aura, grandpa }
+}
+
+fn properties() -> Properties {
+    let mut properties = Properties::new();
+    properties.insert("tokenSymbol".into(), "BBTC".into());
+    properties.insert("tokenDecimals".into(), 12.into());
+    properties.insert("ss58Format".into(), 42.into());
+    properties
+}
+
+pub fn development_config() -> Result<ChainSpec, String> {
+    let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+    Ok(ChainSpec::from_genesis(
+        "BlessChain Development",
+        "blesschain_dev",
+        ChainType::Development,
+        move || {
+            testnet_genesis(
+                wasm_binary,
+                vec![authority_keys_from_seed("Alice")],
+                vec![
+                    get_account_id_from_seed::<sr25519::Public>("Alice"),
+                    get_account_id_from_seed::<sr25519::Public>("Bob"),
+                    get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+                    get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+                ],
+            )
+        },
+        vec![],
+        None,
+        None,
+        None,
+        Some(properties()),
+        None,
+    ))
 }
 
+pub fn local_testnet_config() -> Result<ChainSpec, String> {
+    let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+    Ok(ChainSpec::from_genesis(
+        "BlessChain Local Testnet",
+        "blesschain_local_testnet",
+        ChainType::Local,
+        move || {
+            testnet_genesis(
+                wasm_binary,
+                vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
+                vec![
+                    get_account_id_from_seed::<sr25519::Public>("Alice"),
+                    get_account_id_from_seed::<sr25519::Public>("Bob"),
+                    get_account_id_from_seed::<sr25519::Public>("Charlie"),
+                    get_account_id_from_seed::<sr25519::Public>("Dave"),
+                    get_account_id_from_seed::<sr25519::Public>("Eve"),
+                    get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+                ],
+            )
+        },
+        vec![],
+        None,
+        None,
+        None,
+        Some(properties()),
+        None,
+    ))
+}
+
+/// Genesis shared by the dev and local chains. Aura and GRANDPA authorities are left
+/// empty: `pallet_session` fills them in from the validator set and session keys.
+/// Root is only reachable through a referendum on the `root` track.
+fn testnet_genesis(
+    wasm_binary: &[u8],
+    initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
+    endowed_accounts: Vec<AccountId>,
+) -> GenesisConfig {
+    GenesisConfig {
+        system: SystemConfig { code: wasm_binary.to_vec() },
+        balances: BalancesConfig {
+            balances: endowed_accounts.iter().cloned().map(|k| (k, ENDOWMENT)).collect(),
+        },
+        validator_set: ValidatorSetConfig {
+            initial_validators: initial_authorities.iter().map(|x| x.0.clone()).collect(),
+        },
+        session: SessionConfig {
+            keys: initial_authorities
+                .iter()
+                .map(|x| (x.0.clone(), x.0.clone(), session_keys(x.1.clone(), x.2.clone())))
+                .collect(),
+        },
+        aura: AuraConfig { authorities: vec![] },
+        grandpa: GrandpaConfig { authorities: vec![] },
+        transaction_payment: Default::default(),
+        // Endows the treasury account with the existential deposit.
+        treasury: Default::default(),
+        assets: Default::default(),
+    }
+}
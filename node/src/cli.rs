@@ -1,13 +1,90 @@
-//! BlessChain CLI definition (minimal)
+//! BlessChain CLI definition
 
-use sc_cli::{SubstrateCli, RunCmd};
+use sc_cli::{RunCmd, SubstrateCli};
 use sc_service::ChainSpec;
 
+use crate::chain_spec;
+
 #[derive(Debug, clap::Parser)]
 #[command(name = "blesschain-node")]
 pub struct Cli {
     #[command(subcommand)]
-    pub subcommand: Option<RunCmd>,
+    pub subcommand: Option<Subcommand>,
+
+    #[clap(flatten)]
+    pub run: RunCmd,
+
+    /// Author blocks on demand instead of running Aura and GRANDPA.
+    ///
+    /// Only allowed on development chains (e.g. `--dev`).
+    #[arg(long, value_enum, ignore_case = true)]
+    pub sealing: Option<Sealing>,
+
+    /// Log output format. Target filters (`-l`) apply to both.
+    #[arg(long, value_enum, ignore_case = true, global = true, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
+}
+
+/// Block authoring modes for fast local testing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum Sealing {
+    /// Author and finalize a block as soon as a transaction enters the pool.
+    Instant,
+    /// Author blocks only when `engine_createBlock` is called over RPC.
+    Manual,
+}
+
+/// How log records are written.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Human readable lines, as printed by Substrate.
+    Text,
+    /// One JSON object per record on stderr.
+    Json,
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[allow(clippy::large_enum_variant)]
+pub enum Subcommand {
+    /// Key management cli utilities
+    #[command(subcommand)]
+    Key(sc_cli::KeySubcommand),
+
+    /// Build a chain specification.
+    BuildSpec(sc_cli::BuildSpecCmd),
+
+    /// Validate blocks.
+    CheckBlock(sc_cli::CheckBlockCmd),
+
+    /// Export blocks.
+    ExportBlocks(sc_cli::ExportBlocksCmd),
+
+    /// Export the state of a given block into a chain spec.
+    ExportState(sc_cli::ExportStateCmd),
+
+    /// Import blocks.
+    ImportBlocks(sc_cli::ImportBlocksCmd),
+
+    /// Remove the whole chain.
+    PurgeChain(sc_cli::PurgeChainCmd),
+
+    /// Revert the chain to a previous state.
+    Revert(sc_cli::RevertCmd),
+
+    /// Db meta columns information.
+    ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Benchmark runtime pallets.
+    #[command(subcommand)]
+    Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+    /// Replay runtime upgrade migrations or blocks against a snapshot of state.
+    #[cfg(feature = "try-runtime")]
+    TryRuntime(try_runtime_cli::TryRuntimeCmd),
+
+    /// Try-runtime is only available when built with `--features try-runtime`.
+    #[cfg(not(feature = "try-runtime"))]
+    TryRuntime,
 }
 
 impl SubstrateCli for Cli {
@@ -20,7 +97,7 @@ impl SubstrateCli for Cli {
     }
 
     fn description() -> String {
-        "BlessChain minimal node".into()
+        "BlessChain node".into()
     }
 
     fn author() -> String {
@@ -35,8 +112,11 @@ impl SubstrateCli for Cli {
         2025
     }
 
-    fn load_spec(&self, _: &str) -> Result<Box<dyn ChainSpec>, String> {
-        Ok(Box::new(crate::chain_spec::development_config()?))
+    fn load_spec(&self, id: &str) -> Result<Box<dyn ChainSpec>, String> {
+        Ok(match id {
+            "dev" => Box::new(chain_spec::development_config()?),
+            "" | "local" => Box::new(chain_spec::local_testnet_config()?),
+            path => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
+        })
     }
 }
-
@@ -1,11 +1,167 @@
 //! CLI command dispatcher for BlessChain.
 
-use crate::cli::Cli;
+use blesschain_runtime::ExistentialDeposit;
+use clap::Parser;
+use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory};
+use sc_service::{ChainType, PartialComponents};
+use sp_core::Get;
+use sp_keyring::Sr25519Keyring;
+
+use crate::{
+    benchmarking::{
+        inherent_benchmark_data, reference_hardware, RemarkBuilder, TransferKeepAliveBuilder,
+    },
+    cli::{Cli, Subcommand},
+    logging::create_runner,
+    service,
+};
+
+pub fn run() -> sc_cli::Result<()> {
+    let cli = Cli::parse();
 
-pub fn run(cli: Cli) -> sc_cli::Result<()> {
     match &cli.subcommand {
-        None => cli.run(),
-        Some(cmd) => cmd.run(),
+        Some(Subcommand::Key(cmd)) => cmd.run(&cli),
+        Some(Subcommand::BuildSpec(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
+        },
+        Some(Subcommand::CheckBlock(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.async_run(|config| {
+                let PartialComponents { client, task_manager, import_queue, .. } =
+                    service::new_partial(&config)?;
+                Ok((cmd.run(client, import_queue), task_manager))
+            })
+        },
+        Some(Subcommand::ExportBlocks(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.async_run(|config| {
+                let PartialComponents { client, task_manager, .. } =
+                    service::new_partial(&config)?;
+                Ok((cmd.run(client, config.database), task_manager))
+            })
+        },
+        Some(Subcommand::ExportState(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.async_run(|config| {
+                let PartialComponents { client, task_manager, .. } =
+                    service::new_partial(&config)?;
+                Ok((cmd.run(client, config.chain_spec), task_manager))
+            })
+        },
+        Some(Subcommand::ImportBlocks(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.async_run(|config| {
+                let PartialComponents { client, task_manager, import_queue, .. } =
+                    service::new_partial(&config)?;
+                Ok((cmd.run(client, import_queue), task_manager))
+            })
+        },
+        Some(Subcommand::PurgeChain(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.sync_run(|config| cmd.run(config.database))
+        },
+        Some(Subcommand::Revert(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.async_run(|config| {
+                let PartialComponents { client, task_manager, backend, .. } =
+                    service::new_partial(&config)?;
+                let aux_revert = Box::new(|client, _, blocks| {
+                    sc_consensus_grandpa::revert(client, blocks)?;
+                    Ok(())
+                });
+                Ok((cmd.run(client, backend, Some(aux_revert)), task_manager))
+            })
+        },
+        Some(Subcommand::ChainInfo(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.sync_run(|config| cmd.run::<blesschain_runtime::opaque::Block>(&config))
+        },
+        Some(Subcommand::Benchmark(cmd)) => {
+            let runner = create_runner(&cli, cmd)?;
+            runner.sync_run(|config| match cmd {
+                BenchmarkCmd::Pallet(cmd) => {
+                    if !cfg!(feature = "runtime-benchmarks") {
+                        return Err("Runtime benchmarking wasn't enabled when building the node. \
+                            Rebuild with `--features runtime-benchmarks`."
+                            .into())
+                    }
+                    cmd.run::<blesschain_runtime::opaque::Block, service::ExecutorDispatch>(config)
+                },
+                BenchmarkCmd::Block(cmd) => {
+                    let PartialComponents { client, .. } = service::new_partial(&config)?;
+                    cmd.run(client)
+                },
+                #[cfg(not(feature = "runtime-benchmarks"))]
+                BenchmarkCmd::Storage(_) => Err(
+                    "Storage benchmarking can be enabled with `--features runtime-benchmarks`."
+                        .into(),
+                ),
+                #[cfg(feature = "runtime-benchmarks")]
+                BenchmarkCmd::Storage(cmd) => {
+                    let PartialComponents { client, backend, .. } = service::new_partial(&config)?;
+                    let db = backend.expose_db();
+                    let storage = backend.expose_storage();
+
+                    cmd.run(config, client, db, storage)
+                },
+                BenchmarkCmd::Overhead(cmd) => {
+                    let PartialComponents { client, .. } = service::new_partial(&config)?;
+                    let ext_builder = RemarkBuilder::new(client.clone());
+
+                    cmd.run(config, client, inherent_benchmark_data()?, Vec::new(), &ext_builder)
+                },
+                BenchmarkCmd::Extrinsic(cmd) => {
+                    let PartialComponents { client, .. } = service::new_partial(&config)?;
+                    let ext_factory = ExtrinsicFactory(vec![
+                        Box::new(RemarkBuilder::new(client.clone())),
+                        Box::new(TransferKeepAliveBuilder::new(
+                            client.clone(),
+                            Sr25519Keyring::Alice.to_account_id(),
+                            ExistentialDeposit::get(),
+                        )),
+                    ]);
+
+                    cmd.run(client, inherent_benchmark_data()?, Vec::new(), &ext_factory)
+                },
+                BenchmarkCmd::Machine(cmd) => cmd.run(&config, reference_hardware()),
+            })
+        },
+        #[cfg(feature = "try-runtime")]
+        Some(Subcommand::TryRuntime(cmd)) => {
+            use blesschain_runtime::{constants::SLOT_DURATION, opaque::Block};
+            use sc_executor::{sp_wasm_interface::ExtendedHostFunctions, NativeExecutionDispatch};
+            use try_runtime_cli::block_building_info::timestamp_with_aura_info;
+
+            let runner = create_runner(&cli, cmd)?;
+            runner.async_run(|config| {
+                let registry = config.prometheus_config.as_ref().map(|cfg| &cfg.registry);
+                let task_manager = sc_service::TaskManager::new(config.tokio_handle.clone(), registry)
+                    .map_err(|e| sc_cli::Error::Service(sc_service::Error::Prometheus(e)))?;
+                let info_provider = timestamp_with_aura_info(SLOT_DURATION);
+
+                Ok((
+                    cmd.run::<Block, ExtendedHostFunctions<
+                        sp_io::SubstrateHostFunctions,
+                        <service::ExecutorDispatch as NativeExecutionDispatch>::ExtendHostFunctions,
+                    >, _>(Some(info_provider)),
+                    task_manager,
+                ))
+            })
+        },
+        #[cfg(not(feature = "try-runtime"))]
+        Some(Subcommand::TryRuntime) => Err("try-runtime was not enabled when building the node. \
+            Rebuild with `--features try-runtime`."
+            .into()),
+        None => {
+            let runner = create_runner(&cli, &cli.run)?;
+            let sealing = cli.sealing;
+            runner.run_node_until_exit(|config| async move {
+                if sealing.is_some() && config.chain_spec.chain_type() != ChainType::Development {
+                    return Err("--sealing is only supported on development chains".into())
+                }
+                service::new_full(config, sealing).map_err(sc_cli::Error::Service)
+            })
+        },
     }
 }
-
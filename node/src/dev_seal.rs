@@ -0,0 +1,217 @@
+//! Instant and manual sealing for local development.
+//!
+//! `--sealing instant` authors (and finalizes) a block as soon as a transaction
+//! enters the pool; `--sealing manual` only authors when `engine_createBlock` is
+//! called. Neither runs Aura slots or GRANDPA, so integration tests no longer wait
+//! for `SLOT_DURATION` per block.
+
+use std::sync::Arc;
+
+use blesschain_runtime::{opaque::Block, Hash, Runtime, RuntimeApi};
+use codec::Decode;
+use futures::{channel::mpsc, FutureExt};
+use sc_client_api::StorageProvider;
+use sc_consensus_aura::SlotDuration;
+use sc_consensus_manual_seal::{
+    consensus::aura::AuraConsensusDataProvider, InstantSealParams, ManualSealParams,
+};
+use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
+use sp_core::storage::StorageKey;
+
+use crate::{
+    cli::Sealing,
+    import_queue::BlessBlockImport,
+    metrics::{self, ImportMetrics, NodeMetrics},
+    service::{new_executor, open_rocksdb_stats, FullBackend, FullClient},
+};
+
+const LOG_TARGET: &str = "sealing";
+
+/// Capacity of the `engine_*` RPC command queue.
+const COMMAND_QUEUE_SIZE: usize = 1024;
+
+/// Builds a development node that seals blocks on demand.
+pub fn new_dev(config: Configuration, sealing: Sealing) -> Result<TaskManager, ServiceError> {
+    let (client, backend, keystore_container, mut task_manager) =
+        sc_service::new_full_parts::<Block, RuntimeApi, _>(&config, None, new_executor(&config))?;
+    let client = Arc::new(client);
+
+    let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+    let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+        config.transaction_pool.clone(),
+        config.role.is_authority().into(),
+        config.prometheus_registry(),
+        task_manager.spawn_essential_handle(),
+        client.clone(),
+    );
+
+    let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+    let import_metrics = config.prometheus_registry().map(ImportMetrics::register).transpose()?;
+    let block_import =
+        BlessBlockImport::new(client.clone(), client.clone(), slot_duration, import_metrics);
+
+    let import_queue = sc_consensus_manual_seal::import_queue(
+        Box::new(block_import.clone()),
+        &task_manager.spawn_essential_handle(),
+        config.prometheus_registry(),
+    );
+
+    let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
+        sc_service::build_network(sc_service::BuildNetworkParams {
+            config: &config,
+            client: client.clone(),
+            transaction_pool: transaction_pool.clone(),
+            spawn_handle: task_manager.spawn_handle(),
+            import_queue,
+            block_announce_validator_builder: None,
+            warp_sync_params: None,
+        })?;
+
+    crate::offchain::start(
+        &config,
+        &task_manager,
+        client.clone(),
+        keystore_container.keystore(),
+        network.clone(),
+    );
+
+    let prometheus_registry = config.prometheus_registry().cloned();
+    let rocksdb_stats = open_rocksdb_stats(&config);
+
+    if let Some(registry) = prometheus_registry.as_ref() {
+        task_manager.spawn_handle().spawn(
+            "blesschain-metrics",
+            None,
+            metrics::run(
+                client.clone(),
+                transaction_pool.clone(),
+                network.clone(),
+                rocksdb_stats,
+                NodeMetrics::register(registry)?,
+            ),
+        );
+    }
+
+    task_manager.spawn_handle().spawn(
+        "runtime-execution",
+        None,
+        crate::execution::watch(client.clone(), config.execution_strategies.importing),
+    );
+
+    // `engine_*` RPCs are only exposed when blocks are sealed manually.
+    let (command_sink, commands_stream) = mpsc::channel(COMMAND_QUEUE_SIZE);
+    let command_sink = (sealing == Sealing::Manual).then_some(command_sink);
+
+    let rpc_extensions_builder = {
+        let client = client.clone();
+        let pool = transaction_pool.clone();
+
+        Box::new(move |deny_unsafe, _| {
+            let deps = crate::rpc::FullDeps::<_, _, FullBackend> {
+                client: client.clone(),
+                pool: pool.clone(),
+                deny_unsafe,
+                grandpa: None,
+                command_sink: command_sink.clone(),
+            };
+            crate::rpc::create_full(deps).map_err(Into::into)
+        })
+    };
+
+    let _rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+        network,
+        client: client.clone(),
+        keystore: keystore_container.keystore(),
+        task_manager: &mut task_manager,
+        transaction_pool: transaction_pool.clone(),
+        rpc_builder: rpc_extensions_builder,
+        backend,
+        system_rpc_tx,
+        tx_handler_controller,
+        sync_service,
+        config,
+        telemetry: None,
+    })?;
+
+    let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+        task_manager.spawn_handle(),
+        client.clone(),
+        transaction_pool.clone(),
+        prometheus_registry.as_ref(),
+        None,
+    );
+
+    let create_inherent_data_providers = {
+        let client = client.clone();
+        move |parent, ()| {
+            let client = client.clone();
+            async move {
+                let timestamp = sp_timestamp::InherentDataProvider::new(
+                    next_timestamp(&client, parent, slot_duration).into(),
+                );
+
+                let slot =
+                    sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+                        *timestamp,
+                        slot_duration,
+                    );
+
+                Ok((slot, timestamp))
+            }
+        }
+    };
+
+    let consensus_data_provider = Box::new(AuraConsensusDataProvider::new(client.clone()));
+
+    let authorship = match sealing {
+        Sealing::Manual =>
+            sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+                block_import,
+                env: proposer_factory,
+                client,
+                pool: transaction_pool,
+                commands_stream,
+                select_chain,
+                consensus_data_provider: Some(consensus_data_provider),
+                create_inherent_data_providers,
+            })
+            .boxed(),
+        Sealing::Instant =>
+            sc_consensus_manual_seal::run_instant_seal_and_finalize(InstantSealParams {
+                block_import,
+                env: proposer_factory,
+                client,
+                pool: transaction_pool,
+                select_chain,
+                consensus_data_provider: Some(consensus_data_provider),
+                create_inherent_data_providers,
+            })
+            .boxed(),
+    };
+
+    task_manager
+        .spawn_essential_handle()
+        .spawn_blocking("manual-seal", Some("block-authoring"), authorship);
+
+    log::info!(target: LOG_TARGET, "Sealing mode: {:?}", sealing);
+
+    network_starter.start_network();
+    Ok(task_manager)
+}
+
+/// Timestamp for the block built on `parent`: the wall clock, but at least one
+/// slot after the parent so every block lands in a fresh Aura slot even when
+/// blocks are sealed faster than `SLOT_DURATION`.
+fn next_timestamp(client: &FullClient, parent: Hash, slot_duration: SlotDuration) -> u64 {
+    let key = StorageKey(pallet_timestamp::Now::<Runtime>::hashed_key().to_vec());
+    let parent_timestamp = client
+        .storage(parent, &key)
+        .ok()
+        .flatten()
+        .and_then(|data| u64::decode(&mut &data.0[..]).ok())
+        .unwrap_or_default();
+
+    let now = sp_timestamp::Timestamp::current().as_millis();
+    now.max(parent_timestamp + slot_duration.as_millis())
+}
@@ -0,0 +1,96 @@
+//! Native vs WASM runtime execution.
+//!
+//! `--execution` (and the per-context `--execution-syncing`, `--execution-import-block`,
+//! ...) selects how runtime calls are executed:
+//!
+//! * `native`: the runtime compiled into this binary, as long as it matches the
+//!   on-chain runtime, otherwise the on-chain WASM;
+//! * `wasm`: always the on-chain WASM;
+//! * `both`: native and WASM side by side; on differing results Substrate logs
+//!   "Consensus error between wasm ... and native ..." and keeps the WASM result;
+//! * `native-else-wasm` (the default): native, falling back to WASM if native fails.
+//!
+//! Native execution needs the on-chain `spec_name`, `spec_version` and
+//! `authoring_version` to equal the native ones. [`watch`] reports which runtime
+//! is actually used whenever the on-chain runtime changes, so an operator notices
+//! when a node silently falls back to WASM (or `both` stops comparing anything)
+//! after a runtime upgrade.
+
+use std::sync::Arc;
+
+use blesschain_runtime::opaque::Block;
+use futures::StreamExt;
+use sc_client_api::{BlockchainEvents, ExecutionStrategy};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use sp_version::RuntimeVersion;
+
+use crate::service::FullClient;
+
+const LOG_TARGET: &str = "execution";
+
+/// Log the execution path for the best block's runtime, then again every time
+/// a new best block changes the on-chain runtime version.
+pub async fn watch(client: Arc<FullClient>, strategy: ExecutionStrategy) {
+    let native = blesschain_runtime::native_version().runtime_version;
+    let mut imports = client.import_notification_stream();
+
+    let mut current = on_chain_version(&client, client.info().best_hash);
+    if let Some(on_chain) = current.as_ref() {
+        report(&native, on_chain, strategy);
+    }
+
+    while let Some(notification) = imports.next().await {
+        if !notification.is_new_best {
+            continue
+        }
+        let Some(on_chain) = on_chain_version(&client, notification.hash) else { continue };
+        if current.as_ref() != Some(&on_chain) {
+            report(&native, &on_chain, strategy);
+            current = Some(on_chain);
+        }
+    }
+}
+
+fn on_chain_version(client: &FullClient, hash: <Block as BlockT>::Hash) -> Option<RuntimeVersion> {
+    client
+        .runtime_version_at(hash)
+        .map_err(|e| log::debug!(target: LOG_TARGET, "Runtime version at {hash:?} unavailable: {e}"))
+        .ok()
+}
+
+fn report(native: &RuntimeVersion, on_chain: &RuntimeVersion, strategy: ExecutionStrategy) {
+    let compatible = native.can_call_with(on_chain);
+
+    match strategy {
+        ExecutionStrategy::AlwaysWasm => log::info!(
+            target: LOG_TARGET,
+            "Executing on-chain runtime {} v{} in WASM",
+            on_chain.spec_name,
+            on_chain.spec_version,
+        ),
+        _ if compatible => log::info!(
+            target: LOG_TARGET,
+            "On-chain runtime {} v{} matches the native runtime; executing with {:?}",
+            on_chain.spec_name,
+            on_chain.spec_version,
+            strategy,
+        ),
+        ExecutionStrategy::Both => log::warn!(
+            target: LOG_TARGET,
+            "On-chain runtime {} v{} differs from native v{}; only WASM runs, native/WASM \
+             results are not compared until the node binary is upgraded",
+            on_chain.spec_name,
+            on_chain.spec_version,
+            native.spec_version,
+        ),
+        _ => log::warn!(
+            target: LOG_TARGET,
+            "On-chain runtime {} v{} differs from native v{}; falling back to WASM \
+             until the node binary is upgraded",
+            on_chain.spec_name,
+            on_chain.spec_version,
+            native.spec_version,
+        ),
+    }
+}
@@ -0,0 +1,147 @@
+//! Longest-chain fork choice with reorg routes.
+//!
+//! Kept free of client types so competing forks can be tested against an
+//! in-memory block tree; `import_queue` plugs the client in through [`ChainView`].
+
+use std::fmt::Debug;
+
+#[cfg(test)]
+mod tests;
+
+/// A block as seen by the fork-choice rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockRef<H, N> {
+    pub hash: H,
+    pub number: N,
+}
+
+/// Read access to already imported blocks.
+pub trait ChainView<H, N> {
+    /// Parent of `block`, or `None` if `block` is unknown.
+    fn parent(&self, block: &BlockRef<H, N>) -> Option<BlockRef<H, N>>;
+
+    /// Whether `block` has been imported.
+    fn contains(&self, block: &BlockRef<H, N>) -> bool;
+}
+
+/// Blocks to retract and enact when the best chain switches forks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reorg<H, N> {
+    /// Last block shared by the old and the new best chain.
+    pub common_ancestor: BlockRef<H, N>,
+    /// Blocks leaving the best chain, from the old best block down.
+    pub retracted: Vec<BlockRef<H, N>>,
+    /// Blocks joining the best chain, from the ancestor up to the new best block.
+    pub enacted: Vec<BlockRef<H, N>>,
+}
+
+impl<H, N> Reorg<H, N> {
+    /// Number of blocks rolled back.
+    pub fn depth(&self) -> usize {
+        self.retracted.len()
+    }
+}
+
+/// Outcome of feeding a newly imported block to the fork-choice rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ForkChoice<H, N> {
+    /// The block builds on the current best block.
+    Extend,
+    /// The block is on another fork that is now strictly longer than the best chain.
+    Reorg(Reorg<H, N>),
+    /// The block is on a fork that is not longer than the best chain; keep the best.
+    Keep,
+}
+
+impl<H, N> ForkChoice<H, N> {
+    /// Whether the block becomes the new best block.
+    pub fn is_new_best(&self) -> bool {
+        !matches!(self, ForkChoice::Keep)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error<H, N> {
+    /// The candidate's parent has not been imported.
+    UnknownParent(H),
+    /// A block on the route is not known to the chain view.
+    UnknownBlock(H),
+    /// Switching to the fork would retract the finalized block.
+    RevertsFinalized { common_ancestor: N, finalized: N },
+}
+
+/// Apply the longest-chain rule to `candidate`, whose parent is `parent`.
+///
+/// Ties keep the current best block, so the first block seen at a height wins.
+/// A fork that would retract a finalized block is rejected, and so is a
+/// candidate whose parent is unknown, whatever its height.
+pub fn choose<H, N, V>(
+    view: &V,
+    best: &BlockRef<H, N>,
+    finalized: N,
+    candidate: &BlockRef<H, N>,
+    parent: &BlockRef<H, N>,
+) -> Result<ForkChoice<H, N>, Error<H, N>>
+where
+    H: Copy + PartialEq + Debug,
+    N: Copy + Ord + Debug,
+    V: ChainView<H, N>,
+{
+    if !view.contains(parent) {
+        return Err(Error::UnknownParent(parent.hash))
+    }
+    if parent.hash == best.hash {
+        return Ok(ForkChoice::Extend)
+    }
+    if candidate.number <= best.number {
+        return Ok(ForkChoice::Keep)
+    }
+
+    let mut route = route(view, best, parent)?;
+    if route.common_ancestor.number < finalized {
+        return Err(Error::RevertsFinalized {
+            common_ancestor: route.common_ancestor.number,
+            finalized,
+        })
+    }
+    route.enacted.push(*candidate);
+
+    Ok(ForkChoice::Reorg(route))
+}
+
+/// Route between two known blocks through their common ancestor.
+pub fn route<H, N, V>(
+    view: &V,
+    from: &BlockRef<H, N>,
+    to: &BlockRef<H, N>,
+) -> Result<Reorg<H, N>, Error<H, N>>
+where
+    H: Copy + PartialEq + Debug,
+    N: Copy + Ord + Debug,
+    V: ChainView<H, N>,
+{
+    let parent_of = |block: &BlockRef<H, N>| view.parent(block).ok_or(Error::UnknownBlock(block.hash));
+
+    let mut from = *from;
+    let mut to = *to;
+    let mut retracted = Vec::new();
+    let mut enacted = Vec::new();
+
+    while from.number > to.number {
+        retracted.push(from);
+        from = parent_of(&from)?;
+    }
+    while to.number > from.number {
+        enacted.push(to);
+        to = parent_of(&to)?;
+    }
+    while from.hash != to.hash {
+        retracted.push(from);
+        enacted.push(to);
+        from = parent_of(&from)?;
+        to = parent_of(&to)?;
+    }
+
+    enacted.reverse();
+    Ok(Reorg { common_ancestor: from, retracted, enacted })
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use super::*;
+
+type Block = BlockRef<u64, u32>;
+
+/// In-memory block tree keyed by hash. Hashes are arbitrary labels.
+#[derive(Default)]
+struct Tree {
+    parents: HashMap<u64, Block>,
+    blocks: HashMap<u64, Block>,
+}
+
+impl Tree {
+    fn new() -> Self {
+        let mut tree = Tree::default();
+        tree.blocks.insert(0, Block { hash: 0, number: 0 });
+        tree
+    }
+
+    fn import(&mut self, hash: u64, parent: u64) -> Block {
+        let parent = self.blocks[&parent];
+        let block = Block { hash, number: parent.number + 1 };
+        self.blocks.insert(hash, block);
+        self.parents.insert(hash, parent);
+        block
+    }
+
+    fn get(&self, hash: u64) -> Block {
+        self.blocks[&hash]
+    }
+}
+
+impl ChainView<u64, u32> for Tree {
+    fn parent(&self, block: &Block) -> Option<Block> {
+        self.parents.get(&block.hash).copied()
+    }
+
+    fn contains(&self, block: &Block) -> bool {
+        self.blocks.contains_key(&block.hash)
+    }
+}
+
+/// Genesis <- 1 <- 2 <- 3 (best), and a fork 2 <- 13.
+fn tree_with_fork() -> Tree {
+    let mut tree = Tree::new();
+    tree.import(1, 0);
+    tree.import(2, 1);
+    tree.import(3, 2);
+    tree.import(13, 2);
+    tree
+}
+
+#[test]
+fn block_on_best_extends() {
+    let mut tree = tree_with_fork();
+    let best = tree.get(3);
+    let candidate = tree.import(4, 3);
+
+    assert_eq!(choose(&tree, &best, 0, &candidate, &best), Ok(ForkChoice::Extend));
+}
+
+#[test]
+fn shorter_or_equal_fork_keeps_best() {
+    let mut tree = tree_with_fork();
+    let best = tree.get(3);
+
+    // Same height as the best block: first seen wins.
+    let fork = tree.get(13);
+    let parent = tree.get(2);
+    assert_eq!(choose(&tree, &best, 0, &fork, &parent), Ok(ForkChoice::Keep));
+
+    // A fork from genesis that is shorter.
+    let side = tree.import(21, 0);
+    let side_child = tree.import(22, 21);
+    assert_eq!(choose(&tree, &best, 0, &side_child, &side), Ok(ForkChoice::Keep));
+}
+
+#[test]
+fn longer_fork_reorgs() {
+    let mut tree = tree_with_fork();
+    let best = tree.get(3);
+    let parent = tree.get(13);
+    let candidate = tree.import(14, 13);
+
+    let choice = choose(&tree, &best, 0, &candidate, &parent).unwrap();
+    assert!(choice.is_new_best());
+
+    let ForkChoice::Reorg(reorg) = choice else { panic!("expected a reorg, got {choice:?}") };
+    assert_eq!(reorg.common_ancestor, tree.get(2));
+    assert_eq!(reorg.retracted, vec![tree.get(3)]);
+    assert_eq!(reorg.enacted, vec![tree.get(13), candidate]);
+    assert_eq!(reorg.depth(), 1);
+}
+
+#[test]
+fn deep_reorg_lists_blocks_in_order() {
+    let mut tree = tree_with_fork();
+    let best = tree.get(3);
+    tree.import(31, 1);
+    tree.import(32, 31);
+    let parent = tree.import(33, 32);
+    let candidate = tree.import(34, 33);
+
+    let ForkChoice::Reorg(reorg) = choose(&tree, &best, 1, &candidate, &parent).unwrap() else {
+        panic!("expected a reorg")
+    };
+    assert_eq!(reorg.common_ancestor, tree.get(1));
+    assert_eq!(reorg.retracted, vec![tree.get(3), tree.get(2)]);
+    assert_eq!(
+        reorg.enacted,
+        vec![tree.get(31), tree.get(32), tree.get(33), candidate]
+    );
+}
+
+#[test]
+fn fork_below_finalized_is_rejected() {
+    let mut tree = tree_with_fork();
+    let best = tree.get(3);
+    let parent = tree.get(13);
+    let candidate = tree.import(14, 13);
+
+    // Block 3 is finalized, the fork branches off at 2.
+    assert_eq!(
+        choose(&tree, &best, 3, &candidate, &parent),
+        Err(Error::RevertsFinalized { common_ancestor: 2, finalized: 3 })
+    );
+}
+
+#[test]
+fn unknown_parent_is_an_error() {
+    let tree = tree_with_fork();
+    let best = tree.get(3);
+    let parent = Block { hash: 99, number: 5 };
+    let candidate = Block { hash: 100, number: 6 };
+
+    assert_eq!(
+        choose(&tree, &best, 0, &candidate, &parent),
+        Err(Error::UnknownParent(99))
+    );
+
+    // Also below the best block, where a known parent would just keep the best.
+    let parent = Block { hash: 98, number: 1 };
+    let candidate = Block { hash: 101, number: 2 };
+    assert_eq!(
+        choose(&tree, &best, 0, &candidate, &parent),
+        Err(Error::UnknownParent(98))
+    );
+}
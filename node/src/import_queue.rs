@@ -0,0 +1,252 @@
+//! Block import pipeline.
+//!
+//! Blocks coming from the network or from our own Aura worker go through:
+//!
+//! 1. the Aura verifier, which checks that the seal was produced by the slot's
+//!    authority over the pre-hash, that the slot is not in the future, and the
+//!    inherents (including the timestamp drift) against our local clock;
+//! 2. [`BlessBlockImport`], which checks the timestamp inherent against the header
+//!    slot and `SLOT_DURATION` and applies the longest-chain rule from
+//!    [`crate::fork_choice`], refusing forks that retract the finalized block;
+//! 3. the GRANDPA block import, which tracks authority set changes;
+//! 4. the client, which executes the block through the runtime and stores it.
+
+use std::{marker::PhantomData, sync::Arc, time::Instant};
+
+use blesschain_runtime::{opaque::Block, RuntimeCall, UncheckedExtrinsic};
+use codec::{Decode, Encode};
+use sc_consensus::{
+    BlockCheckParams, BlockImport, BlockImportParams, ForkChoiceStrategy, ImportResult,
+};
+use sc_consensus_aura::{ImportQueueParams, SlotDuration};
+use sc_telemetry::TelemetryHandle;
+use sp_blockchain::{HeaderBackend, HeaderMetadata};
+use sp_consensus::Error as ConsensusError;
+use sp_consensus_aura::sr25519::{AuthorityPair as AuraPair, AuthoritySignature as AuraSignature};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
+use substrate_prometheus_endpoint::Registry;
+
+use crate::{
+    fork_choice::{self, BlockRef, ChainView, ForkChoice},
+    metrics::ImportMetrics,
+    service::FullClient,
+};
+
+const LOG_TARGET: &str = "import";
+
+/// Build the Aura import queue on top of `block_import`.
+pub fn build_import_queue<I, J>(
+    client: Arc<FullClient>,
+    block_import: I,
+    justification_import: J,
+    slot_duration: SlotDuration,
+    spawner: &impl sp_core::traits::SpawnEssentialNamed,
+    registry: Option<&Registry>,
+    telemetry: Option<TelemetryHandle>,
+) -> Result<sc_consensus::DefaultImportQueue<Block, FullClient>, ConsensusError>
+where
+    I: BlockImport<Block, Error = ConsensusError, Transaction = sp_api::TransactionFor<FullClient, Block>>
+        + Send
+        + Sync
+        + 'static,
+    J: sc_consensus::JustificationImport<Block, Error = ConsensusError> + Send + Sync + 'static,
+{
+    sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(ImportQueueParams {
+        block_import,
+        justification_import: Some(Box::new(justification_import)),
+        client,
+        create_inherent_data_providers: move |_, ()| async move {
+            let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+
+            let slot =
+                sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+                    *timestamp,
+                    slot_duration,
+                );
+
+            Ok((slot, timestamp))
+        },
+        spawner,
+        registry,
+        check_for_equivocation: sc_consensus_aura::CheckForEquivocation::Yes,
+        telemetry,
+        compatibility_mode: Default::default(),
+    })
+}
+
+/// Block import wrapper enforcing slot timing and longest-chain fork choice.
+pub struct BlessBlockImport<I, C> {
+    inner: I,
+    client: Arc<C>,
+    slot_duration: SlotDuration,
+    metrics: Option<ImportMetrics>,
+    _phantom: PhantomData<Block>,
+}
+
+impl<I: Clone, C> Clone for BlessBlockImport<I, C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            client: self.client.clone(),
+            slot_duration: self.slot_duration,
+            metrics: self.metrics.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, C> BlessBlockImport<I, C>
+where
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error = sp_blockchain::Error>,
+{
+    pub fn new(
+        inner: I,
+        client: Arc<C>,
+        slot_duration: SlotDuration,
+        metrics: Option<ImportMetrics>,
+    ) -> Self {
+        Self { inner, client, slot_duration, metrics, _phantom: PhantomData }
+    }
+
+    /// The timestamp inherent must fall inside the slot announced by the Aura seal.
+    fn check_timestamp(
+        &self,
+        header: &<Block as BlockT>::Header,
+        body: &[<Block as BlockT>::Extrinsic],
+    ) -> Result<(), ConsensusError> {
+        let slot = sc_consensus_aura::find_pre_digest::<Block, AuraSignature>(header)
+            .map_err(|e| ConsensusError::ClientImport(e.to_string()))?;
+        let timestamp = timestamp_of(body).ok_or_else(|| {
+            ConsensusError::ClientImport(format!(
+                "block #{} has no timestamp inherent",
+                header.number()
+            ))
+        })?;
+
+        let expected_slot = timestamp / self.slot_duration.as_millis();
+        if expected_slot != *slot {
+            return Err(ConsensusError::ClientImport(format!(
+                "timestamp {timestamp} of block #{} belongs to slot {expected_slot}, header claims slot {}",
+                header.number(),
+                *slot,
+            )))
+        }
+
+        Ok(())
+    }
+
+    /// `None` if the parent is unknown.
+    fn fork_choice(
+        &self,
+        header: &<Block as BlockT>::Header,
+        hash: <Block as BlockT>::Hash,
+    ) -> Result<Option<ForkChoice<<Block as BlockT>::Hash, NumberFor<Block>>>, ConsensusError> {
+        let info = self.client.info();
+        let best = BlockRef { hash: info.best_hash, number: info.best_number };
+        let candidate = BlockRef { hash, number: *header.number() };
+        let parent = BlockRef {
+            hash: *header.parent_hash(),
+            number: header.number().saturating_sub(1),
+        };
+
+        match fork_choice::choose(
+            &ClientView(&*self.client),
+            &best,
+            info.finalized_number,
+            &candidate,
+            &parent,
+        ) {
+            Ok(choice) => Ok(Some(choice)),
+            Err(fork_choice::Error::UnknownParent(_)) => Ok(None),
+            Err(e) => Err(ConsensusError::ClientImport(format!("fork choice failed: {e:?}"))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<I, C> BlockImport<Block> for BlessBlockImport<I, C>
+where
+    I: BlockImport<Block, Error = ConsensusError> + Send + Sync,
+    I::Transaction: Send + 'static,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error = sp_blockchain::Error> + Send + Sync,
+{
+    type Error = ConsensusError;
+    type Transaction = I::Transaction;
+
+    async fn check_block(
+        &mut self,
+        block: BlockCheckParams<Block>,
+    ) -> Result<ImportResult, Self::Error> {
+        self.inner.check_block(block).await
+    }
+
+    async fn import_block(
+        &mut self,
+        mut block: BlockImportParams<Block, Self::Transaction>,
+    ) -> Result<ImportResult, Self::Error> {
+        let number = *block.header.number();
+        let hash = block.post_hash();
+
+        if let Some(body) = block.body.as_ref() {
+            self.check_timestamp(&block.header, body)?;
+        }
+
+        // Nothing is imported without a fork choice; the sync fetches the parent.
+        let Some(choice) = self.fork_choice(&block.header, hash)? else {
+            return Ok(ImportResult::UnknownParent)
+        };
+        if let ForkChoice::Reorg(reorg) = &choice {
+            log::info!(
+                target: LOG_TARGET,
+                "Reorg to #{} ({:?}): retracting {} block(s) back to #{} ({:?})",
+                number,
+                hash,
+                reorg.depth(),
+                reorg.common_ancestor.number,
+                reorg.common_ancestor.hash,
+            );
+            if let Some(metrics) = self.metrics.as_ref() {
+                metrics.observe_reorg(reorg.depth());
+            }
+        }
+        block.fork_choice = Some(ForkChoiceStrategy::Custom(choice.is_new_best()));
+
+        let started = Instant::now();
+        let result = self.inner.import_block(block).await;
+        if let (Ok(ImportResult::Imported(_)), Some(metrics)) = (&result, self.metrics.as_ref()) {
+            metrics.observe_import(started.elapsed());
+        }
+        result
+    }
+}
+
+/// Decode the `Timestamp::set` inherent from an opaque block body.
+fn timestamp_of(body: &[<Block as BlockT>::Extrinsic]) -> Option<u64> {
+    body.iter().find_map(|xt| {
+        let xt = UncheckedExtrinsic::decode(&mut &xt.encode()[..]).ok()?;
+        match xt.function {
+            RuntimeCall::Timestamp(pallet_timestamp::Call::set { now }) => Some(now),
+            _ => None,
+        }
+    })
+}
+
+/// [`ChainView`] over the client's header metadata cache.
+struct ClientView<'a, C>(&'a C);
+
+impl<C> ChainView<<Block as BlockT>::Hash, NumberFor<Block>> for ClientView<'_, C>
+where
+    C: HeaderMetadata<Block, Error = sp_blockchain::Error>,
+{
+    fn parent(
+        &self,
+        block: &BlockRef<<Block as BlockT>::Hash, NumberFor<Block>>,
+    ) -> Option<BlockRef<<Block as BlockT>::Hash, NumberFor<Block>>> {
+        let meta = self.0.header_metadata(block.hash).ok()?;
+        Some(BlockRef { hash: meta.parent, number: meta.number.saturating_sub(1) })
+    }
+
+    fn contains(&self, block: &BlockRef<<Block as BlockT>::Hash, NumberFor<Block>>) -> bool {
+        self.0.header_metadata(block.hash).is_ok()
+    }
+}
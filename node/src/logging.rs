@@ -0,0 +1,68 @@
+//! Log output formats.
+//!
+//! `--log-format text` (the default) keeps Substrate's own logger. With
+//! `--log-format json` every record is written to stderr as one JSON object per
+//! line, ready for local log shippers. Both honour the `-l`/`--log` target
+//! directives, e.g. `-l sync=debug,runtime=trace`.
+//!
+//! Runtime `log` macros (targets under `runtime`) reach the node through the
+//! `sp_io::logging` host function and end up in the same `log` facade, so they
+//! are filtered and formatted like node logs.
+
+use sc_cli::{CliConfiguration, Runner, SubstrateCli};
+use tracing_subscriber::EnvFilter;
+
+use crate::cli::{Cli, LogFormat};
+
+/// Level for targets without a directive, as in Substrate's logger.
+const DEFAULT_DIRECTIVE: &str = "info";
+
+/// Like [`SubstrateCli::create_runner`], but installs the logger selected by
+/// `--log-format`.
+pub fn create_runner<T: CliConfiguration>(cli: &Cli, command: &T) -> sc_cli::Result<Runner<Cli>> {
+    match cli.log_format {
+        LogFormat::Text => cli.create_runner(command),
+        LogFormat::Json => {
+            let tokio_runtime = sc_cli::build_runtime()?;
+            let config = command.create_configuration(cli, tokio_runtime.handle().clone())?;
+
+            sp_panic_handler::set(&Cli::support_url(), &Cli::impl_version());
+            init_json(&command.log_filters()?)?;
+            if let Some(new_limit) = fdlimit::raise_fd_limit() {
+                if new_limit < sc_cli::RECOMMENDED_OPEN_FILE_DESCRIPTOR_LIMIT {
+                    log::warn!(
+                        "Low open file descriptor limit configured for the process. \
+                         Current value: {new_limit:?}, recommended value: {:?}.",
+                        sc_cli::RECOMMENDED_OPEN_FILE_DESCRIPTOR_LIMIT,
+                    );
+                }
+            }
+
+            Runner::new(config, tokio_runtime)
+        },
+    }
+}
+
+/// Install a global JSON subscriber filtered by the comma separated `directives`.
+fn init_json(directives: &str) -> sc_cli::Result<()> {
+    let mut filter = EnvFilter::new(DEFAULT_DIRECTIVE);
+    for directive in directives.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        let directive = directive
+            .parse()
+            .map_err(|e| format!("invalid log directive `{directive}`: {e}"))?;
+        filter = filter.add_directive(directive);
+    }
+
+    tracing_log::LogTracer::init().map_err(|e| format!("failed to forward `log` records: {e}"))?;
+
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_current_span(false)
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| format!("failed to install the JSON logger: {e}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,239 @@
+//! BlessChain Prometheus metrics, served on `--prometheus-port` next to the
+//! standard Substrate ones.
+//!
+//! * `blesschain_block_height{status="best|finalized"}`
+//! * `blesschain_block_import_seconds`, `blesschain_reorgs_total`, `blesschain_reorg_depth`
+//! * `blesschain_txpool_transactions{status="ready|future"}`
+//! * `blesschain_peers`
+//! * `blesschain_rocksdb{property=...}` and `blesschain_rocksdb_memory_bytes{kind=...}`
+//!
+//! RocksDB figures come from a secondary instance of the node's database opened
+//! with the vendored `rocksdb` crate; it follows the primary by replaying its
+//! WAL, so file, key and mem-table numbers track the node while its own block
+//! cache does not (and is therefore not exported).
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use blesschain_runtime::opaque::Block;
+use sc_network::NetworkStatusProvider;
+use sc_transaction_pool_api::TransactionPool;
+use sp_blockchain::HeaderBackend;
+use substrate_prometheus_endpoint::{
+    register, Counter, Gauge, GaugeVec, Histogram, HistogramOpts, Opts, PrometheusError,
+    Registry, U64,
+};
+
+const LOG_TARGET: &str = "metrics";
+
+/// How often gauges are refreshed.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(6);
+
+/// Integer RocksDB properties exported as `blesschain_rocksdb{property=...}`,
+/// summed over all column families.
+const ROCKSDB_PROPERTIES: &[(&str, &std::ffi::CStr)] = &[
+    ("estimate_num_keys", rocksdb::properties::ESTIMATE_NUM_KEYS),
+    ("total_sst_files_size", rocksdb::properties::TOTAL_SST_FILES_SIZE),
+    ("live_sst_files_size", rocksdb::properties::LIVE_SST_FILES_SIZE),
+    ("estimate_live_data_size", rocksdb::properties::ESTIMATE_LIVE_DATA_SIZE),
+    ("cur_size_all_mem_tables", rocksdb::properties::CUR_SIZE_ALL_MEM_TABLES),
+    ("estimate_pending_compaction_bytes", rocksdb::properties::ESTIMATE_PENDING_COMPACTION_BYTES),
+    ("num_running_compactions", rocksdb::properties::NUM_RUNNING_COMPACTIONS),
+    ("num_running_flushes", rocksdb::properties::NUM_RUNNING_FLUSHES),
+    ("background_errors", rocksdb::properties::BACKGROUND_ERRORS),
+];
+
+/// Metrics recorded by the block import pipeline.
+#[derive(Clone)]
+pub struct ImportMetrics {
+    import_time: Histogram,
+    reorgs: Counter<U64>,
+    reorg_depth: Histogram,
+}
+
+impl ImportMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            import_time: register(
+                Histogram::with_opts(
+                    HistogramOpts::new(
+                        "blesschain_block_import_seconds",
+                        "Time spent importing a block, including runtime execution",
+                    )
+                    .buckets(vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]),
+                )?,
+                registry,
+            )?,
+            reorgs: register(
+                Counter::new("blesschain_reorgs_total", "Number of best chain reorganisations")?,
+                registry,
+            )?,
+            reorg_depth: register(
+                Histogram::with_opts(
+                    HistogramOpts::new("blesschain_reorg_depth", "Blocks retracted per reorg")
+                        .buckets(vec![1.0, 2.0, 3.0, 5.0, 10.0, 25.0, 100.0]),
+                )?,
+                registry,
+            )?,
+        })
+    }
+
+    pub fn observe_import(&self, elapsed: Duration) {
+        self.import_time.observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_reorg(&self, depth: usize) {
+        self.reorgs.inc();
+        self.reorg_depth.observe(depth as f64);
+    }
+}
+
+/// Gauges refreshed every [`UPDATE_INTERVAL`] by [`run`].
+#[derive(Clone)]
+pub struct NodeMetrics {
+    block_height: GaugeVec<U64>,
+    txpool: GaugeVec<U64>,
+    peers: Gauge<U64>,
+    rocksdb: GaugeVec<U64>,
+    rocksdb_memory: GaugeVec<U64>,
+}
+
+impl NodeMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            block_height: register(
+                GaugeVec::new(
+                    Opts::new("blesschain_block_height", "Best and finalized block numbers"),
+                    &["status"],
+                )?,
+                registry,
+            )?,
+            txpool: register(
+                GaugeVec::new(
+                    Opts::new("blesschain_txpool_transactions", "Transactions in the pool"),
+                    &["status"],
+                )?,
+                registry,
+            )?,
+            peers: register(Gauge::new("blesschain_peers", "Connected peers")?, registry)?,
+            rocksdb: register(
+                GaugeVec::new(
+                    Opts::new("blesschain_rocksdb", "RocksDB integer properties"),
+                    &["property"],
+                )?,
+                registry,
+            )?,
+            rocksdb_memory: register(
+                GaugeVec::new(
+                    Opts::new("blesschain_rocksdb_memory_bytes", "Approximate RocksDB memory usage"),
+                    &["kind"],
+                )?,
+                registry,
+            )?,
+        })
+    }
+}
+
+/// Read-only view of the node's RocksDB database.
+pub struct RocksDbStats {
+    db: rocksdb::DB,
+    column_families: Vec<String>,
+}
+
+impl RocksDbStats {
+    /// Open `primary` as a secondary instance, keeping its own files in `secondary`.
+    pub fn open(primary: &Path, secondary: &Path) -> Result<Self, rocksdb::Error> {
+        let mut opts = rocksdb::Options::default();
+        // Secondary instances must keep every file open.
+        opts.set_max_open_files(-1);
+
+        let column_families = rocksdb::DB::list_cf(&opts, primary)?;
+        let db = rocksdb::DB::open_cf_as_secondary(&opts, primary, secondary, &column_families)?;
+
+        Ok(Self { db, column_families })
+    }
+
+    /// Where the secondary instance for `primary` keeps its files.
+    pub fn secondary_path(primary: &Path) -> PathBuf {
+        primary.with_file_name("rocksdb-metrics")
+    }
+
+    fn update(&self, metrics: &NodeMetrics) {
+        if let Err(e) = self.db.try_catch_up_with_primary() {
+            log::debug!(target: LOG_TARGET, "RocksDB secondary failed to catch up: {e}");
+            return
+        }
+
+        for (label, property) in ROCKSDB_PROPERTIES {
+            let total: u64 = self
+                .column_families
+                .iter()
+                .filter_map(|name| self.db.cf_handle(name))
+                .filter_map(|cf| self.db.property_int_value_cf(cf, *property).ok().flatten())
+                .sum();
+            metrics.rocksdb.with_label_values(&[label]).set(total);
+        }
+
+        match rocksdb::perf::get_memory_usage_stats(Some(&[&self.db]), None) {
+            Ok(stats) => {
+                let memory = &metrics.rocksdb_memory;
+                memory.with_label_values(&["mem_table_total"]).set(stats.mem_table_total);
+                memory.with_label_values(&["mem_table_unflushed"]).set(stats.mem_table_unflushed);
+                memory
+                    .with_label_values(&["mem_table_readers_total"])
+                    .set(stats.mem_table_readers_total);
+            },
+            Err(e) => log::debug!(target: LOG_TARGET, "RocksDB memory usage unavailable: {e}"),
+        }
+    }
+}
+
+/// Refresh [`NodeMetrics`] until the node shuts down.
+///
+/// Client and RocksDB reads hit the disk, so they run on the blocking pool
+/// rather than stalling the async worker.
+pub async fn run<C, P, N>(
+    client: Arc<C>,
+    pool: Arc<P>,
+    network: Arc<N>,
+    rocksdb: Option<RocksDbStats>,
+    metrics: NodeMetrics,
+) where
+    C: HeaderBackend<Block> + 'static,
+    P: TransactionPool<Block = Block>,
+    N: NetworkStatusProvider,
+{
+    let rocksdb = rocksdb.map(Arc::new);
+    loop {
+        let reads = {
+            let (client, rocksdb, metrics) = (client.clone(), rocksdb.clone(), metrics.clone());
+            tokio::task::spawn_blocking(move || {
+                if let Some(rocksdb) = rocksdb {
+                    rocksdb.update(&metrics);
+                }
+                client.info()
+            })
+        };
+        match reads.await {
+            Ok(info) => {
+                let height = &metrics.block_height;
+                height.with_label_values(&["best"]).set(info.best_number.into());
+                height.with_label_values(&["finalized"]).set(info.finalized_number.into());
+            },
+            Err(e) => log::warn!(target: LOG_TARGET, "Metrics reads failed: {e}"),
+        }
+
+        let status = pool.status();
+        metrics.txpool.with_label_values(&["ready"]).set(status.ready as u64);
+        metrics.txpool.with_label_values(&["future"]).set(status.future as u64);
+
+        if let Ok(status) = network.status().await {
+            metrics.peers.set(status.num_connected_peers as u64);
+        }
+
+        futures_timer::Delay::new(UPDATE_INTERVAL).await;
+    }
+}
@@ -0,0 +1,40 @@
+//! Off-chain workers.
+//!
+//! Whenever an imported block becomes the new best block, the runtime's
+//! `offchain_worker` hooks run on its state in a separate task. Workers get
+//! local storage in the node database, which survives restarts, HTTP access,
+//! and the transaction pool for signed transactions, signed with the keystore's
+//! [`KEY_TYPE`] keys.
+//!
+//! `--offchain-worker` picks when workers run (by default only on authorities);
+//! `--enable-offchain-indexing` lets block execution write to the same storage.
+
+use std::sync::Arc;
+
+use blesschain_runtime::offchain::KEY_TYPE;
+use sc_service::{Configuration, TaskManager};
+use sp_keystore::{Keystore, KeystorePtr};
+
+use crate::service::FullClient;
+
+const LOG_TARGET: &str = "offchain";
+
+/// Start the workers if `--offchain-worker` enables them, and give dev nodes
+/// (`--alice` and friends) a signing key.
+pub fn start(
+    config: &Configuration,
+    task_manager: &TaskManager,
+    client: Arc<FullClient>,
+    keystore: KeystorePtr,
+    network: Arc<dyn sc_offchain::NetworkProvider + Send + Sync>,
+) {
+    if let Some(seed) = config.dev_key_seed.as_deref() {
+        if let Err(e) = keystore.sr25519_generate_new(KEY_TYPE, Some(seed)) {
+            log::warn!(target: LOG_TARGET, "No off-chain worker key for {seed}: {e}");
+        }
+    }
+
+    if config.offchain_worker.enabled {
+        sc_service::build_offchain_workers(config, task_manager.spawn_handle(), client, network);
+    }
+}
@@ -1,6 +1,109 @@
-//! Minimal empty RPC placeholder (for compatibility)
+//! RPC extensions for the BlessChain node.
+//!
+//! On top of the default Substrate RPCs this exposes account nonces, fee queries,
+//! GRANDPA round state / justifications and the `blesschain_finalizedHead` helper.
+//! Nodes started with `--sealing manual` also get `engine_createBlock` and
+//! `engine_finalizeBlock`.
 
-pub fn create_rpc() {
-    // No RPCs implemented for minimal BlessChain node
+use std::sync::Arc;
+
+use blesschain_runtime::{opaque::Block, AccountId, Balance, Hash, Index};
+use futures::channel::mpsc;
+use jsonrpsee::RpcModule;
+use sc_consensus_grandpa::{
+    FinalityProofProvider, GrandpaJustificationStream, SharedAuthoritySet, SharedVoterState,
+};
+use sc_consensus_manual_seal::EngineCommand;
+use sc_rpc::SubscriptionTaskExecutor;
+pub use sc_rpc_api::DenyUnsafe;
+use sc_transaction_pool_api::TransactionPool;
+use sp_api::ProvideRuntimeApi;
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+
+mod finality;
+
+pub use finality::{Finality, FinalityApiServer, FinalizedHead};
+
+/// Dependencies for the GRANDPA RPC.
+pub struct GrandpaDeps<B> {
+    /// Voting round info.
+    pub shared_voter_state: SharedVoterState,
+    /// Authority set info.
+    pub shared_authority_set: SharedAuthoritySet<<Block as sp_runtime::traits::Block>::Hash, u32>,
+    /// Receives notifications about justification events from GRANDPA.
+    pub justification_stream: GrandpaJustificationStream<Block>,
+    /// Executor to drive the subscription manager in the GRANDPA RPC handler.
+    pub subscription_executor: SubscriptionTaskExecutor,
+    /// Finality proof provider.
+    pub finality_provider: Arc<FinalityProofProvider<B, Block>>,
 }
 
+/// Full client dependencies.
+pub struct FullDeps<C, P, B> {
+    /// The client instance to use.
+    pub client: Arc<C>,
+    /// Transaction pool instance.
+    pub pool: Arc<P>,
+    /// Whether to deny unsafe calls
+    pub deny_unsafe: DenyUnsafe,
+    /// GRANDPA specific dependencies, `None` when blocks are sealed on demand.
+    pub grandpa: Option<GrandpaDeps<B>>,
+    /// Channel driving the manual-seal worker, set with `--sealing manual`.
+    pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+}
+
+/// Instantiate all full RPC extensions.
+pub fn create_full<C, P, B>(
+    deps: FullDeps<C, P, B>,
+) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>>
+where
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: Send + Sync + 'static,
+    C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
+    C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: BlockBuilder<Block>,
+    P: TransactionPool + 'static,
+    B: sc_client_api::Backend<Block> + Send + Sync + 'static,
+    B::State: sc_client_api::StateBackend<sp_runtime::traits::HashFor<Block>>,
+{
+    use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
+    use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
+    use substrate_frame_rpc_system::{System, SystemApiServer};
+
+    let mut module = RpcModule::new(());
+    let FullDeps { client, pool, deny_unsafe, grandpa, command_sink } = deps;
+
+    module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+
+    if let Some(GrandpaDeps {
+        shared_voter_state,
+        shared_authority_set,
+        justification_stream,
+        subscription_executor,
+        finality_provider,
+    }) = grandpa
+    {
+        module.merge(
+            Grandpa::new(
+                subscription_executor,
+                shared_authority_set,
+                shared_voter_state,
+                justification_stream,
+                finality_provider,
+            )
+            .into_rpc(),
+        )?;
+    }
+
+    if let Some(command_sink) = command_sink {
+        module.merge(ManualSeal::new(command_sink).into_rpc())?;
+    }
+
+    module.merge(Finality::new(client).into_rpc())?;
+
+    Ok(module)
+}
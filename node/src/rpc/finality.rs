@@ -0,0 +1,57 @@
+//! `blesschain_finalizedHead`: the latest GRANDPA-finalized block, by number.
+//!
+//! `chain_getFinalizedHead` only returns a hash, so consumers that just want to
+//! know how deep a reorg can go would need a second round trip.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+/// Finalized and best block as seen by this node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizedHead<Hash, Number> {
+    /// Number of the latest finalized block.
+    pub number: Number,
+    /// Hash of the latest finalized block.
+    pub hash: Hash,
+    /// Number of the best (not necessarily final) block.
+    pub best_number: Number,
+}
+
+#[rpc(client, server)]
+pub trait FinalityApi<Hash, Number> {
+    /// Returns the latest finalized block together with the current best block number.
+    #[method(name = "blesschain_finalizedHead")]
+    fn finalized_head(&self) -> RpcResult<FinalizedHead<Hash, Number>>;
+}
+
+/// Implements [`FinalityApiServer`] on top of the client's header backend.
+pub struct Finality<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Finality<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: PhantomData }
+    }
+}
+
+impl<C, Block> FinalityApiServer<Block::Hash, NumberFor<Block>> for Finality<C, Block>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block> + Send + Sync + 'static,
+{
+    fn finalized_head(&self) -> RpcResult<FinalizedHead<Block::Hash, NumberFor<Block>>> {
+        let info = self.client.info();
+        Ok(FinalizedHead {
+            number: info.finalized_number,
+            hash: info.finalized_hash,
+            best_number: info.best_number,
+        })
+    }
+}
@@ -1,18 +1,396 @@
-use std::{thread, time::Duration};
-use std::time::SystemTime;
-
-/// 简单的“模拟出块”循环：每隔 N 秒打印一次
-pub fn run(block_interval_secs: u64) -> ! {
-    let mut height: u64 = 0;
-    println!("🧱 Mock block production loop started (every {block_interval_secs}s) ...");
-    loop {
-        height += 1;
-        let now = SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-        println!("💥 Block #{height} at {now}");
-        thread::sleep(Duration::from_secs(block_interval_secs));
+//! Service and service factory for BlessChain: Aura block authoring with GRANDPA finality.
+
+use std::{sync::Arc, time::Duration};
+
+use blesschain_runtime::{self, opaque::Block, RuntimeApi};
+use sc_client_api::BlockBackend;
+use sc_consensus_aura::{SlotProportion, StartAuraParams};
+use sc_consensus_grandpa::SharedVoterState;
+pub use sc_executor::NativeElseWasmExecutor;
+use sc_service::{
+    error::Error as ServiceError, Configuration, DatabaseSource, TaskManager, WarpSyncParams,
+};
+use sc_telemetry::{Telemetry, TelemetryWorker};
+use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+
+use crate::{
+    cli::Sealing,
+    import_queue::{build_import_queue, BlessBlockImport},
+    metrics::{self, ImportMetrics, NodeMetrics, RocksDbStats},
+};
+
+/// Native executor instance.
+pub struct ExecutorDispatch;
+
+impl sc_executor::NativeExecutionDispatch for ExecutorDispatch {
+    /// Only needed by `benchmark pallet`.
+    #[cfg(feature = "runtime-benchmarks")]
+    type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type ExtendHostFunctions = ();
+
+    fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
+        blesschain_runtime::api::dispatch(method, data)
+    }
+
+    fn native_version() -> sc_executor::NativeVersion {
+        blesschain_runtime::native_version()
     }
 }
 
+pub(crate) type FullClient =
+    sc_service::TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<ExecutorDispatch>>;
+pub(crate) type FullBackend = sc_service::TFullBackend<Block>;
+pub(crate) type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
+type FullGrandpaBlockImport =
+    sc_consensus_grandpa::GrandpaBlockImport<FullBackend, Block, FullClient, FullSelectChain>;
+type FullBlockImport = BlessBlockImport<FullGrandpaBlockImport, FullClient>;
+
+const LOG_TARGET: &str = "blesschain";
+
+/// How often GRANDPA gossips votes between authorities.
+const GRANDPA_GOSSIP_DURATION: Duration = Duration::from_millis(333);
+
+/// Every `GRANDPA_JUSTIFICATION_PERIOD` blocks a justification is stored so light
+/// clients and warp sync can prove finality without replaying every vote.
+const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+
+pub(crate) fn new_executor(config: &Configuration) -> NativeElseWasmExecutor<ExecutorDispatch> {
+    NativeElseWasmExecutor::<ExecutorDispatch>::new(
+        config.wasm_method,
+        config.default_heap_pages,
+        config.max_runtime_instances,
+        config.runtime_cache_size,
+    )
+}
+
+#[allow(clippy::type_complexity)]
+pub fn new_partial(
+    config: &Configuration,
+) -> Result<
+    sc_service::PartialComponents<
+        FullClient,
+        FullBackend,
+        FullSelectChain,
+        sc_consensus::DefaultImportQueue<Block, FullClient>,
+        sc_transaction_pool::FullPool<Block, FullClient>,
+        (
+            FullBlockImport,
+            sc_consensus_grandpa::LinkHalf<Block, FullClient, FullSelectChain>,
+            Option<Telemetry>,
+        ),
+    >,
+    ServiceError,
+> {
+    let telemetry = config
+        .telemetry_endpoints
+        .clone()
+        .filter(|x| !x.is_empty())
+        .map(|endpoints| -> Result<_, sc_telemetry::Error> {
+            let worker = TelemetryWorker::new(16)?;
+            let telemetry = worker.handle().new_telemetry(endpoints);
+            Ok((worker, telemetry))
+        })
+        .transpose()?;
+
+    let executor = new_executor(config);
+
+    let (client, backend, keystore_container, task_manager) =
+        sc_service::new_full_parts::<Block, RuntimeApi, _>(
+            config,
+            telemetry.as_ref().map(|(_, telemetry)| telemetry.handle()),
+            executor,
+        )?;
+    let client = Arc::new(client);
+
+    let telemetry = telemetry.map(|(worker, telemetry)| {
+        task_manager.spawn_handle().spawn("telemetry", None, worker.run());
+        telemetry
+    });
+
+    let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+    let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+        config.transaction_pool.clone(),
+        config.role.is_authority().into(),
+        config.prometheus_registry(),
+        task_manager.spawn_essential_handle(),
+        client.clone(),
+    );
+
+    let (grandpa_block_import, grandpa_link) = sc_consensus_grandpa::block_import(
+        client.clone(),
+        &(client.clone() as Arc<_>),
+        select_chain.clone(),
+        telemetry.as_ref().map(|x| x.handle()),
+    )?;
+
+    let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+    let import_metrics = config.prometheus_registry().map(ImportMetrics::register).transpose()?;
+    let block_import = BlessBlockImport::new(
+        grandpa_block_import.clone(),
+        client.clone(),
+        slot_duration,
+        import_metrics,
+    );
+
+    let import_queue = build_import_queue(
+        client.clone(),
+        block_import.clone(),
+        grandpa_block_import,
+        slot_duration,
+        &task_manager.spawn_essential_handle(),
+        config.prometheus_registry(),
+        telemetry.as_ref().map(|x| x.handle()),
+    )?;
+
+    Ok(sc_service::PartialComponents {
+        client,
+        backend,
+        task_manager,
+        import_queue,
+        keystore_container,
+        select_chain,
+        transaction_pool,
+        other: (block_import, grandpa_link, telemetry),
+    })
+}
+
+/// Secondary RocksDB instance for the metrics task; `None` for other backends
+/// or if the database cannot be opened, which only costs the RocksDB gauges.
+pub(crate) fn open_rocksdb_stats(config: &Configuration) -> Option<RocksDbStats> {
+    let path = match &config.database {
+        DatabaseSource::RocksDb { path, .. } => path,
+        DatabaseSource::Auto { rocksdb_path, .. } if rocksdb_path.exists() => rocksdb_path,
+        _ => return None,
+    };
+
+    RocksDbStats::open(path, &RocksDbStats::secondary_path(path))
+        .map_err(|e| log::warn!(target: LOG_TARGET, "RocksDB metrics disabled: {e}"))
+        .ok()
+}
+
+/// Builds a new service for a full client.
+pub fn new_full(
+    mut config: Configuration,
+    sealing: Option<Sealing>,
+) -> Result<TaskManager, ServiceError> {
+    if let Some(sealing) = sealing {
+        return crate::dev_seal::new_dev(config, sealing)
+    }
+
+    let sc_service::PartialComponents {
+        client,
+        backend,
+        mut task_manager,
+        import_queue,
+        keystore_container,
+        select_chain,
+        transaction_pool,
+        other: (block_import, grandpa_link, mut telemetry),
+    } = new_partial(&config)?;
+
+    let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
+        &client.block_hash(0).ok().flatten().expect("Genesis block exists; qed"),
+        &config.chain_spec,
+    );
+
+    config
+        .network
+        .extra_sets
+        .push(sc_consensus_grandpa::grandpa_peers_set_config(grandpa_protocol_name.clone()));
+    let warp_sync = Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+        backend.clone(),
+        grandpa_link.shared_authority_set().clone(),
+        Vec::default(),
+    ));
+
+    let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
+        sc_service::build_network(sc_service::BuildNetworkParams {
+            config: &config,
+            client: client.clone(),
+            transaction_pool: transaction_pool.clone(),
+            spawn_handle: task_manager.spawn_handle(),
+            import_queue,
+            block_announce_validator_builder: None,
+            warp_sync_params: Some(WarpSyncParams::WithProvider(warp_sync)),
+        })?;
+
+    crate::offchain::start(
+        &config,
+        &task_manager,
+        client.clone(),
+        keystore_container.keystore(),
+        network.clone(),
+    );
+
+    let role = config.role.clone();
+    let force_authoring = config.force_authoring;
+    let backoff_authoring_blocks: Option<()> = None;
+    let name = config.network.node_name.clone();
+    let enable_grandpa = !config.disable_grandpa;
+    let prometheus_registry = config.prometheus_registry().cloned();
+    let rocksdb_stats = open_rocksdb_stats(&config);
+    let execution_strategy = config.execution_strategies.importing;
+
+    let shared_voter_state = SharedVoterState::empty();
+
+    let rpc_extensions_builder = {
+        let client = client.clone();
+        let pool = transaction_pool.clone();
+        let backend = backend.clone();
+        let shared_voter_state = shared_voter_state.clone();
+        let shared_authority_set = grandpa_link.shared_authority_set().clone();
+        let justification_stream = grandpa_link.justification_stream();
+
+        Box::new(move |deny_unsafe, subscription_executor| {
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: pool.clone(),
+                deny_unsafe,
+                grandpa: Some(crate::rpc::GrandpaDeps {
+                    shared_voter_state: shared_voter_state.clone(),
+                    shared_authority_set: shared_authority_set.clone(),
+                    justification_stream: justification_stream.clone(),
+                    subscription_executor,
+                    finality_provider: sc_consensus_grandpa::FinalityProofProvider::new_for_service(
+                        backend.clone(),
+                        Some(shared_authority_set.clone()),
+                    ),
+                }),
+                command_sink: None,
+            };
+            crate::rpc::create_full(deps).map_err(Into::into)
+        })
+    };
+
+    let _rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+        network: network.clone(),
+        client: client.clone(),
+        keystore: keystore_container.keystore(),
+        task_manager: &mut task_manager,
+        transaction_pool: transaction_pool.clone(),
+        rpc_builder: rpc_extensions_builder,
+        backend,
+        system_rpc_tx,
+        tx_handler_controller,
+        sync_service: sync_service.clone(),
+        config,
+        telemetry: telemetry.as_mut(),
+    })?;
+
+    if let Some(registry) = prometheus_registry.as_ref() {
+        task_manager.spawn_handle().spawn(
+            "blesschain-metrics",
+            None,
+            metrics::run(
+                client.clone(),
+                transaction_pool.clone(),
+                network.clone(),
+                rocksdb_stats,
+                NodeMetrics::register(registry)?,
+            ),
+        );
+    }
+
+    task_manager.spawn_handle().spawn(
+        "runtime-execution",
+        None,
+        crate::execution::watch(client.clone(), execution_strategy),
+    );
+
+    if role.is_authority() {
+        let proposer_factory = crate::vrf::VrfProposerFactory::new(
+            sc_basic_authorship::ProposerFactory::new(
+                task_manager.spawn_handle(),
+                client.clone(),
+                transaction_pool,
+                prometheus_registry.as_ref(),
+                telemetry.as_ref().map(|x| x.handle()),
+            ),
+            client.clone(),
+            keystore_container.keystore(),
+        );
+
+        let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+
+        let aura = sc_consensus_aura::start_aura::<AuraPair, _, _, _, _, _, _, _, _, _, _>(
+            StartAuraParams {
+                slot_duration,
+                client,
+                select_chain,
+                block_import,
+                proposer_factory,
+                create_inherent_data_providers: move |_, ()| async move {
+                    let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
+
+                    let slot =
+                        sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+                            *timestamp,
+                            slot_duration,
+                        );
+
+                    Ok((slot, timestamp))
+                },
+                force_authoring,
+                backoff_authoring_blocks,
+                keystore: keystore_container.keystore(),
+                sync_oracle: sync_service.clone(),
+                justification_sync_link: sync_service.clone(),
+                block_proposal_slot_portion: SlotProportion::new(2f32 / 3f32),
+                max_block_proposal_slot_portion: None,
+                telemetry: telemetry.as_ref().map(|x| x.handle()),
+                compatibility_mode: Default::default(),
+            },
+        )?;
+
+        // the AURA authoring task is considered essential, i.e. if it
+        // fails we take down the service with it.
+        task_manager
+            .spawn_essential_handle()
+            .spawn_blocking("aura", Some("block-authoring"), aura);
+    }
+
+    if enable_grandpa {
+        // if the node isn't actively participating in consensus then it doesn't
+        // need a keystore, regardless of which protocol we use below.
+        let keystore = if role.is_authority() { Some(keystore_container.keystore()) } else { None };
+
+        let grandpa_config = sc_consensus_grandpa::Config {
+            gossip_duration: GRANDPA_GOSSIP_DURATION,
+            justification_period: GRANDPA_JUSTIFICATION_PERIOD,
+            name: Some(name),
+            observer_enabled: false,
+            keystore,
+            local_role: role,
+            telemetry: telemetry.as_ref().map(|x| x.handle()),
+            protocol_name: grandpa_protocol_name,
+        };
+
+        // start the full GRANDPA voter
+        // NOTE: non-authorities could run the GRANDPA observer protocol, but at
+        // this point the full voter should provide better guarantees of block
+        // and vote data availability than the observer.
+        let grandpa_config = sc_consensus_grandpa::GrandpaParams {
+            config: grandpa_config,
+            link: grandpa_link,
+            network,
+            sync: Arc::new(sync_service),
+            voting_rule: sc_consensus_grandpa::VotingRulesBuilder::default().build(),
+            prometheus_registry,
+            shared_voter_state,
+            telemetry: telemetry.as_ref().map(|x| x.handle()),
+        };
+
+        // the GRANDPA voter task is considered infallible, i.e.
+        // if it fails we take down the service with it.
+        task_manager.spawn_essential_handle().spawn_blocking(
+            "grandpa-voter",
+            None,
+            sc_consensus_grandpa::run_grandpa_voter(grandpa_config)?,
+        );
+    }
+
+    network_starter.start_network();
+    Ok(task_manager)
+}
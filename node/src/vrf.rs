@@ -0,0 +1,131 @@
+//! Author VRF outputs for `pallet-randomness`.
+//!
+//! [`VrfProposerFactory`] wraps the Aura proposer factory. Before a block is
+//! built it finds the slot's author among the Aura authorities and, if the
+//! keystore holds that key, adds a VRF signature over the runtime's transcript
+//! as a `VRF_ENGINE_ID` pre-runtime digest. Blocks sealed without one, such as
+//! `--dev` blocks, leave the runtime on the collective flip.
+
+use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use blesschain_runtime::{opaque::Block, Runtime};
+use codec::{Decode, Encode};
+use futures::{FutureExt, TryFutureExt};
+use pallet_randomness::{vrf_transcript, VRF_ENGINE_ID};
+use sc_client_api::StorageProvider;
+use sp_api::ProvideRuntimeApi;
+use sp_consensus::{Environment, Proposer};
+use sp_consensus_aura::{sr25519::AuthorityId as AuraId, AuraApi, Slot, AURA_ENGINE_ID};
+use sp_core::{crypto::key_types::AURA, sr25519, storage::StorageKey};
+use sp_inherents::InherentData;
+use sp_keystore::KeystorePtr;
+use sp_runtime::{
+    traits::{Block as BlockT, Header as _},
+    Digest, DigestItem,
+};
+
+use crate::service::FullClient;
+
+const LOG_TARGET: &str = "vrf";
+
+type Hash = <Block as BlockT>::Hash;
+
+/// Proposer factory whose proposers add this node's VRF output to the blocks
+/// it authors.
+pub struct VrfProposerFactory<F> {
+    inner: F,
+    client: Arc<FullClient>,
+    keystore: KeystorePtr,
+}
+
+impl<F> VrfProposerFactory<F> {
+    pub fn new(inner: F, client: Arc<FullClient>, keystore: KeystorePtr) -> Self {
+        Self { inner, client, keystore }
+    }
+}
+
+impl<F: Environment<Block>> Environment<Block> for VrfProposerFactory<F> {
+    type Proposer = VrfProposer<F::Proposer>;
+    type CreateProposer = Pin<Box<dyn Future<Output = Result<Self::Proposer, Self::Error>> + Send>>;
+    type Error = F::Error;
+
+    fn init(&mut self, parent: &<Block as BlockT>::Header) -> Self::CreateProposer {
+        let client = self.client.clone();
+        let keystore = self.keystore.clone();
+        let parent_hash = parent.hash();
+        let number = u64::from(*parent.number()) + 1;
+        self.inner
+            .init(parent)
+            .map_ok(move |inner| VrfProposer { inner, client, keystore, parent_hash, number })
+            .boxed()
+    }
+}
+
+pub struct VrfProposer<P> {
+    inner: P,
+    client: Arc<FullClient>,
+    keystore: KeystorePtr,
+    parent_hash: Hash,
+    number: u64,
+}
+
+impl<P> VrfProposer<P> {
+    /// The VRF digest for the block claimed by the Aura pre-digest in
+    /// `digest`, if this node holds the slot author's key.
+    fn vrf_digest(&self, digest: &Digest) -> Option<DigestItem> {
+        let slot = digest.logs.iter().find_map(|item| match item.as_pre_runtime() {
+            Some((AURA_ENGINE_ID, mut data)) => Slot::decode(&mut data).ok(),
+            _ => None,
+        })?;
+        let authorities: Vec<AuraId> =
+            self.client.runtime_api().authorities(self.parent_hash).ok()?;
+        if authorities.is_empty() {
+            return None
+        }
+        let author: sr25519::Public =
+            authorities[(u64::from(slot) % authorities.len() as u64) as usize].clone().into();
+
+        let transcript = vrf_transcript(&self.previous_randomness(), self.number);
+        match self.keystore.sr25519_vrf_sign(AURA, &author, &transcript) {
+            Ok(signature) =>
+                signature.map(|signature| DigestItem::PreRuntime(VRF_ENGINE_ID, signature.encode())),
+            Err(e) => {
+                log::warn!(target: LOG_TARGET, "No VRF output for block {}: {e}", self.number);
+                None
+            },
+        }
+    }
+
+    /// `RandomnessSource::VrfRandomness` as of the parent block. The key comes
+    /// from the runtime, so it follows the pallet's name in `construct_runtime!`.
+    fn previous_randomness(&self) -> [u8; 32] {
+        let key = StorageKey(pallet_randomness::VrfRandomness::<Runtime>::hashed_key().to_vec());
+        self.client
+            .storage(self.parent_hash, &key)
+            .ok()
+            .flatten()
+            .and_then(|data| <[u8; 32]>::decode(&mut &data.0[..]).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl<P: Proposer<Block>> Proposer<Block> for VrfProposer<P> {
+    type Error = P::Error;
+    type Transaction = P::Transaction;
+    type Proposal = P::Proposal;
+    type ProofRecording = P::ProofRecording;
+    type Proof = P::Proof;
+
+    fn propose(
+        self,
+        inherent_data: InherentData,
+        mut inherent_digests: Digest,
+        max_duration: Duration,
+        block_size_limit: Option<usize>,
+    ) -> Self::Proposal {
+        if let Some(item) = self.vrf_digest(&inherent_digests) {
+            inherent_digests.push(item);
+        }
+        self.inner.propose(inherent_data, inherent_digests, max_duration, block_size_limit)
+    }
+}
@@ -0,0 +1,16 @@
+//! Runtime API for inspecting what `pallet-call-filter` currently blocks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait CallFilterApi {
+        /// Paused `(pallet, call)` names; `call` is `None` when the whole pallet
+        /// is paused.
+        fn paused_calls() -> Vec<(Vec<u8>, Option<Vec<u8>>)>;
+
+        /// Whether safe mode is on.
+        fn safe_mode() -> bool;
+    }
+}
@@ -0,0 +1,44 @@
+//! Benchmarks for the call filter pallet.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::dispatch::GetCallMetadata;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+/// The first call of the first pallet in the runtime, as raw names.
+fn some_call<T: Config>() -> (Vec<u8>, Option<Vec<u8>>) {
+    let pallet = <T as Config>::RuntimeCall::get_module_names()[0];
+    let call = <T as Config>::RuntimeCall::get_call_names(pallet)[0];
+    (pallet.as_bytes().to_vec(), Some(call.as_bytes().to_vec()))
+}
+
+benchmarks! {
+    pause {
+        let (pallet, call) = some_call::<T>();
+    }: _(RawOrigin::Root, pallet, call)
+    verify {
+        assert_eq!(Pallet::<T>::paused_calls().len(), 1);
+    }
+
+    unpause {
+        let (pallet, call) = some_call::<T>();
+        Pallet::<T>::pause(RawOrigin::Root.into(), pallet.clone(), call.clone())?;
+    }: _(RawOrigin::Root, pallet, call)
+    verify {
+        assert!(Pallet::<T>::paused_calls().is_empty());
+    }
+
+    enter_safe_mode {
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(SafeMode::<T>::get());
+    }
+
+    exit_safe_mode {
+        Pallet::<T>::enter_safe_mode(RawOrigin::Root.into())?;
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(!SafeMode::<T>::get());
+    }
+}
@@ -0,0 +1,237 @@
+//! Emergency call filtering.
+//!
+//! Meant to be the runtime's `BaseCallFilter`. `PauseOrigin` can pause a whole
+//! pallet or a single call by name, e.g. to stop a buggy call on a live chain
+//! until a fixed runtime is deployed, or put the chain into safe mode where
+//! only `SafeModeCalls` are dispatched.
+//!
+//! `WhitelistedCalls` are never filtered, so inherents keep working and the
+//! filter can always be lifted again. Root origin bypasses `BaseCallFilter`
+//! altogether.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        dispatch::{CallMetadata, GetCallMetadata},
+        pallet_prelude::*,
+        traits::Contains,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_std::prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The runtime's call type, to read pallet and call names from.
+        type RuntimeCall: GetCallMetadata;
+
+        /// May pause and unpause calls and switch safe mode.
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Calls that are dispatched whatever is paused.
+        type WhitelistedCalls: Contains<<Self as Config>::RuntimeCall>;
+
+        /// Calls, besides the whitelisted ones, still dispatched in safe mode.
+        type SafeModeCalls: Contains<<Self as Config>::RuntimeCall>;
+
+        /// Longest pallet or call name that can be paused, in bytes.
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+    /// A pallet name and, unless the whole pallet is paused, a call name.
+    pub type PausedCallOf<T> = (NameOf<T>, Option<NameOf<T>>);
+
+    #[pallet::storage]
+    pub type PausedCalls<T: Config> = StorageMap<_, Blake2_128Concat, PausedCallOf<T>, ()>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn safe_mode)]
+    pub type SafeMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `call` of `pallet`, or all of `pallet` when `call` is `None`, is filtered.
+        CallPaused { pallet: NameOf<T>, call: Option<NameOf<T>> },
+        /// The pause was lifted.
+        CallUnpaused { pallet: NameOf<T>, call: Option<NameOf<T>> },
+        /// Only whitelisted and safe-mode calls are dispatched.
+        SafeModeEntered,
+        /// Safe mode was lifted; individual pauses still apply.
+        SafeModeExited,
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// A name is longer than `MaxNameLength`.
+        NameTooLong,
+        /// The runtime has no such pallet or call.
+        UnknownCall,
+        /// The call or pallet is already paused.
+        AlreadyPaused,
+        /// The call or pallet is not paused.
+        NotPaused,
+        /// Safe mode is already on.
+        AlreadyInSafeMode,
+        /// Safe mode is not on.
+        NotInSafeMode,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Stop dispatching `call_name` of `pallet_name`, or every call of
+        /// `pallet_name` if `call_name` is `None`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(
+            origin: OriginFor<T>,
+            pallet_name: Vec<u8>,
+            call_name: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            let (pallet, call) = Self::paused_call(pallet_name, call_name)?;
+            let key = (pallet.clone(), call.clone());
+            ensure!(!PausedCalls::<T>::contains_key(&key), Error::<T>::AlreadyPaused);
+
+            PausedCalls::<T>::insert(key, ());
+
+            Self::deposit_event(Event::CallPaused { pallet, call });
+            Ok(())
+        }
+
+        /// Lift a pause set by [`Pallet::pause`] with the same arguments.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unpause())]
+        pub fn unpause(
+            origin: OriginFor<T>,
+            pallet_name: Vec<u8>,
+            call_name: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            // Not checked against the runtime: a pause may outlive the call it
+            // names across a runtime upgrade and must still be removable.
+            let pallet: NameOf<T> = pallet_name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+            let call: Option<NameOf<T>> = call_name
+                .map(|name| name.try_into().map_err(|_| Error::<T>::NameTooLong))
+                .transpose()?;
+
+            PausedCalls::<T>::take((pallet.clone(), call.clone())).ok_or(Error::<T>::NotPaused)?;
+
+            Self::deposit_event(Event::CallUnpaused { pallet, call });
+            Ok(())
+        }
+
+        /// Dispatch only `WhitelistedCalls` and `SafeModeCalls` until
+        /// [`Pallet::exit_safe_mode`].
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::enter_safe_mode())]
+        pub fn enter_safe_mode(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            ensure!(!SafeMode::<T>::get(), Error::<T>::AlreadyInSafeMode);
+
+            SafeMode::<T>::put(true);
+
+            Self::deposit_event(Event::SafeModeEntered);
+            Ok(())
+        }
+
+        /// Leave safe mode.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::exit_safe_mode())]
+        pub fn exit_safe_mode(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            ensure!(SafeMode::<T>::get(), Error::<T>::NotInSafeMode);
+
+            SafeMode::<T>::kill();
+
+            Self::deposit_event(Event::SafeModeExited);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Currently paused `(pallet, call)` names; `call` is `None` for a
+        /// whole pallet.
+        pub fn paused_calls() -> Vec<(Vec<u8>, Option<Vec<u8>>)> {
+            PausedCalls::<T>::iter_keys()
+                .map(|(pallet, call)| (pallet.into_inner(), call.map(|c| c.into_inner())))
+                .collect()
+        }
+
+        /// Whether `call` is currently paused by name. Ignores safe mode and
+        /// the whitelist, see [`Contains`] for the full check.
+        pub fn is_paused(call: &<T as Config>::RuntimeCall) -> bool {
+            let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+            let Ok(pallet) = NameOf::<T>::try_from(pallet_name.as_bytes().to_vec()) else {
+                return false
+            };
+            if PausedCalls::<T>::contains_key((pallet.clone(), None::<NameOf<T>>)) {
+                return true
+            }
+            NameOf::<T>::try_from(function_name.as_bytes().to_vec())
+                .map_or(false, |call| PausedCalls::<T>::contains_key((pallet, Some(call))))
+        }
+
+        /// Bound the names and check they exist in the runtime, so a typo does
+        /// not silently pause nothing.
+        fn paused_call(
+            pallet_name: Vec<u8>,
+            call_name: Option<Vec<u8>>,
+        ) -> Result<PausedCallOf<T>, DispatchError> {
+            let pallet_str =
+                sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::UnknownCall)?;
+            let pallet_str = <T as Config>::RuntimeCall::get_module_names()
+                .iter()
+                .find(|name| **name == pallet_str)
+                .ok_or(Error::<T>::UnknownCall)?;
+            if let Some(call_name) = &call_name {
+                let call_str =
+                    sp_std::str::from_utf8(call_name).map_err(|_| Error::<T>::UnknownCall)?;
+                ensure!(
+                    <T as Config>::RuntimeCall::get_call_names(pallet_str)
+                        .iter()
+                        .any(|name| *name == call_str),
+                    Error::<T>::UnknownCall
+                );
+            }
+
+            let pallet = pallet_name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+            let call = call_name
+                .map(|name| name.try_into().map_err(|_| Error::<T>::NameTooLong))
+                .transpose()?;
+            Ok((pallet, call))
+        }
+    }
+
+    impl<T: Config> Contains<<T as Config>::RuntimeCall> for Pallet<T> {
+        fn contains(call: &<T as Config>::RuntimeCall) -> bool {
+            if T::WhitelistedCalls::contains(call) {
+                return true
+            }
+            if SafeMode::<T>::get() && !T::SafeModeCalls::contains(call) {
+                return false
+            }
+            !Self::is_paused(call)
+        }
+    }
+}
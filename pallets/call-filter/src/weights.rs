@@ -0,0 +1,70 @@
+//! Weights for `pallet_call_filter`, not benchmarked yet.
+//!
+//! Each call touches one bounded pause entry or the safe mode flag.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_call_filter`.
+pub trait WeightInfo {
+    fn pause() -> Weight;
+    fn unpause() -> Weight;
+    fn enter_safe_mode() -> Weight;
+    fn exit_safe_mode() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn pause() -> Weight {
+        Weight::from_parts(16_500_000, 3566)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn unpause() -> Weight {
+        Weight::from_parts(15_000_000, 3566)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn enter_safe_mode() -> Weight {
+        Weight::from_parts(9_500_000, 1486)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn exit_safe_mode() -> Weight {
+        Weight::from_parts(9_500_000, 1486)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn pause() -> Weight {
+        Weight::from_parts(16_500_000, 3566)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn unpause() -> Weight {
+        Weight::from_parts(15_000_000, 3566)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn enter_safe_mode() -> Weight {
+        Weight::from_parts(9_500_000, 1486)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn exit_safe_mode() -> Weight {
+        Weight::from_parts(9_500_000, 1486)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+}
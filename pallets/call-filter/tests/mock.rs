@@ -0,0 +1,98 @@
+use frame_support::{
+    parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Contains},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+use pallet_call_filter as call_filter;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        CallFilter: call_filter,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = CallFilter;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+/// The filter's own calls, so a pause can always be lifted.
+pub struct WhitelistedCalls;
+impl Contains<RuntimeCall> for WhitelistedCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::CallFilter(_))
+    }
+}
+
+/// `remark_with_event` stands in for a governance call.
+pub struct SafeModeCalls;
+impl Contains<RuntimeCall> for SafeModeCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
+    }
+}
+
+parameter_types! {
+    pub const MaxNameLength: u32 = 32;
+}
+
+impl call_filter::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PauseOrigin = EnsureRoot<u64>;
+    type WhitelistedCalls = WhitelistedCalls;
+    type SafeModeCalls = SafeModeCalls;
+    type MaxNameLength = MaxNameLength;
+    type WeightInfo = ();
+}
+
+pub fn remark() -> RuntimeCall {
+    frame_system::Call::remark { remark: vec![] }.into()
+}
+
+pub fn remark_with_event() -> RuntimeCall {
+    frame_system::Call::remark_with_event { remark: vec![] }.into()
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
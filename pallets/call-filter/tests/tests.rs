@@ -0,0 +1,138 @@
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_call_filter::{Error, Event};
+use sp_runtime::{traits::Dispatchable, DispatchError};
+
+/// Dispatch `call` as a signed user, through `BaseCallFilter`.
+fn dispatch(call: RuntimeCall) -> Result<(), DispatchError> {
+    call.dispatch(RuntimeOrigin::signed(1)).map(|_| ()).map_err(|e| e.error)
+}
+
+fn filtered() -> Result<(), DispatchError> {
+    Err(frame_system::Error::<Test>::CallFiltered.into())
+}
+
+fn name(name: &str) -> Vec<u8> {
+    name.as_bytes().to_vec()
+}
+
+#[test]
+fn pausing_a_call_filters_only_that_call() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(dispatch(remark()));
+
+        assert_ok!(CallFilter::pause(RuntimeOrigin::root(), name("System"), Some(name("remark"))));
+        System::assert_last_event(
+            Event::CallPaused {
+                pallet: name("System").try_into().unwrap(),
+                call: Some(name("remark").try_into().unwrap()),
+            }
+            .into(),
+        );
+
+        assert_eq!(dispatch(remark()), filtered());
+        assert_ok!(dispatch(remark_with_event()));
+        assert_eq!(CallFilter::paused_calls(), vec![(name("System"), Some(name("remark")))]);
+    });
+}
+
+#[test]
+fn pausing_a_pallet_filters_all_its_calls() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallFilter::pause(RuntimeOrigin::root(), name("System"), None));
+
+        assert_eq!(dispatch(remark()), filtered());
+        assert_eq!(dispatch(remark_with_event()), filtered());
+        assert_eq!(CallFilter::paused_calls(), vec![(name("System"), None)]);
+    });
+}
+
+#[test]
+fn unpause_lifts_the_pause() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallFilter::unpause(RuntimeOrigin::root(), name("System"), None),
+            Error::<Test>::NotPaused
+        );
+        assert_ok!(CallFilter::pause(RuntimeOrigin::root(), name("System"), None));
+
+        assert_ok!(CallFilter::unpause(RuntimeOrigin::root(), name("System"), None));
+
+        assert_ok!(dispatch(remark()));
+        assert!(CallFilter::paused_calls().is_empty());
+        System::assert_last_event(
+            Event::CallUnpaused { pallet: name("System").try_into().unwrap(), call: None }.into(),
+        );
+    });
+}
+
+#[test]
+fn pause_rejects_unknown_and_duplicate_names() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallFilter::pause(RuntimeOrigin::root(), name("Sytem"), None),
+            Error::<Test>::UnknownCall
+        );
+        assert_noop!(
+            CallFilter::pause(RuntimeOrigin::root(), name("System"), Some(name("remarks"))),
+            Error::<Test>::UnknownCall
+        );
+
+        assert_ok!(CallFilter::pause(RuntimeOrigin::root(), name("System"), Some(name("remark"))));
+        assert_noop!(
+            CallFilter::pause(RuntimeOrigin::root(), name("System"), Some(name("remark"))),
+            Error::<Test>::AlreadyPaused
+        );
+    });
+}
+
+#[test]
+fn only_pause_origin_manages_the_filter() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallFilter::pause(RuntimeOrigin::signed(1), name("System"), None),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CallFilter::enter_safe_mode(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn safe_mode_allows_only_whitelisted_and_safe_mode_calls() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallFilter::exit_safe_mode(RuntimeOrigin::root()),
+            Error::<Test>::NotInSafeMode
+        );
+        assert_ok!(CallFilter::enter_safe_mode(RuntimeOrigin::root()));
+        System::assert_last_event(Event::SafeModeEntered.into());
+        assert_noop!(
+            CallFilter::enter_safe_mode(RuntimeOrigin::root()),
+            Error::<Test>::AlreadyInSafeMode
+        );
+
+        assert_eq!(dispatch(remark()), filtered());
+        assert_ok!(dispatch(remark_with_event()));
+        // Whitelisted calls pass the filter and fail on their own origin check.
+        assert_eq!(
+            dispatch(pallet_call_filter::Call::<Test>::exit_safe_mode {}.into()),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_ok!(CallFilter::exit_safe_mode(RuntimeOrigin::root()));
+        assert_ok!(dispatch(remark()));
+    });
+}
+
+#[test]
+fn root_bypasses_the_filter() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallFilter::enter_safe_mode(RuntimeOrigin::root()));
+        assert_ok!(CallFilter::pause(RuntimeOrigin::root(), name("System"), None));
+
+        assert_ok!(remark().dispatch(RuntimeOrigin::root()));
+    });
+}
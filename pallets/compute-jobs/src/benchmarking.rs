@@ -0,0 +1,180 @@
+//! Benchmarks for the compute job market.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use pallet_homecdn::ProviderRegistry;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{Bounded, Saturating},
+    Perbill,
+};
+
+const SEED: u32 = 0;
+
+fn fund<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn provider<T: Config>(index: u32) -> T::AccountId {
+    let provider = account("provider", index, SEED);
+    fund::<T>(&provider);
+    T::Providers::register_for_benchmarks(&provider);
+    provider
+}
+
+fn resources() -> Resources {
+    Resources {
+        cpu_cores: 16,
+        memory_mib: 65_536,
+        gpus: 1,
+        gpu_memory_mib: 24_576,
+        storage_gib: 100,
+    }
+}
+
+/// Post a job from `requester` with twice `MinReward`.
+fn post<T: Config>(requester: &T::AccountId) -> Result<JobId, &'static str> {
+    fund::<T>(requester);
+    let id = NextJobId::<T>::get();
+    let reward = T::MinReward::get().saturating_add(T::MinReward::get());
+    Pallet::<T>::post(
+        RawOrigin::Signed(requester.clone()).into(),
+        H256::repeat_byte(1),
+        resources(),
+        reward,
+        T::MaxJobDuration::get(),
+    )?;
+    Ok(id)
+}
+
+/// Post a job from `requester` and fill its bids up to `MaxBids`.
+fn post_with_bids<T: Config>(requester: &T::AccountId) -> Result<JobId, &'static str> {
+    let id = post::<T>(requester)?;
+    for index in 0..T::MaxBids::get() {
+        let provider = provider::<T>(index);
+        Pallet::<T>::bid(RawOrigin::Signed(provider).into(), id, T::MinReward::get())?;
+    }
+    Ok(id)
+}
+
+/// Post a job from `requester` and have `provider` accept it and submit.
+fn submitted<T: Config>(
+    requester: &T::AccountId,
+    provider: &T::AccountId,
+) -> Result<JobId, &'static str> {
+    let id = post::<T>(requester)?;
+    Pallet::<T>::accept(RawOrigin::Signed(provider.clone()).into(), id)?;
+    Pallet::<T>::submit_result(RawOrigin::Signed(provider.clone()).into(), id, H256::zero())?;
+    Ok(id)
+}
+
+benchmarks! {
+    post {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let reward = T::MinReward::get();
+        let timeout = T::MaxJobDuration::get();
+    }: _(RawOrigin::Signed(caller), H256::repeat_byte(1), resources(), reward, timeout)
+    verify {
+        assert!(Jobs::<T>::contains_key(0));
+    }
+
+    // The last bid fits, after `MaxBids - 1` others.
+    bid {
+        let id = post::<T>(&account("requester", 0, SEED))?;
+        for index in 1..T::MaxBids::get() {
+            let provider = provider::<T>(index);
+            Pallet::<T>::bid(RawOrigin::Signed(provider).into(), id, T::MinReward::get())?;
+        }
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        T::Providers::register_for_benchmarks(&caller);
+    }: _(RawOrigin::Signed(caller), id, T::MinReward::get())
+    verify {
+        assert_eq!(Bids::<T>::get(id).len() as u32, T::MaxBids::get());
+    }
+
+    accept {
+        let id = post_with_bids::<T>(&account("requester", 0, SEED))?;
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        T::Providers::register_for_benchmarks(&caller);
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(Bids::<T>::get(id).is_empty());
+    }
+
+    // The chosen bid is the last one.
+    assign {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = post_with_bids::<T>(&caller)?;
+        let provider: T::AccountId = account("provider", T::MaxBids::get() - 1, SEED);
+    }: _(RawOrigin::Signed(caller), id, provider)
+    verify {
+        assert_eq!(Jobs::<T>::get(id).unwrap().reward, T::MinReward::get());
+    }
+
+    submit_result {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        T::Providers::register_for_benchmarks(&caller);
+        let id = post::<T>(&account("requester", 0, SEED))?;
+        Pallet::<T>::accept(RawOrigin::Signed(caller.clone()).into(), id)?;
+    }: _(RawOrigin::Signed(caller), id, H256::repeat_byte(2))
+    verify {
+        assert!(matches!(Jobs::<T>::get(id).unwrap().status, JobStatus::Submitted { .. }));
+    }
+
+    approve {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = submitted::<T>(&caller, &provider::<T>(0))?;
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(!Jobs::<T>::contains_key(id));
+    }
+
+    release {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = submitted::<T>(&account("requester", 0, SEED), &provider::<T>(0))?;
+        let review_until = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::ReviewPeriod::get());
+        frame_system::Pallet::<T>::set_block_number(review_until);
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(!Jobs::<T>::contains_key(id));
+    }
+
+    dispute {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = submitted::<T>(&caller, &provider::<T>(0))?;
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(matches!(Jobs::<T>::get(id).unwrap().status, JobStatus::Disputed { .. }));
+    }
+
+    resolve {
+        let origin = T::ArbitrationOrigin::try_successful_origin()
+            .map_err(|_| "no arbitration origin")?;
+        let requester: T::AccountId = account("requester", 0, SEED);
+        let id = submitted::<T>(&requester, &provider::<T>(0))?;
+        Pallet::<T>::dispute(RawOrigin::Signed(requester).into(), id)?;
+    }: _<T::RuntimeOrigin>(origin, id, Perbill::from_percent(50), true)
+    verify {
+        assert!(!Jobs::<T>::contains_key(id));
+    }
+
+    // A missed deadline, which forfeits the provider's deposit.
+    cancel {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = post::<T>(&caller)?;
+        Pallet::<T>::accept(RawOrigin::Signed(provider::<T>(0)).into(), id)?;
+        let due_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::MaxJobDuration::get());
+        frame_system::Pallet::<T>::set_block_number(due_at);
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(!Jobs::<T>::contains_key(id));
+    }
+}
@@ -0,0 +1,533 @@
+//! Compute job market.
+//!
+//! A requester posts a job by the content hash of its specification (model,
+//! inputs, parameters), the [`Resources`] it needs and a reward, which is
+//! reserved as escrow. Active HomeCDN providers either `accept` the job at the
+//! full reward or `bid` a lower price for the requester to `assign`. The
+//! assigned provider reserves `ProviderDeposit` and has the job's timeout to
+//! submit the hash of its result.
+//!
+//! The requester then has `ReviewPeriod` blocks to `approve` the result or
+//! `dispute` it; without either, anyone may `release` the payment once the
+//! period is over. `ArbitrationOrigin` settles disputes by splitting the
+//! escrow and may forfeit the provider's deposit to the requester, as happens
+//! when a provider misses its deadline.
+//!
+//! The chain only sees hashes: fetching the specification and checking the
+//! result happen off chain, e.g. over HomeCDN.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Named reserve holding requesters' escrows and providers' deposits, so no
+/// other pallet's reserve is paid out or refunded by a job.
+pub const RESERVE_ID: [u8; 8] = *b"compjob/";
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, NamedReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_homecdn::ProviderRegistry;
+    use sp_core::H256;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Perbill,
+    };
+
+    pub type JobId = u64;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// What a job needs from the provider running it.
+    #[derive(
+        Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct Resources {
+        pub cpu_cores: u32,
+        /// Memory, in MiB.
+        pub memory_mib: u32,
+        pub gpus: u32,
+        /// Memory per GPU, in MiB.
+        pub gpu_memory_mib: u32,
+        /// Scratch storage, in GiB.
+        pub storage_gib: u32,
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum JobStatus<AccountId, BlockNumber> {
+        /// Waiting for a provider.
+        Open,
+        /// `provider` runs the job and must submit before `due_at`.
+        Assigned { provider: AccountId, due_at: BlockNumber },
+        /// `provider` submitted `result`; the requester may dispute it until
+        /// `review_until`.
+        Submitted { provider: AccountId, result: H256, review_until: BlockNumber },
+        /// The requester disputed `result`, awaiting `ArbitrationOrigin`.
+        Disputed { provider: AccountId, result: H256 },
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Job<AccountId, Balance, BlockNumber> {
+        pub requester: AccountId,
+        /// Content hash of the job specification.
+        pub spec: H256,
+        pub resources: Resources,
+        /// Escrowed from the requester: the posted reward until the job is
+        /// assigned, then the agreed price.
+        pub reward: Balance,
+        /// Blocks the provider has from assignment to submit.
+        pub timeout: BlockNumber,
+        pub status: JobStatus<AccountId, BlockNumber>,
+    }
+
+    pub type JobOf<T> =
+        Job<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+    pub type BidsOf<T> =
+        BoundedVec<(<T as frame_system::Config>::AccountId, BalanceOf<T>), <T as Config>::MaxBids>;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency rewards and deposits are reserved in, under [`RESERVE_ID`].
+        type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+
+        /// Who may take jobs.
+        type Providers: ProviderRegistry<Self::AccountId, BalanceOf<Self>>;
+
+        /// Settles disputed results.
+        type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Smallest reward a job is posted with.
+        #[pallet::constant]
+        type MinReward: Get<BalanceOf<Self>>;
+
+        /// Reserved from the provider while it holds a job.
+        #[pallet::constant]
+        type ProviderDeposit: Get<BalanceOf<Self>>;
+
+        /// Longest timeout a job may give its provider.
+        #[pallet::constant]
+        type MaxJobDuration: Get<BlockNumberFor<Self>>;
+
+        /// Blocks the requester has to dispute a submitted result.
+        #[pallet::constant]
+        type ReviewPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Most bids on one job.
+        #[pallet::constant]
+        type MaxBids: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    #[pallet::getter(fn job)]
+    pub type Jobs<T: Config> = StorageMap<_, Twox64Concat, JobId, JobOf<T>>;
+
+    #[pallet::storage]
+    pub type NextJobId<T> = StorageValue<_, JobId, ValueQuery>;
+
+    /// Bids on open jobs, as provider and price.
+    #[pallet::storage]
+    #[pallet::getter(fn bids)]
+    pub type Bids<T: Config> = StorageMap<_, Twox64Concat, JobId, BidsOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `requester` posted job `id` for `spec`, escrowing `reward`.
+        Posted { id: JobId, requester: T::AccountId, spec: H256, reward: BalanceOf<T> },
+        /// `provider` offered to run job `id` for `price`.
+        BidPlaced { id: JobId, provider: T::AccountId, price: BalanceOf<T> },
+        /// Job `id` went to `provider` for `price`, due before `due_at`.
+        Assigned {
+            id: JobId,
+            provider: T::AccountId,
+            price: BalanceOf<T>,
+            due_at: BlockNumberFor<T>,
+        },
+        /// `provider` submitted `result`, which can be disputed until
+        /// `review_until`.
+        ResultSubmitted {
+            id: JobId,
+            provider: T::AccountId,
+            result: H256,
+            review_until: BlockNumberFor<T>,
+        },
+        /// `provider` was paid `amount` for job `id`.
+        Paid { id: JobId, provider: T::AccountId, amount: BalanceOf<T> },
+        /// The requester disputed the result of job `id`.
+        Disputed { id: JobId },
+        /// The dispute over job `id` was settled: `paid` to the provider,
+        /// `refunded` to the requester and `forfeited` of the provider's
+        /// deposit to the requester.
+        Resolved { id: JobId, paid: BalanceOf<T>, refunded: BalanceOf<T>, forfeited: BalanceOf<T> },
+        /// Job `id` was cancelled, refunding `refunded`; `forfeited` of the
+        /// provider's deposit went to the requester for a missed deadline.
+        Cancelled { id: JobId, refunded: BalanceOf<T>, forfeited: BalanceOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No job has this id.
+        UnknownJob,
+        /// The reward is below `MinReward`.
+        RewardTooLow,
+        /// The timeout is zero or above `MaxJobDuration`.
+        InvalidTimeout,
+        /// Only active HomeCDN providers may take jobs.
+        NotProvider,
+        /// Only the requester may do this.
+        NotRequester,
+        /// Only the assigned provider may do this.
+        NotAssignedProvider,
+        /// Requesters cannot take their own jobs.
+        OwnJob,
+        /// The job is no longer open.
+        NotOpen,
+        /// The job is not assigned.
+        NotAssigned,
+        /// No result is awaiting review.
+        NotSubmitted,
+        /// The job is not disputed.
+        NotDisputed,
+        /// Bids cannot be above the posted reward.
+        PriceAboveReward,
+        /// The job has `MaxBids` bids.
+        TooManyBids,
+        /// The provider has not bid on the job.
+        NoSuchBid,
+        /// The provider's deadline has passed.
+        DeadlinePassed,
+        /// The provider's deadline has not passed.
+        DeadlineNotReached,
+        /// The review period is not over.
+        InReview,
+        /// The review period is over.
+        ReviewOver,
+        /// A result was submitted; approve or dispute it instead.
+        ResultPending,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Post a job for `spec` needing `resources`, escrowing `reward`. The
+        /// provider will have `timeout` blocks from assignment to submit.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::post())]
+        pub fn post(
+            origin: OriginFor<T>,
+            spec: H256,
+            resources: Resources,
+            #[pallet::compact] reward: BalanceOf<T>,
+            timeout: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            ensure!(reward >= T::MinReward::get(), Error::<T>::RewardTooLow);
+            ensure!(
+                !timeout.is_zero() && timeout <= T::MaxJobDuration::get(),
+                Error::<T>::InvalidTimeout
+            );
+
+            T::Currency::reserve_named(&RESERVE_ID, &requester, reward)?;
+            let id = NextJobId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            Jobs::<T>::insert(
+                id,
+                Job {
+                    requester: requester.clone(),
+                    spec,
+                    resources,
+                    reward,
+                    timeout,
+                    status: JobStatus::Open,
+                },
+            );
+
+            Self::deposit_event(Event::Posted { id, requester, spec, reward });
+            Ok(())
+        }
+
+        /// Offer to run job `id` for `price`, replacing any earlier bid.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::bid())]
+        pub fn bid(
+            origin: OriginFor<T>,
+            id: JobId,
+            #[pallet::compact] price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+            let job = Self::open_job(id, &provider)?;
+            ensure!(price <= job.reward, Error::<T>::PriceAboveReward);
+
+            Bids::<T>::try_mutate(id, |bids| {
+                match bids.iter_mut().find(|(bidder, _)| *bidder == provider) {
+                    Some(bid) => bid.1 = price,
+                    None => bids
+                        .try_push((provider.clone(), price))
+                        .map_err(|_| Error::<T>::TooManyBids)?,
+                }
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::BidPlaced { id, provider, price });
+            Ok(())
+        }
+
+        /// Take job `id` at its full reward.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::accept())]
+        pub fn accept(origin: OriginFor<T>, id: JobId) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+            let job = Self::open_job(id, &provider)?;
+            let price = job.reward;
+            Self::assign_to(id, job, provider, price)
+        }
+
+        /// Give job `id` to `provider` at the price it bid, refunding the rest
+        /// of the reward.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::assign())]
+        pub fn assign(origin: OriginFor<T>, id: JobId, provider: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let job = Self::open_job(id, &provider)?;
+            ensure!(job.requester == who, Error::<T>::NotRequester);
+
+            let (_, price) = Bids::<T>::get(id)
+                .into_iter()
+                .find(|(bidder, _)| *bidder == provider)
+                .ok_or(Error::<T>::NoSuchBid)?;
+            Self::assign_to(id, job, provider, price)
+        }
+
+        /// Submit the content hash of job `id`'s result.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::submit_result())]
+        pub fn submit_result(origin: OriginFor<T>, id: JobId, result: H256) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            let JobStatus::Assigned { provider, due_at } = job.status else {
+                return Err(Error::<T>::NotAssigned.into())
+            };
+            ensure!(provider == who, Error::<T>::NotAssignedProvider);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now < due_at, Error::<T>::DeadlinePassed);
+
+            let review_until = now.saturating_add(T::ReviewPeriod::get());
+            job.status = JobStatus::Submitted { provider: provider.clone(), result, review_until };
+            Jobs::<T>::insert(id, job);
+
+            Self::deposit_event(Event::ResultSubmitted { id, provider, result, review_until });
+            Ok(())
+        }
+
+        /// Accept the result of job `id`, paying the provider.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(origin: OriginFor<T>, id: JobId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            ensure!(job.requester == who, Error::<T>::NotRequester);
+            let JobStatus::Submitted { provider, .. } = job.status.clone() else {
+                return Err(Error::<T>::NotSubmitted.into())
+            };
+
+            Self::pay(id, job, provider)
+        }
+
+        /// Pay the provider of job `id` once the review period has passed
+        /// without a dispute.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::release())]
+        pub fn release(origin: OriginFor<T>, id: JobId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            let JobStatus::Submitted { provider, review_until, .. } = job.status.clone() else {
+                return Err(Error::<T>::NotSubmitted.into())
+            };
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= review_until,
+                Error::<T>::InReview
+            );
+
+            Self::pay(id, job, provider)
+        }
+
+        /// Dispute the result of job `id`, holding the escrow for
+        /// `ArbitrationOrigin`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::dispute())]
+        pub fn dispute(origin: OriginFor<T>, id: JobId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            ensure!(job.requester == who, Error::<T>::NotRequester);
+            let JobStatus::Submitted { provider, result, review_until } = job.status else {
+                return Err(Error::<T>::NotSubmitted.into())
+            };
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < review_until,
+                Error::<T>::ReviewOver
+            );
+
+            job.status = JobStatus::Disputed { provider, result };
+            Jobs::<T>::insert(id, job);
+
+            Self::deposit_event(Event::Disputed { id });
+            Ok(())
+        }
+
+        /// Settle the dispute over job `id`: pay the provider `provider_share`
+        /// of the escrow and refund the rest. With `forfeit`, the provider's
+        /// deposit goes to the requester instead of back to the provider.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::resolve())]
+        pub fn resolve(
+            origin: OriginFor<T>,
+            id: JobId,
+            provider_share: Perbill,
+            forfeit: bool,
+        ) -> DispatchResult {
+            T::ArbitrationOrigin::ensure_origin(origin)?;
+            let job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            let JobStatus::Disputed { provider, .. } = job.status else {
+                return Err(Error::<T>::NotDisputed.into())
+            };
+
+            let share = provider_share * job.reward;
+            let paid = Self::pay_out(&job.requester, &provider, share)?;
+            let refunded = Self::refund(&job.requester, job.reward.saturating_sub(share));
+            let forfeited = Self::release_deposit(&provider, &job.requester, forfeit)?;
+            Jobs::<T>::remove(id);
+
+            Self::deposit_event(Event::Resolved { id, paid, refunded, forfeited });
+            Ok(())
+        }
+
+        /// Withdraw job `id` and its escrow. Open jobs can be cancelled at any
+        /// time; assigned ones once the provider has missed its deadline,
+        /// which forfeits the provider's deposit to the requester.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::cancel())]
+        pub fn cancel(origin: OriginFor<T>, id: JobId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            ensure!(job.requester == who, Error::<T>::NotRequester);
+
+            let forfeited = match job.status {
+                JobStatus::Open => {
+                    Bids::<T>::remove(id);
+                    Zero::zero()
+                }
+                JobStatus::Assigned { provider, due_at } => {
+                    ensure!(
+                        frame_system::Pallet::<T>::block_number() >= due_at,
+                        Error::<T>::DeadlineNotReached
+                    );
+                    Self::release_deposit(&provider, &job.requester, true)?
+                }
+                _ => return Err(Error::<T>::ResultPending.into()),
+            };
+            let refunded = Self::refund(&job.requester, job.reward);
+            Jobs::<T>::remove(id);
+
+            Self::deposit_event(Event::Cancelled { id, refunded, forfeited });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Job `id`, if `provider` may bid on or accept it.
+        fn open_job(id: JobId, provider: &T::AccountId) -> Result<JobOf<T>, DispatchError> {
+            let job = Jobs::<T>::get(id).ok_or(Error::<T>::UnknownJob)?;
+            ensure!(job.status == JobStatus::Open, Error::<T>::NotOpen);
+            ensure!(job.requester != *provider, Error::<T>::OwnJob);
+            ensure!(T::Providers::is_active(provider), Error::<T>::NotProvider);
+            Ok(job)
+        }
+
+        fn assign_to(
+            id: JobId,
+            mut job: JobOf<T>,
+            provider: T::AccountId,
+            price: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::Currency::reserve_named(&RESERVE_ID, &provider, T::ProviderDeposit::get())?;
+            Self::refund(&job.requester, job.reward.saturating_sub(price));
+
+            let due_at = frame_system::Pallet::<T>::block_number().saturating_add(job.timeout);
+            job.reward = price;
+            job.status = JobStatus::Assigned { provider: provider.clone(), due_at };
+            Jobs::<T>::insert(id, job);
+            Bids::<T>::remove(id);
+
+            Self::deposit_event(Event::Assigned { id, provider, price, due_at });
+            Ok(())
+        }
+
+        /// Pay `provider` the escrow of job `id` and return its deposit.
+        fn pay(id: JobId, job: JobOf<T>, provider: T::AccountId) -> DispatchResult {
+            let amount = Self::pay_out(&job.requester, &provider, job.reward)?;
+            Self::release_deposit(&provider, &job.requester, false)?;
+            Jobs::<T>::remove(id);
+
+            Self::deposit_event(Event::Paid { id, provider, amount });
+            Ok(())
+        }
+
+        /// Return `provider`'s deposit, or with `forfeit` hand it to
+        /// `requester`. Returns what was forfeited.
+        fn release_deposit(
+            provider: &T::AccountId,
+            requester: &T::AccountId,
+            forfeit: bool,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let deposit = T::ProviderDeposit::get();
+            if !forfeit {
+                Self::refund(provider, deposit);
+                return Ok(Zero::zero())
+            }
+            Self::pay_out(provider, requester, deposit)
+        }
+
+        /// Move `amount` of `from`'s reserve to `to`. Returns what actually
+        /// moved, which is less if the reserve no longer holds `amount`.
+        fn pay_out(
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let missing = T::Currency::repatriate_reserved_named(
+                &RESERVE_ID,
+                from,
+                to,
+                amount,
+                BalanceStatus::Free,
+            )?;
+            Ok(amount.saturating_sub(missing))
+        }
+
+        /// Return `amount` of `who`'s reserve. Returns what was returned.
+        fn refund(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            amount.saturating_sub(T::Currency::unreserve_named(&RESERVE_ID, who, amount))
+        }
+    }
+}
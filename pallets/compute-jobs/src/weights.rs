@@ -0,0 +1,137 @@
+//! Weights for `pallet_compute_jobs`, not benchmarked yet.
+//!
+//! Costs follow the storage accesses and reserves of each call, with bid lists
+//! charged at `MaxBids`.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_compute_jobs`.
+pub trait WeightInfo {
+    fn post() -> Weight;
+    fn bid() -> Weight;
+    fn accept() -> Weight;
+    fn assign() -> Weight;
+    fn submit_result() -> Weight;
+    fn approve() -> Weight;
+    fn release() -> Weight;
+    fn dispute() -> Weight;
+    fn resolve() -> Weight;
+    fn cancel() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn post() -> Weight {
+        Weight::from_parts(38_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn bid() -> Weight {
+        Weight::from_parts(31_000_000, 5218)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn accept() -> Weight {
+        Weight::from_parts(61_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    fn assign() -> Weight {
+        Weight::from_parts(67_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    fn submit_result() -> Weight {
+        Weight::from_parts(21_000_000, 3629)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn approve() -> Weight {
+        Weight::from_parts(63_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn release() -> Weight {
+        Weight::from_parts(63_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn dispute() -> Weight {
+        Weight::from_parts(20_000_000, 3629)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn resolve() -> Weight {
+        Weight::from_parts(71_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn cancel() -> Weight {
+        Weight::from_parts(66_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn post() -> Weight {
+        Weight::from_parts(38_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn bid() -> Weight {
+        Weight::from_parts(31_000_000, 5218)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn accept() -> Weight {
+        Weight::from_parts(61_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    fn assign() -> Weight {
+        Weight::from_parts(67_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    fn submit_result() -> Weight {
+        Weight::from_parts(21_000_000, 3629)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn approve() -> Weight {
+        Weight::from_parts(63_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn release() -> Weight {
+        Weight::from_parts(63_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn dispute() -> Weight {
+        Weight::from_parts(20_000_000, 3629)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn resolve() -> Weight {
+        Weight::from_parts(71_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn cancel() -> Weight {
+        Weight::from_parts(66_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+}
@@ -0,0 +1,140 @@
+use frame_support::traits::{ConstU16, ConstU32, ConstU64, GenesisBuild};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+use pallet_compute_jobs as compute_jobs;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        HomeCdn: pallet_homecdn,
+        ComputeJobs: compute_jobs,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = u64;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ConstU32<10>;
+    type MaxReserves = ConstU32<10>;
+    type ReserveIdentifier = [u8; 8];
+    type HoldIdentifier = ();
+    type FreezeIdentifier = ();
+    type MaxHolds = ConstU32<0>;
+    type MaxFreezes = ConstU32<0>;
+}
+
+/// Smallest bond a provider is routed with in the mock.
+pub const MIN_BOND: u64 = 100;
+/// Blocks a retired bond stays slashable in the mock.
+pub const RETIREMENT: u64 = 10;
+
+impl pallet_homecdn::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    // Slashed bonds are burned.
+    type OnSlash = ();
+    type SlashOrigin = EnsureRoot<u64>;
+    type MinBond = ConstU64<MIN_BOND>;
+    type RetirementPeriod = ConstU64<RETIREMENT>;
+    type MaxProviders = ConstU32<8>;
+    type MaxRegionLength = ConstU32<8>;
+    type MaxEndpointLength = ConstU32<32>;
+    type WeightInfo = ();
+}
+
+/// Smallest reward in the mock.
+pub const MIN_REWARD: u64 = 10;
+/// Reserved from a provider while it holds a job in the mock.
+pub const PROVIDER_DEPOSIT: u64 = 50;
+/// Longest job timeout in the mock.
+pub const MAX_JOB_DURATION: u64 = 20;
+/// Blocks the requester has to dispute in the mock.
+pub const REVIEW_PERIOD: u64 = 5;
+/// Most bids per job in the mock.
+pub const MAX_BIDS: u32 = 2;
+
+impl compute_jobs::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Providers = HomeCdn;
+    type ArbitrationOrigin = EnsureRoot<u64>;
+    type MinReward = ConstU64<MIN_REWARD>;
+    type ProviderDeposit = ConstU64<PROVIDER_DEPOSIT>;
+    type MaxJobDuration = ConstU64<MAX_JOB_DURATION>;
+    type ReviewPeriod = ConstU64<REVIEW_PERIOD>;
+    type MaxBids = ConstU32<MAX_BIDS>;
+    type WeightInfo = ();
+}
+
+/// Balance of accounts 1 to 4 at genesis.
+pub const ENDOWMENT: u64 = 1_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=4).map(|who| (who, ENDOWMENT)).collect(),
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Register `who` as an active HomeCDN provider with `MIN_BOND`.
+pub fn register_provider(who: u64) {
+    HomeCdn::register(
+        RuntimeOrigin::signed(who),
+        1_024,
+        1_000,
+        b"eu".to_vec(),
+        b"https://cdn".to_vec(),
+        MIN_BOND,
+    )
+    .unwrap();
+}
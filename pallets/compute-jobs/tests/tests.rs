@@ -0,0 +1,291 @@
+use crate::mock::*;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{NamedReservableCurrency, ReservableCurrency},
+};
+use pallet_compute_jobs::{Error, Event, JobStatus, Resources, RESERVE_ID};
+use sp_core::H256;
+use sp_runtime::{DispatchError, Perbill};
+
+const REQUESTER: u64 = 1;
+const SPEC: H256 = H256::repeat_byte(1);
+const RESULT: H256 = H256::repeat_byte(2);
+
+fn resources() -> Resources {
+    Resources { cpu_cores: 8, memory_mib: 32_768, gpus: 1, gpu_memory_mib: 24_576, storage_gib: 50 }
+}
+
+/// Post the next job from `REQUESTER` with `reward` and a 10 block timeout.
+fn post(reward: u64) -> u64 {
+    let id = pallet_compute_jobs::NextJobId::<Test>::get();
+    assert_ok!(ComputeJobs::post(RuntimeOrigin::signed(REQUESTER), SPEC, resources(), reward, 10));
+    id
+}
+
+/// Post a job for 100, have `provider` accept it and submit `RESULT`.
+fn submitted(provider: u64) -> u64 {
+    let id = post(100);
+    assert_ok!(ComputeJobs::accept(RuntimeOrigin::signed(provider), id));
+    assert_ok!(ComputeJobs::submit_result(RuntimeOrigin::signed(provider), id, RESULT));
+    id
+}
+
+#[test]
+fn accepted_job_is_paid_on_approval() {
+    new_test_ext().execute_with(|| {
+        register_provider(2);
+        let id = post(100);
+        System::assert_last_event(
+            Event::Posted { id, requester: REQUESTER, spec: SPEC, reward: 100 }.into(),
+        );
+        assert_eq!(Balances::reserved_balance(REQUESTER), 100);
+
+        assert_ok!(ComputeJobs::accept(RuntimeOrigin::signed(2), id));
+        System::assert_last_event(
+            Event::Assigned { id, provider: 2, price: 100, due_at: 11 }.into(),
+        );
+        assert_eq!(Balances::reserved_balance(2), MIN_BOND + PROVIDER_DEPOSIT);
+
+        assert_ok!(ComputeJobs::submit_result(RuntimeOrigin::signed(2), id, RESULT));
+        System::assert_last_event(
+            Event::ResultSubmitted {
+                id,
+                provider: 2,
+                result: RESULT,
+                review_until: 1 + REVIEW_PERIOD,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ComputeJobs::approve(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NotRequester
+        );
+        assert_ok!(ComputeJobs::approve(RuntimeOrigin::signed(REQUESTER), id));
+        System::assert_last_event(Event::Paid { id, provider: 2, amount: 100 }.into());
+        assert_eq!(Balances::free_balance(REQUESTER), ENDOWMENT - 100);
+        assert_eq!(Balances::free_balance(2), ENDOWMENT - MIN_BOND + 100);
+        assert_eq!(Balances::reserved_balance(2), MIN_BOND);
+        assert_eq!(ComputeJobs::job(id), None);
+    });
+}
+
+#[test]
+fn requester_assigns_a_bid_and_gets_the_difference_back() {
+    new_test_ext().execute_with(|| {
+        for provider in 2..=4 {
+            register_provider(provider);
+        }
+        let id = post(100);
+
+        assert_noop!(
+            ComputeJobs::bid(RuntimeOrigin::signed(2), id, 101),
+            Error::<Test>::PriceAboveReward
+        );
+        assert_ok!(ComputeJobs::bid(RuntimeOrigin::signed(2), id, 80));
+        assert_ok!(ComputeJobs::bid(RuntimeOrigin::signed(3), id, 60));
+        // A provider may change its bid without taking another slot.
+        assert_ok!(ComputeJobs::bid(RuntimeOrigin::signed(3), id, 70));
+        System::assert_last_event(Event::BidPlaced { id, provider: 3, price: 70 }.into());
+        assert_eq!(ComputeJobs::bids(id).into_inner(), vec![(2, 80), (3, 70)]);
+        assert_noop!(
+            ComputeJobs::bid(RuntimeOrigin::signed(4), id, 50),
+            Error::<Test>::TooManyBids
+        );
+
+        assert_noop!(
+            ComputeJobs::assign(RuntimeOrigin::signed(2), id, 3),
+            Error::<Test>::NotRequester
+        );
+        assert_noop!(
+            ComputeJobs::assign(RuntimeOrigin::signed(REQUESTER), id, 4),
+            Error::<Test>::NoSuchBid
+        );
+        assert_ok!(ComputeJobs::assign(RuntimeOrigin::signed(REQUESTER), id, 3));
+        System::assert_last_event(
+            Event::Assigned { id, provider: 3, price: 70, due_at: 11 }.into(),
+        );
+        assert_eq!(Balances::reserved_balance(REQUESTER), 70);
+        assert!(ComputeJobs::bids(id).is_empty());
+
+        assert_noop!(ComputeJobs::accept(RuntimeOrigin::signed(2), id), Error::<Test>::NotOpen);
+        assert_noop!(
+            ComputeJobs::submit_result(RuntimeOrigin::signed(2), id, RESULT),
+            Error::<Test>::NotAssignedProvider
+        );
+    });
+}
+
+#[test]
+fn only_active_providers_take_jobs() {
+    new_test_ext().execute_with(|| {
+        register_provider(REQUESTER);
+        register_provider(2);
+        let id = post(100);
+
+        assert_noop!(ComputeJobs::accept(RuntimeOrigin::signed(3), id), Error::<Test>::NotProvider);
+        assert_noop!(
+            ComputeJobs::bid(RuntimeOrigin::signed(3), id, 50),
+            Error::<Test>::NotProvider
+        );
+        assert_noop!(
+            ComputeJobs::accept(RuntimeOrigin::signed(REQUESTER), id),
+            Error::<Test>::OwnJob
+        );
+
+        assert_ok!(HomeCdn::retire(RuntimeOrigin::signed(2)));
+        assert_noop!(ComputeJobs::accept(RuntimeOrigin::signed(2), id), Error::<Test>::NotProvider);
+        assert_noop!(ComputeJobs::accept(RuntimeOrigin::signed(2), 1), Error::<Test>::UnknownJob);
+    });
+}
+
+#[test]
+fn payment_is_released_after_an_undisputed_review_period() {
+    new_test_ext().execute_with(|| {
+        register_provider(2);
+        let id = submitted(2);
+
+        assert_noop!(ComputeJobs::release(RuntimeOrigin::signed(4), id), Error::<Test>::InReview);
+        System::set_block_number(1 + REVIEW_PERIOD);
+        assert_noop!(
+            ComputeJobs::dispute(RuntimeOrigin::signed(REQUESTER), id),
+            Error::<Test>::ReviewOver
+        );
+
+        assert_ok!(ComputeJobs::release(RuntimeOrigin::signed(4), id));
+        System::assert_last_event(Event::Paid { id, provider: 2, amount: 100 }.into());
+        assert_eq!(Balances::free_balance(2), ENDOWMENT - MIN_BOND + 100);
+    });
+}
+
+#[test]
+fn arbitration_settles_disputes() {
+    new_test_ext().execute_with(|| {
+        register_provider(2);
+        let id = submitted(2);
+
+        assert_noop!(
+            ComputeJobs::dispute(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NotRequester
+        );
+        assert_noop!(
+            ComputeJobs::resolve(RuntimeOrigin::root(), id, Perbill::zero(), false),
+            Error::<Test>::NotDisputed
+        );
+        assert_ok!(ComputeJobs::dispute(RuntimeOrigin::signed(REQUESTER), id));
+        System::assert_last_event(Event::Disputed { id }.into());
+        assert!(matches!(ComputeJobs::job(id).unwrap().status, JobStatus::Disputed { .. }));
+
+        // Neither party can end a dispute on its own.
+        System::set_block_number(1 + REVIEW_PERIOD);
+        assert_noop!(
+            ComputeJobs::release(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NotSubmitted
+        );
+        assert_noop!(
+            ComputeJobs::approve(RuntimeOrigin::signed(REQUESTER), id),
+            Error::<Test>::NotSubmitted
+        );
+        assert_noop!(
+            ComputeJobs::resolve(RuntimeOrigin::signed(REQUESTER), id, Perbill::zero(), true),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(ComputeJobs::resolve(
+            RuntimeOrigin::root(),
+            id,
+            Perbill::from_percent(25),
+            true
+        ));
+        System::assert_last_event(
+            Event::Resolved { id, paid: 25, refunded: 75, forfeited: PROVIDER_DEPOSIT }.into(),
+        );
+        assert_eq!(Balances::free_balance(REQUESTER), ENDOWMENT - 25 + PROVIDER_DEPOSIT);
+        assert_eq!(Balances::free_balance(2), ENDOWMENT - MIN_BOND - PROVIDER_DEPOSIT + 25);
+        assert_eq!(Balances::reserved_balance(2), MIN_BOND);
+        assert_eq!(ComputeJobs::job(id), None);
+    });
+}
+
+#[test]
+fn resolve_counts_only_what_left_the_escrow() {
+    new_test_ext().execute_with(|| {
+        register_provider(2);
+        let id = submitted(2);
+        assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &REQUESTER), 100);
+        assert_ok!(ComputeJobs::dispute(RuntimeOrigin::signed(REQUESTER), id));
+        // Something else releases most of the requester's reserve behind the job.
+        assert_eq!(Balances::unreserve(&REQUESTER, 90), 0);
+
+        assert_ok!(ComputeJobs::resolve(
+            RuntimeOrigin::root(),
+            id,
+            Perbill::from_percent(25),
+            false
+        ));
+
+        System::assert_last_event(
+            Event::Resolved { id, paid: 10, refunded: 0, forfeited: 0 }.into(),
+        );
+        assert_eq!(Balances::free_balance(2), ENDOWMENT - MIN_BOND + 10);
+        assert_eq!(Balances::reserved_balance(2), MIN_BOND);
+    });
+}
+
+#[test]
+fn missed_deadline_lets_the_requester_cancel() {
+    new_test_ext().execute_with(|| {
+        register_provider(2);
+        let id = post(100);
+        assert_ok!(ComputeJobs::accept(RuntimeOrigin::signed(2), id));
+
+        assert_noop!(
+            ComputeJobs::cancel(RuntimeOrigin::signed(REQUESTER), id),
+            Error::<Test>::DeadlineNotReached
+        );
+        System::set_block_number(11);
+        assert_noop!(
+            ComputeJobs::submit_result(RuntimeOrigin::signed(2), id, RESULT),
+            Error::<Test>::DeadlinePassed
+        );
+
+        assert_ok!(ComputeJobs::cancel(RuntimeOrigin::signed(REQUESTER), id));
+        System::assert_last_event(
+            Event::Cancelled { id, refunded: 100, forfeited: PROVIDER_DEPOSIT }.into(),
+        );
+        assert_eq!(Balances::free_balance(REQUESTER), ENDOWMENT + PROVIDER_DEPOSIT);
+        assert_eq!(Balances::reserved_balance(2), MIN_BOND);
+    });
+}
+
+#[test]
+fn open_jobs_can_be_cancelled_but_submitted_ones_cannot() {
+    new_test_ext().execute_with(|| {
+        register_provider(2);
+        let origin = RuntimeOrigin::signed(REQUESTER);
+        assert_noop!(
+            ComputeJobs::post(origin.clone(), SPEC, resources(), MIN_REWARD - 1, 10),
+            Error::<Test>::RewardTooLow
+        );
+        for timeout in [0, MAX_JOB_DURATION + 1] {
+            assert_noop!(
+                ComputeJobs::post(origin.clone(), SPEC, resources(), 100, timeout),
+                Error::<Test>::InvalidTimeout
+            );
+        }
+
+        let id = post(100);
+        assert_ok!(ComputeJobs::bid(RuntimeOrigin::signed(2), id, 80));
+        assert_noop!(
+            ComputeJobs::cancel(RuntimeOrigin::signed(2), id),
+            Error::<Test>::NotRequester
+        );
+        assert_ok!(ComputeJobs::cancel(origin.clone(), id));
+        System::assert_last_event(Event::Cancelled { id, refunded: 100, forfeited: 0 }.into());
+        assert!(ComputeJobs::bids(id).is_empty());
+        assert_eq!(Balances::reserved_balance(REQUESTER), 0);
+
+        let id = submitted(2);
+        assert_noop!(ComputeJobs::cancel(origin, id), Error::<Test>::ResultPending);
+    });
+}
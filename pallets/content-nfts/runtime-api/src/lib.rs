@@ -0,0 +1,51 @@
+//! Runtime API wallets and the BlessImage and BlessVideo apps use to read
+//! content ownership and licensing from `pallet-content-nfts`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{Perbill, RuntimeDebug};
+
+/// What the holder of an item may do with its content.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LicenseTerms {
+    pub commercial_use: bool,
+    pub derivatives: bool,
+    pub attribution: bool,
+    /// Content hash of the full license text, if there is one.
+    pub terms: Option<H256>,
+}
+
+/// An item as wallets and apps see it.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ItemDetails<AccountId, Balance> {
+    pub collection: u32,
+    pub item: u32,
+    pub owner: AccountId,
+    /// Content hash of the image or video.
+    pub content_hash: H256,
+    pub license: LicenseTerms,
+    /// Price the owner sells at, if listed.
+    pub price: Option<Balance>,
+    /// Share of the price a sale pays to `royalty_recipient`.
+    pub royalty: Perbill,
+    pub royalty_recipient: AccountId,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait ContentNftsApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// `item` of `collection`, if it exists.
+        fn item(collection: u32, item: u32) -> Option<ItemDetails<AccountId, Balance>>;
+
+        /// The item minted for `content_hash`, if any.
+        fn item_by_content(content_hash: H256) -> Option<ItemDetails<AccountId, Balance>>;
+
+        /// The terms `item` of `collection` is licensed under.
+        fn license(collection: u32, item: u32) -> Option<LicenseTerms>;
+    }
+}
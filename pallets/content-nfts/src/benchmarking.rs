@@ -0,0 +1,136 @@
+//! Benchmarks for content NFTs.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::{traits::Bounded, Perbill};
+
+const SEED: u32 = 0;
+
+fn fund<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn license() -> License {
+    License {
+        commercial_use: true,
+        derivatives: true,
+        attribution: true,
+        terms: Some(H256::repeat_byte(2)),
+    }
+}
+
+/// Create a collection owned by `creator` with the largest royalty.
+fn create<T: Config>(creator: &T::AccountId) -> Result<CollectionId, &'static str> {
+    fund::<T>(creator);
+    let collection = NextCollectionId::<T>::get();
+    Pallet::<T>::create_collection(
+        RawOrigin::Signed(creator.clone()).into(),
+        T::MaxRoyalty::get(),
+    )?;
+    Ok(collection)
+}
+
+/// Create a collection owned by `creator` and mint one item to it.
+fn mint<T: Config>(creator: &T::AccountId) -> Result<(CollectionId, ItemId), &'static str> {
+    let collection = create::<T>(creator)?;
+    Pallet::<T>::mint(
+        RawOrigin::Signed(creator.clone()).into(),
+        collection,
+        H256::repeat_byte(1),
+        License::default(),
+    )?;
+    Ok((collection, 0))
+}
+
+benchmarks! {
+    create_collection {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), T::MaxRoyalty::get())
+    verify {
+        assert_eq!(Collections::<T>::get(0).unwrap().owner, caller);
+    }
+
+    set_royalty {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection = create::<T>(&caller)?;
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+    }: _(RawOrigin::Signed(caller), collection, Perbill::from_percent(1), recipient.clone())
+    verify {
+        assert_eq!(Collections::<T>::get(collection).unwrap().royalty_recipient, recipient);
+    }
+
+    destroy_collection {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection = create::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller), collection)
+    verify {
+        assert!(!Collections::<T>::contains_key(collection));
+    }
+
+    mint {
+        let caller: T::AccountId = whitelisted_caller();
+        let collection = create::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller.clone()), collection, H256::repeat_byte(1), license())
+    verify {
+        assert_eq!(ContentItems::<T>::get(H256::repeat_byte(1)), Some((collection, 0)));
+    }
+
+    set_license {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = mint::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller), collection, item, license())
+    verify {
+        assert_eq!(Items::<T>::get(collection, item).unwrap().license, license());
+    }
+
+    transfer {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = mint::<T>(&caller)?;
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: _(RawOrigin::Signed(caller), collection, item, dest.clone())
+    verify {
+        assert_eq!(Items::<T>::get(collection, item).unwrap().owner, dest);
+    }
+
+    set_price {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = mint::<T>(&caller)?;
+        let price = T::ItemDeposit::get();
+    }: _(RawOrigin::Signed(caller), collection, item, Some(price))
+    verify {
+        assert_eq!(Items::<T>::get(collection, item).unwrap().price, Some(price));
+    }
+
+    // The worst case pays a royalty to a recipient other than the seller.
+    buy {
+        let creator: T::AccountId = account("creator", 0, SEED);
+        let (collection, item) = mint::<T>(&creator)?;
+        let seller: T::AccountId = account("seller", 0, SEED);
+        fund::<T>(&seller);
+        Pallet::<T>::transfer(
+            RawOrigin::Signed(creator).into(),
+            collection,
+            item,
+            seller.clone(),
+        )?;
+        let price = T::ItemDeposit::get();
+        Pallet::<T>::set_price(RawOrigin::Signed(seller).into(), collection, item, Some(price))?;
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), collection, item, price)
+    verify {
+        assert_eq!(Items::<T>::get(collection, item).unwrap().owner, caller);
+    }
+
+    burn {
+        let caller: T::AccountId = whitelisted_caller();
+        let (collection, item) = mint::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller), collection, item)
+    verify {
+        assert!(ContentItems::<T>::get(H256::repeat_byte(1)).is_none());
+    }
+}
@@ -0,0 +1,480 @@
+//! Content NFTs for BlessImage and BlessVideo.
+//!
+//! A creator opens a collection, reserving `CollectionDeposit`, and mints one
+//! item per piece of content, reserving `ItemDeposit` each. An item carries the
+//! content hash of the image or video and the [`License`] its holder may use
+//! the content under. A content hash is minted at most once across all
+//! collections, so [`ContentItems`] proves who owns a piece of content.
+//!
+//! Holders transfer items or list them at a price in the pallet's currency,
+//! BBTC in the runtime. Every sale pays the collection's royalty share of the
+//! price to its royalty recipient and the rest to the seller. The creator sets
+//! the royalty, up to `MaxRoyalty`, while none of the collection's items are for
+//! sale, and may change an item's license only while it still holds the item,
+//! so a buyer keeps the terms it bought under.
+//!
+//! Wallets and the BlessImage and BlessVideo apps read items and their
+//! licenses through `pallet-content-nfts-runtime-api`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_core::H256;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Perbill,
+    };
+
+    pub type CollectionId = u32;
+    pub type ItemId = u32;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Collection<AccountId, Balance> {
+        /// The creator, who mints and licenses the items.
+        pub owner: AccountId,
+        /// Reserved from the owner until the collection is destroyed.
+        pub deposit: Balance,
+        /// Share of every sale price paid to `royalty_recipient`.
+        pub royalty: Perbill,
+        pub royalty_recipient: AccountId,
+        /// Items minted and not burned.
+        pub items: u32,
+        /// Items currently for sale. The royalty cannot change while any are.
+        pub listed: u32,
+        /// Id of the next item minted.
+        pub next_item: ItemId,
+    }
+
+    /// What the holder of an item may do with its content.
+    #[derive(
+        Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct License {
+        /// Use the content commercially.
+        pub commercial_use: bool,
+        /// Publish works derived from the content.
+        pub derivatives: bool,
+        /// Uses must credit the creator.
+        pub attribution: bool,
+        /// Content hash of the full license text, if there is one.
+        pub terms: Option<H256>,
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Item<AccountId, Balance> {
+        pub owner: AccountId,
+        /// Content hash of the image or video.
+        pub content_hash: H256,
+        pub license: License,
+        /// Reserved from the collection owner until the item is burned.
+        pub deposit: Balance,
+        /// Price the owner sells at, if listed.
+        pub price: Option<Balance>,
+    }
+
+    pub type CollectionOf<T> = Collection<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+    pub type ItemOf<T> = Item<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency deposits are reserved and sales are paid in.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Reserved from the creator per collection.
+        #[pallet::constant]
+        type CollectionDeposit: Get<BalanceOf<Self>>;
+
+        /// Reserved from the creator per item.
+        #[pallet::constant]
+        type ItemDeposit: Get<BalanceOf<Self>>;
+
+        /// Largest royalty a collection may set.
+        #[pallet::constant]
+        type MaxRoyalty: Get<Perbill>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    #[pallet::getter(fn collection)]
+    pub type Collections<T: Config> = StorageMap<_, Twox64Concat, CollectionId, CollectionOf<T>>;
+
+    #[pallet::storage]
+    pub type NextCollectionId<T> = StorageValue<_, CollectionId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn item)]
+    pub type Items<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, CollectionId, Twox64Concat, ItemId, ItemOf<T>>;
+
+    /// The item minted for each content hash.
+    #[pallet::storage]
+    #[pallet::getter(fn content_item)]
+    pub type ContentItems<T: Config> =
+        StorageMap<_, Blake2_128Concat, H256, (CollectionId, ItemId)>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `owner` created `collection`, paying `royalty` of sales to itself.
+        CollectionCreated { collection: CollectionId, owner: T::AccountId, royalty: Perbill },
+        /// Sales in `collection` now pay `royalty` to `recipient`.
+        RoyaltySet { collection: CollectionId, royalty: Perbill, recipient: T::AccountId },
+        /// `collection` was destroyed and its deposit released.
+        CollectionDestroyed { collection: CollectionId },
+        /// `item` was minted to `owner` for `content_hash`.
+        Minted { collection: CollectionId, item: ItemId, owner: T::AccountId, content_hash: H256 },
+        /// `item` is now licensed under `license`.
+        LicenseSet { collection: CollectionId, item: ItemId, license: License },
+        /// `item` moved from `from` to `to`.
+        Transferred { collection: CollectionId, item: ItemId, from: T::AccountId, to: T::AccountId },
+        /// `item` is for sale at `price`, or no longer for sale.
+        PriceSet { collection: CollectionId, item: ItemId, price: Option<BalanceOf<T>> },
+        /// `buyer` bought `item` from `seller` for `price`, `royalty` of which
+        /// went to the royalty recipient.
+        Sold {
+            collection: CollectionId,
+            item: ItemId,
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            price: BalanceOf<T>,
+            royalty: BalanceOf<T>,
+        },
+        /// `item` was burned and its content hash freed.
+        Burned { collection: CollectionId, item: ItemId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No such collection.
+        UnknownCollection,
+        /// No such item.
+        UnknownItem,
+        /// The caller does not own the collection.
+        NotCollectionOwner,
+        /// The caller does not own the item.
+        NotItemOwner,
+        /// The royalty is above `MaxRoyalty`.
+        RoyaltyTooHigh,
+        /// The collection still has items.
+        CollectionNotEmpty,
+        /// An item was minted for this content hash already.
+        ContentAlreadyMinted,
+        /// The item is not for sale.
+        NotForSale,
+        /// The price is above what the buyer offered.
+        PriceTooHigh,
+        /// The buyer owns the item.
+        AlreadyOwned,
+        /// Items of the collection are for sale.
+        ItemsListed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a collection paying `royalty` of every sale to the caller,
+        /// reserving `CollectionDeposit`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_collection())]
+        pub fn create_collection(origin: OriginFor<T>, royalty: Perbill) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+
+            let deposit = T::CollectionDeposit::get();
+            T::Currency::reserve(&owner, deposit)?;
+            let collection = NextCollectionId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            Collections::<T>::insert(
+                collection,
+                Collection {
+                    owner: owner.clone(),
+                    deposit,
+                    royalty,
+                    royalty_recipient: owner.clone(),
+                    items: 0,
+                    listed: 0,
+                    next_item: 0,
+                },
+            );
+
+            Self::deposit_event(Event::CollectionCreated { collection, owner, royalty });
+            Ok(())
+        }
+
+        /// Pay `royalty` of future sales in `collection` to `recipient`. Only
+        /// while none of its items are for sale, so a listing keeps the
+        /// royalty it was published with.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_royalty())]
+        pub fn set_royalty(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            royalty: Perbill,
+            recipient: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(royalty <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+
+            Collections::<T>::try_mutate(collection, |maybe_collection| {
+                let details = maybe_collection.as_mut().ok_or(Error::<T>::UnknownCollection)?;
+                ensure!(details.owner == who, Error::<T>::NotCollectionOwner);
+                ensure!(details.listed == 0, Error::<T>::ItemsListed);
+                details.royalty = royalty;
+                details.royalty_recipient = recipient.clone();
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::RoyaltySet { collection, royalty, recipient });
+            Ok(())
+        }
+
+        /// Destroy an empty collection and release its deposit.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::destroy_collection())]
+        pub fn destroy_collection(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let details = Collections::<T>::get(collection).ok_or(Error::<T>::UnknownCollection)?;
+            ensure!(details.owner == who, Error::<T>::NotCollectionOwner);
+            ensure!(details.items == 0, Error::<T>::CollectionNotEmpty);
+
+            T::Currency::unreserve(&who, details.deposit);
+            Collections::<T>::remove(collection);
+
+            Self::deposit_event(Event::CollectionDestroyed { collection });
+            Ok(())
+        }
+
+        /// Mint an item for `content_hash` to the caller, licensed under
+        /// `license`, reserving `ItemDeposit`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::mint())]
+        pub fn mint(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            content_hash: H256,
+            license: License,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(
+                !ContentItems::<T>::contains_key(content_hash),
+                Error::<T>::ContentAlreadyMinted
+            );
+
+            let item = Collections::<T>::try_mutate(collection, |maybe_collection| {
+                let details = maybe_collection.as_mut().ok_or(Error::<T>::UnknownCollection)?;
+                ensure!(details.owner == owner, Error::<T>::NotCollectionOwner);
+                let item = details.next_item;
+                details.next_item = item.saturating_add(1);
+                details.items.saturating_inc();
+                Ok::<_, DispatchError>(item)
+            })?;
+            let deposit = T::ItemDeposit::get();
+            T::Currency::reserve(&owner, deposit)?;
+            Items::<T>::insert(
+                collection,
+                item,
+                Item { owner: owner.clone(), content_hash, license, deposit, price: None },
+            );
+            ContentItems::<T>::insert(content_hash, (collection, item));
+
+            Self::deposit_event(Event::Minted { collection, item, owner, content_hash });
+            Ok(())
+        }
+
+        /// License `item` under `license` instead. Only the collection owner
+        /// may, while it holds the item.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_license())]
+        pub fn set_license(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            item: ItemId,
+            license: License,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let creator =
+                Collections::<T>::get(collection).ok_or(Error::<T>::UnknownCollection)?.owner;
+            ensure!(creator == who, Error::<T>::NotCollectionOwner);
+
+            Items::<T>::try_mutate(collection, item, |maybe_item| {
+                let details = maybe_item.as_mut().ok_or(Error::<T>::UnknownItem)?;
+                ensure!(details.owner == who, Error::<T>::NotItemOwner);
+                details.license = license;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::LicenseSet { collection, item, license });
+            Ok(())
+        }
+
+        /// Give `item` to `dest`, taking it off sale.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::transfer())]
+        pub fn transfer(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            item: ItemId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+
+            let was_listed = Items::<T>::try_mutate(collection, item, |maybe_item| {
+                let details = maybe_item.as_mut().ok_or(Error::<T>::UnknownItem)?;
+                ensure!(details.owner == from, Error::<T>::NotItemOwner);
+                details.owner = dest.clone();
+                Ok::<_, DispatchError>(details.price.take().is_some())
+            })?;
+            Self::note_listing(collection, was_listed, false);
+
+            Self::deposit_event(Event::Transferred { collection, item, from, to: dest });
+            Ok(())
+        }
+
+        /// Offer `item` for sale at `price`, or take it off sale with `None`.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_price())]
+        pub fn set_price(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            item: ItemId,
+            price: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let was_listed = Items::<T>::try_mutate(collection, item, |maybe_item| {
+                let details = maybe_item.as_mut().ok_or(Error::<T>::UnknownItem)?;
+                ensure!(details.owner == who, Error::<T>::NotItemOwner);
+                Ok::<_, DispatchError>(core::mem::replace(&mut details.price, price).is_some())
+            })?;
+            Self::note_listing(collection, was_listed, price.is_some());
+
+            Self::deposit_event(Event::PriceSet { collection, item, price });
+            Ok(())
+        }
+
+        /// Buy `item` at its price, if that is at most `max_price`. The
+        /// collection's royalty share goes to its royalty recipient, the rest
+        /// to the seller.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::buy())]
+        pub fn buy(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            item: ItemId,
+            #[pallet::compact] max_price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+            let details = Collections::<T>::get(collection).ok_or(Error::<T>::UnknownCollection)?;
+            let mut bought = Items::<T>::get(collection, item).ok_or(Error::<T>::UnknownItem)?;
+            let price = bought.price.ok_or(Error::<T>::NotForSale)?;
+            ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+            let seller = bought.owner.clone();
+            ensure!(seller != buyer, Error::<T>::AlreadyOwned);
+
+            let royalty = if details.royalty_recipient == seller {
+                Zero::zero()
+            } else {
+                details.royalty * price
+            };
+            if !royalty.is_zero() {
+                T::Currency::transfer(
+                    &buyer,
+                    &details.royalty_recipient,
+                    royalty,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+            }
+            T::Currency::transfer(
+                &buyer,
+                &seller,
+                price.saturating_sub(royalty),
+                ExistenceRequirement::KeepAlive,
+            )?;
+            bought.owner = buyer.clone();
+            bought.price = None;
+            Items::<T>::insert(collection, item, bought);
+            Self::note_listing(collection, true, false);
+
+            Self::deposit_event(Event::Sold { collection, item, seller, buyer, price, royalty });
+            Ok(())
+        }
+
+        /// Burn `item`, freeing its content hash and releasing its deposit to
+        /// the collection owner.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::burn())]
+        pub fn burn(
+            origin: OriginFor<T>,
+            collection: CollectionId,
+            item: ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let burned = Items::<T>::get(collection, item).ok_or(Error::<T>::UnknownItem)?;
+            ensure!(burned.owner == who, Error::<T>::NotItemOwner);
+
+            let creator = Collections::<T>::mutate(collection, |maybe_collection| {
+                maybe_collection.as_mut().map(|details| {
+                    details.items.saturating_dec();
+                    if burned.price.is_some() {
+                        details.listed.saturating_dec();
+                    }
+                    details.owner.clone()
+                })
+            })
+            .ok_or(Error::<T>::UnknownCollection)?;
+            T::Currency::unreserve(&creator, burned.deposit);
+            Items::<T>::remove(collection, item);
+            ContentItems::<T>::remove(burned.content_hash);
+
+            Self::deposit_event(Event::Burned { collection, item });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Count an item of `collection` going on or off sale.
+        fn note_listing(collection: CollectionId, was_listed: bool, listed: bool) {
+            if was_listed == listed {
+                return
+            }
+            Collections::<T>::mutate(collection, |maybe_collection| {
+                if let Some(details) = maybe_collection {
+                    if listed {
+                        details.listed.saturating_inc();
+                    } else {
+                        details.listed.saturating_dec();
+                    }
+                }
+            });
+        }
+    }
+}
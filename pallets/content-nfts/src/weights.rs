@@ -0,0 +1,125 @@
+//! Weights for `pallet_content_nfts`, not benchmarked yet.
+//!
+//! Costs follow the storage accesses, reserves and transfers of each call.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_content_nfts`.
+pub trait WeightInfo {
+    fn create_collection() -> Weight;
+    fn set_royalty() -> Weight;
+    fn destroy_collection() -> Weight;
+    fn mint() -> Weight;
+    fn set_license() -> Weight;
+    fn transfer() -> Weight;
+    fn set_price() -> Weight;
+    fn buy() -> Weight;
+    fn burn() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn create_collection() -> Weight {
+        Weight::from_parts(30_000_000, 1489)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn set_royalty() -> Weight {
+        Weight::from_parts(16_000_000, 3591)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn destroy_collection() -> Weight {
+        Weight::from_parts(28_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn mint() -> Weight {
+        Weight::from_parts(40_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    fn set_license() -> Weight {
+        Weight::from_parts(19_000_000, 3649)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn transfer() -> Weight {
+        Weight::from_parts(17_000_000, 3649)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn set_price() -> Weight {
+        Weight::from_parts(16_000_000, 3649)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn buy() -> Weight {
+        Weight::from_parts(62_000_000, 8799)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    fn burn() -> Weight {
+        Weight::from_parts(38_000_000, 3649)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn create_collection() -> Weight {
+        Weight::from_parts(30_000_000, 1489)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn set_royalty() -> Weight {
+        Weight::from_parts(16_000_000, 3591)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn destroy_collection() -> Weight {
+        Weight::from_parts(28_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn mint() -> Weight {
+        Weight::from_parts(40_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    fn set_license() -> Weight {
+        Weight::from_parts(19_000_000, 3649)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn transfer() -> Weight {
+        Weight::from_parts(17_000_000, 3649)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_price() -> Weight {
+        Weight::from_parts(16_000_000, 3649)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn buy() -> Weight {
+        Weight::from_parts(62_000_000, 8799)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    fn burn() -> Weight {
+        Weight::from_parts(38_000_000, 3649)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+}
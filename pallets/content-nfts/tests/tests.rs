@@ -0,0 +1,247 @@
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_content_nfts::{CollectionId, Error, Event, ItemId, License};
+use sp_core::H256;
+use sp_runtime::Perbill;
+
+/// `creator` creates a collection with `royalty` percent.
+fn create(creator: u64, royalty: u32) -> CollectionId {
+    let collection = pallet_content_nfts::NextCollectionId::<Test>::get();
+    assert_ok!(ContentNfts::create_collection(
+        RuntimeOrigin::signed(creator),
+        Perbill::from_percent(royalty)
+    ));
+    collection
+}
+
+/// `creator` mints an item for the content hash repeating `byte`.
+fn mint(creator: u64, collection: CollectionId, byte: u8) -> ItemId {
+    let item = ContentNfts::collection(collection).unwrap().next_item;
+    assert_ok!(ContentNfts::mint(
+        RuntimeOrigin::signed(creator),
+        collection,
+        H256::repeat_byte(byte),
+        License::default()
+    ));
+    item
+}
+
+#[test]
+fn collections_reserve_deposits_and_are_destroyed_once_empty() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ContentNfts::create_collection(RuntimeOrigin::signed(1), Perbill::from_percent(21)),
+            Error::<Test>::RoyaltyTooHigh
+        );
+        let collection = create(1, 10);
+        System::assert_last_event(
+            Event::CollectionCreated { collection, owner: 1, royalty: Perbill::from_percent(10) }
+                .into(),
+        );
+        assert_eq!(Balances::reserved_balance(1), COLLECTION_DEPOSIT);
+
+        let item = mint(1, collection, 1);
+        assert_eq!(Balances::reserved_balance(1), COLLECTION_DEPOSIT + ITEM_DEPOSIT);
+        assert_noop!(
+            ContentNfts::destroy_collection(RuntimeOrigin::signed(1), collection),
+            Error::<Test>::CollectionNotEmpty
+        );
+
+        assert_ok!(ContentNfts::burn(RuntimeOrigin::signed(1), collection, item));
+        assert_noop!(
+            ContentNfts::destroy_collection(RuntimeOrigin::signed(2), collection),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_ok!(ContentNfts::destroy_collection(RuntimeOrigin::signed(1), collection));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert!(ContentNfts::collection(collection).is_none());
+    });
+}
+
+#[test]
+fn each_content_hash_is_minted_once() {
+    new_test_ext().execute_with(|| {
+        let first = create(1, 0);
+        let second = create(2, 0);
+        let content = H256::repeat_byte(1);
+        assert_noop!(
+            ContentNfts::mint(RuntimeOrigin::signed(2), first, content, License::default()),
+            Error::<Test>::NotCollectionOwner
+        );
+
+        let item = mint(1, first, 1);
+        System::assert_last_event(
+            Event::Minted { collection: first, item, owner: 1, content_hash: content }.into(),
+        );
+        assert_eq!(ContentNfts::content_item(content), Some((first, item)));
+        assert_noop!(
+            ContentNfts::mint(RuntimeOrigin::signed(2), second, content, License::default()),
+            Error::<Test>::ContentAlreadyMinted
+        );
+
+        // Burning frees the content hash.
+        assert_ok!(ContentNfts::burn(RuntimeOrigin::signed(1), first, item));
+        let item = mint(2, second, 1);
+        assert_eq!(ContentNfts::content_item(content), Some((second, item)));
+    });
+}
+
+#[test]
+fn transfer_moves_the_item_and_takes_it_off_sale() {
+    new_test_ext().execute_with(|| {
+        let collection = create(1, 0);
+        let item = mint(1, collection, 1);
+        assert_noop!(
+            ContentNfts::set_price(RuntimeOrigin::signed(2), collection, item, Some(100)),
+            Error::<Test>::NotItemOwner
+        );
+        assert_ok!(ContentNfts::set_price(RuntimeOrigin::signed(1), collection, item, Some(100)));
+
+        assert_ok!(ContentNfts::transfer(RuntimeOrigin::signed(1), collection, item, 2));
+        System::assert_last_event(Event::Transferred { collection, item, from: 1, to: 2 }.into());
+        let details = ContentNfts::item(collection, item).unwrap();
+        assert_eq!((details.owner, details.price), (2, None));
+        assert_noop!(
+            ContentNfts::transfer(RuntimeOrigin::signed(1), collection, item, 3),
+            Error::<Test>::NotItemOwner
+        );
+        // The deposit stays with the creator.
+        assert_eq!(Balances::reserved_balance(1), COLLECTION_DEPOSIT + ITEM_DEPOSIT);
+    });
+}
+
+#[test]
+fn resales_pay_the_royalty_to_the_recipient() {
+    new_test_ext().execute_with(|| {
+        let collection = create(1, 10);
+        assert_noop!(
+            ContentNfts::set_royalty(
+                RuntimeOrigin::signed(2),
+                collection,
+                Perbill::from_percent(10),
+                4
+            ),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_ok!(ContentNfts::set_royalty(
+            RuntimeOrigin::signed(1),
+            collection,
+            Perbill::from_percent(10),
+            4
+        ));
+        let item = mint(1, collection, 1);
+        assert_ok!(ContentNfts::transfer(RuntimeOrigin::signed(1), collection, item, 2));
+        assert_noop!(
+            ContentNfts::buy(RuntimeOrigin::signed(3), collection, item, 200),
+            Error::<Test>::NotForSale
+        );
+        assert_ok!(ContentNfts::set_price(RuntimeOrigin::signed(2), collection, item, Some(200)));
+        assert_noop!(
+            ContentNfts::buy(RuntimeOrigin::signed(3), collection, item, 199),
+            Error::<Test>::PriceTooHigh
+        );
+        assert_noop!(
+            ContentNfts::buy(RuntimeOrigin::signed(2), collection, item, 200),
+            Error::<Test>::AlreadyOwned
+        );
+
+        assert_ok!(ContentNfts::buy(RuntimeOrigin::signed(3), collection, item, 200));
+        System::assert_last_event(
+            Event::Sold { collection, item, seller: 2, buyer: 3, price: 200, royalty: 20 }.into(),
+        );
+        assert_eq!(Balances::free_balance(2), ENDOWMENT + 180);
+        assert_eq!(Balances::free_balance(3), ENDOWMENT - 200);
+        assert_eq!(Balances::free_balance(4), ENDOWMENT + 20);
+        let details = ContentNfts::item(collection, item).unwrap();
+        assert_eq!((details.owner, details.price), (3, None));
+    });
+}
+
+#[test]
+fn royalty_is_fixed_while_items_are_listed() {
+    new_test_ext().execute_with(|| {
+        let collection = create(1, 5);
+        let sold = mint(1, collection, 1);
+        let burned = mint(1, collection, 2);
+        assert_ok!(ContentNfts::transfer(RuntimeOrigin::signed(1), collection, sold, 2));
+        assert_ok!(ContentNfts::set_price(RuntimeOrigin::signed(2), collection, sold, Some(100)));
+        assert_ok!(ContentNfts::set_price(RuntimeOrigin::signed(1), collection, burned, Some(50)));
+        assert_ok!(ContentNfts::set_price(RuntimeOrigin::signed(1), collection, burned, Some(60)));
+        assert_eq!(ContentNfts::collection(collection).unwrap().listed, 2);
+
+        let raise = || {
+            ContentNfts::set_royalty(
+                RuntimeOrigin::signed(1),
+                collection,
+                Perbill::from_percent(20),
+                1,
+            )
+        };
+        assert_noop!(raise(), Error::<Test>::ItemsListed);
+
+        assert_ok!(ContentNfts::buy(RuntimeOrigin::signed(3), collection, sold, 100));
+        System::assert_last_event(
+            Event::Sold { collection, item: sold, seller: 2, buyer: 3, price: 100, royalty: 5 }
+                .into(),
+        );
+        assert_noop!(raise(), Error::<Test>::ItemsListed);
+
+        assert_ok!(ContentNfts::burn(RuntimeOrigin::signed(1), collection, burned));
+        assert_eq!(ContentNfts::collection(collection).unwrap().listed, 0);
+        assert_ok!(raise());
+    });
+}
+
+#[test]
+fn the_royalty_recipient_selling_keeps_the_whole_price() {
+    new_test_ext().execute_with(|| {
+        let collection = create(1, 20);
+        let item = mint(1, collection, 1);
+        assert_ok!(ContentNfts::set_price(RuntimeOrigin::signed(1), collection, item, Some(100)));
+        let creator = Balances::free_balance(1);
+
+        assert_ok!(ContentNfts::buy(RuntimeOrigin::signed(2), collection, item, 100));
+        System::assert_last_event(
+            Event::Sold { collection, item, seller: 1, buyer: 2, price: 100, royalty: 0 }.into(),
+        );
+        assert_eq!(Balances::free_balance(1), creator + 100);
+    });
+}
+
+#[test]
+fn licenses_change_only_while_the_creator_holds_the_item() {
+    new_test_ext().execute_with(|| {
+        let collection = create(1, 0);
+        let item = mint(1, collection, 1);
+        let license = License {
+            commercial_use: true,
+            derivatives: false,
+            attribution: true,
+            terms: Some(H256::repeat_byte(9)),
+        };
+        assert_ok!(ContentNfts::set_license(RuntimeOrigin::signed(1), collection, item, license));
+        System::assert_last_event(Event::LicenseSet { collection, item, license }.into());
+
+        // The buyer keeps the terms it got the item under.
+        assert_ok!(ContentNfts::transfer(RuntimeOrigin::signed(1), collection, item, 2));
+        assert_noop!(
+            ContentNfts::set_license(
+                RuntimeOrigin::signed(1),
+                collection,
+                item,
+                License::default()
+            ),
+            Error::<Test>::NotItemOwner
+        );
+        assert_noop!(
+            ContentNfts::set_license(
+                RuntimeOrigin::signed(2),
+                collection,
+                item,
+                License::default()
+            ),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_eq!(ContentNfts::item(collection, item).unwrap().license, license);
+    });
+}
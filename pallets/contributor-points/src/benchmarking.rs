@@ -0,0 +1,82 @@
+//! Benchmarks for the contributor points pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::{prelude::*, vec};
+
+const SEED: u32 = 0;
+const POINTS: Points = 1_000;
+
+fn award<T: Config>(who: &T::AccountId) -> Result<(), &'static str> {
+    let origin = T::AwardOrigin::try_successful_origin().map_err(|_| "no award origin")?;
+    Pallet::<T>::award_points(origin, who.clone(), POINTS, vec![])?;
+    Ok(())
+}
+
+fn fund_pool<T: Config>() -> BalanceOf<T> {
+    let revenue = T::Currency::minimum_balance() * 1_000u32.into();
+    T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), revenue);
+    PendingRevenue::<T>::put(revenue);
+    revenue
+}
+
+benchmarks! {
+    // Worst case: the account already holds points earned in a closed epoch,
+    // so it is settled before the award.
+    award_points {
+        let r in 0 .. T::MaxReasonLength::get();
+        let origin = T::AwardOrigin::try_successful_origin().map_err(|_| "no award origin")?;
+        let who: T::AccountId = account("contributor", 0, SEED);
+        award::<T>(&who)?;
+        fund_pool::<T>();
+        Pallet::<T>::close_epoch();
+    }: _<T::RuntimeOrigin>(origin, who.clone(), POINTS, vec![0; r as usize])
+    verify {
+        assert_eq!(Contributors::<T>::get(&who).points, 2 * POINTS);
+    }
+
+    revoke_points {
+        let r in 0 .. T::MaxReasonLength::get();
+        let origin = T::AwardOrigin::try_successful_origin().map_err(|_| "no award origin")?;
+        let who: T::AccountId = account("contributor", 0, SEED);
+        award::<T>(&who)?;
+        fund_pool::<T>();
+        Pallet::<T>::close_epoch();
+    }: _<T::RuntimeOrigin>(origin, who.clone(), POINTS / 2, vec![0; r as usize])
+    verify {
+        assert_eq!(Contributors::<T>::get(&who).points, POINTS / 2);
+    }
+
+    deposit_revenue {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+        let amount = T::Currency::minimum_balance() * 1_000u32.into();
+    }: _(RawOrigin::Signed(caller), amount)
+    verify {
+        assert_eq!(PendingRevenue::<T>::get(), amount);
+    }
+
+    claim {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+        award::<T>(&caller)?;
+        let revenue = fund_pool::<T>();
+        Pallet::<T>::close_epoch();
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(T::Currency::free_balance(&caller), T::Currency::minimum_balance() + revenue);
+    }
+
+    close_epoch {
+        award::<T>(&account("contributor", 0, SEED))?;
+        fund_pool::<T>();
+    }: {
+        Pallet::<T>::close_epoch();
+    }
+    verify {
+        assert_eq!(CurrentEpoch::<T>::get(), 1);
+    }
+}
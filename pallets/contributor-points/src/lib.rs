@@ -0,0 +1,335 @@
+//! Non-transferable contributor points and a revenue pool shared among them.
+//!
+//! `AwardOrigin` awards points to contributors, and may revoke them to correct
+//! a mistake; there is no way to transfer points. Anyone can pay revenue into
+//! the pool, and other pallets can route imbalances to it through
+//! `OnUnbalanced`. Every `EpochLength` blocks the revenue paid in during the
+//! epoch is split pro rata over the points held at that moment, and holders
+//! claim their share whenever they like.
+//!
+//! Shares are tracked with a running revenue-per-point total, so closing an
+//! epoch and claiming cost the same whatever the number of contributors.
+//! Revenue of an epoch without points, and the remainder that does not divide
+//! evenly, roll over to the next epoch. Awards, deposits, epoch closes and
+//! claims each emit an event with the figures used, so every payout can be
+//! recomputed from events alone.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, Imbalance, OnUnbalanced},
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{AccountIdConversion, Saturating, Zero},
+        FixedPointNumber, FixedU128, SaturatedConversion,
+    };
+    use sp_std::prelude::*;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
+    /// Contributor points. A plain counter, not a balance.
+    pub type Points = u128;
+    pub type EpochIndex = u32;
+
+    #[derive(
+        Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct Contributor<Balance> {
+        /// Points currently held.
+        pub points: Points,
+        /// [`RevenuePerPoint`] when `unclaimed` was last brought up to date.
+        pub revenue_per_point_paid: FixedU128,
+        /// Revenue earned in closed epochs and not claimed yet.
+        pub unclaimed: Balance,
+    }
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency the revenue pool is held in.
+        type Currency: Currency<Self::AccountId>;
+
+        /// May award and revoke points.
+        type AwardOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Derives the pool account.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Blocks per epoch. Must not be zero.
+        #[pallet::constant]
+        type EpochLength: Get<BlockNumberFor<Self>>;
+
+        /// Longest reason attached to an award or revocation, in bytes.
+        #[pallet::constant]
+        type MaxReasonLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    pub type ReasonOf<T> = BoundedVec<u8, <T as Config>::MaxReasonLength>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn contributor)]
+    pub type Contributors<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Contributor<BalanceOf<T>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn total_points)]
+    pub type TotalPoints<T: Config> = StorageValue<_, Points, ValueQuery>;
+
+    /// Revenue one point has earned over all closed epochs.
+    #[pallet::storage]
+    pub type RevenuePerPoint<T: Config> = StorageValue<_, FixedU128, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn current_epoch)]
+    pub type CurrentEpoch<T: Config> = StorageValue<_, EpochIndex, ValueQuery>;
+
+    /// Revenue in the pool not yet shared out, paid in during the current
+    /// epoch or rolled over from earlier ones.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_revenue)]
+    pub type PendingRevenue<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `who` was awarded `points` and now holds `total`.
+        PointsAwarded { who: T::AccountId, points: Points, total: Points, reason: ReasonOf<T> },
+        /// `points` were taken from `who`, who now holds `total`.
+        PointsRevoked { who: T::AccountId, points: Points, total: Points, reason: ReasonOf<T> },
+        /// `amount` was paid into the pool, by `from` or by another pallet.
+        RevenueDeposited { from: Option<T::AccountId>, amount: BalanceOf<T> },
+        /// `revenue` was shared over `total_points`, `revenue_per_point` each.
+        EpochClosed {
+            epoch: EpochIndex,
+            revenue: BalanceOf<T>,
+            total_points: Points,
+            revenue_per_point: FixedU128,
+        },
+        /// `who` was paid `amount` from the pool.
+        RevenueClaimed { who: T::AccountId, amount: BalanceOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The reason is longer than `MaxReasonLength`.
+        ReasonTooLong,
+        /// Zero points or a zero amount.
+        ZeroAmount,
+        /// The account holds fewer points than revoked.
+        InsufficientPoints,
+        /// The account has no revenue to claim.
+        NothingToClaim,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            if (n % T::EpochLength::get()).is_zero() {
+                Self::close_epoch();
+                T::WeightInfo::close_epoch()
+            } else {
+                Weight::zero()
+            }
+        }
+
+        fn integrity_test() {
+            assert!(!T::EpochLength::get().is_zero(), "`EpochLength` must not be zero");
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Give `who` `points` more points. They share in revenue from the
+        /// epoch that is currently open.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::award_points(reason.len() as u32))]
+        pub fn award_points(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            points: Points,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::AwardOrigin::ensure_origin(origin)?;
+            ensure!(!points.is_zero(), Error::<T>::ZeroAmount);
+            let reason: ReasonOf<T> = reason.try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+
+            let total = Contributors::<T>::mutate(&who, |contributor| {
+                Self::settle(contributor);
+                contributor.points = contributor.points.saturating_add(points);
+                contributor.points
+            });
+            TotalPoints::<T>::mutate(|total| *total = total.saturating_add(points));
+
+            Self::deposit_event(Event::PointsAwarded { who, points, total, reason });
+            Ok(())
+        }
+
+        /// Take `points` back from `who`. Revenue of closed epochs already
+        /// earned with them is kept.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke_points(reason.len() as u32))]
+        pub fn revoke_points(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            points: Points,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::AwardOrigin::ensure_origin(origin)?;
+            ensure!(!points.is_zero(), Error::<T>::ZeroAmount);
+            let reason: ReasonOf<T> = reason.try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+
+            let total = Contributors::<T>::try_mutate_exists(&who, |maybe_contributor| {
+                let contributor =
+                    maybe_contributor.as_mut().ok_or(Error::<T>::InsufficientPoints)?;
+                ensure!(contributor.points >= points, Error::<T>::InsufficientPoints);
+                Self::settle(contributor);
+                contributor.points -= points;
+                let total = contributor.points;
+                if total.is_zero() && contributor.unclaimed.is_zero() {
+                    *maybe_contributor = None;
+                }
+                Ok::<_, DispatchError>(total)
+            })?;
+            TotalPoints::<T>::mutate(|total| *total = total.saturating_sub(points));
+
+            Self::deposit_event(Event::PointsRevoked { who, points, total, reason });
+            Ok(())
+        }
+
+        /// Pay `amount` into the pool, to be shared when the epoch closes.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::deposit_revenue())]
+        pub fn deposit_revenue(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+            T::Currency::transfer(
+                &who,
+                &Self::account_id(),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            PendingRevenue::<T>::mutate(|pending| *pending = pending.saturating_add(amount));
+
+            Self::deposit_event(Event::RevenueDeposited { from: Some(who), amount });
+            Ok(())
+        }
+
+        /// Pay out everything the caller earned in closed epochs.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let amount = Contributors::<T>::try_mutate_exists(&who, |maybe_contributor| {
+                let contributor = maybe_contributor.as_mut().ok_or(Error::<T>::NothingToClaim)?;
+                Self::settle(contributor);
+                let amount = sp_std::mem::take(&mut contributor.unclaimed);
+                ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+                if contributor.points.is_zero() {
+                    *maybe_contributor = None;
+                }
+                Ok::<_, DispatchError>(amount)
+            })?;
+            T::Currency::transfer(
+                &Self::account_id(),
+                &who,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            Self::deposit_event(Event::RevenueClaimed { who, amount });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The pool account.
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Revenue `who` could claim right now.
+        pub fn claimable(who: &T::AccountId) -> BalanceOf<T> {
+            let mut contributor = Contributors::<T>::get(who);
+            Self::settle(&mut contributor);
+            contributor.unclaimed
+        }
+
+        /// Share out the pending revenue over the points held now and open the
+        /// next epoch.
+        pub(crate) fn close_epoch() {
+            let epoch = CurrentEpoch::<T>::get();
+            let total_points = TotalPoints::<T>::get();
+            let pending = PendingRevenue::<T>::get();
+
+            let revenue_per_point =
+                FixedU128::checked_from_rational(pending.saturated_into::<u128>(), total_points)
+                    .unwrap_or_default();
+            let revenue: BalanceOf<T> =
+                revenue_per_point.saturating_mul_int(total_points).saturated_into();
+
+            RevenuePerPoint::<T>::mutate(|acc| *acc = acc.saturating_add(revenue_per_point));
+            PendingRevenue::<T>::put(pending.saturating_sub(revenue));
+            CurrentEpoch::<T>::put(epoch.saturating_add(1));
+
+            Self::deposit_event(Event::EpochClosed {
+                epoch,
+                revenue,
+                total_points,
+                revenue_per_point,
+            });
+        }
+
+        /// Credit `contributor` with what its points earned since it was last
+        /// settled.
+        fn settle(contributor: &mut Contributor<BalanceOf<T>>) {
+            let acc = RevenuePerPoint::<T>::get();
+            let earned = acc
+                .saturating_sub(contributor.revenue_per_point_paid)
+                .saturating_mul_int(contributor.points);
+            contributor.unclaimed = contributor.unclaimed.saturating_add(earned.saturated_into());
+            contributor.revenue_per_point_paid = acc;
+        }
+    }
+
+    /// Revenue routed here by other pallets, e.g. a share of fees or service
+    /// payments, is shared like deposited revenue.
+    impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+        fn on_nonzero_unbalanced(revenue: NegativeImbalanceOf<T>) {
+            let amount = revenue.peek();
+            T::Currency::resolve_creating(&Self::account_id(), revenue);
+            PendingRevenue::<T>::mutate(|pending| *pending = pending.saturating_add(amount));
+
+            Self::deposit_event(Event::RevenueDeposited { from: None, amount });
+        }
+    }
+}
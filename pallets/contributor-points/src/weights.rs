@@ -0,0 +1,86 @@
+//! Weights for `pallet_contributor_points`, not benchmarked yet.
+//!
+//! Claims and epoch rollover are dominated by the pool transfer and the
+//! per-account points entries.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_contributor_points`.
+pub trait WeightInfo {
+    fn award_points(r: u32) -> Weight;
+    fn revoke_points(r: u32) -> Weight;
+    fn deposit_revenue() -> Weight;
+    fn claim() -> Weight;
+    fn close_epoch() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn award_points(r: u32) -> Weight {
+        Weight::from_parts(21_000_000, 3593)
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(r.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn revoke_points(r: u32) -> Weight {
+        Weight::from_parts(21_500_000, 3593)
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(r.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn deposit_revenue() -> Weight {
+        Weight::from_parts(48_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn claim() -> Weight {
+        Weight::from_parts(55_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn close_epoch() -> Weight {
+        Weight::from_parts(14_000_000, 1517)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn award_points(r: u32) -> Weight {
+        Weight::from_parts(21_000_000, 3593)
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(r.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn revoke_points(r: u32) -> Weight {
+        Weight::from_parts(21_500_000, 3593)
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(r.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn deposit_revenue() -> Weight {
+        Weight::from_parts(48_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn claim() -> Weight {
+        Weight::from_parts(55_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn close_epoch() -> Weight {
+        Weight::from_parts(14_000_000, 1517)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+}
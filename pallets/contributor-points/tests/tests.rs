@@ -0,0 +1,179 @@
+use crate::mock::*;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, OnUnbalanced},
+};
+use pallet_contributor_points::{Error, Event};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
+
+fn award(who: u64, points: u128) {
+    assert_ok!(ContributorPoints::award_points(RuntimeOrigin::root(), who, points, vec![]));
+}
+
+fn deposit(from: u64, amount: u64) {
+    assert_ok!(ContributorPoints::deposit_revenue(RuntimeOrigin::signed(from), amount));
+}
+
+#[test]
+fn epoch_revenue_is_shared_pro_rata() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ContributorPoints::award_points(
+            RuntimeOrigin::root(),
+            1,
+            300,
+            b"PR #42".to_vec(),
+        ));
+        System::assert_last_event(
+            Event::PointsAwarded {
+                who: 1,
+                points: 300,
+                total: 300,
+                reason: b"PR #42".to_vec().try_into().unwrap(),
+            }
+            .into(),
+        );
+        award(2, 100);
+        deposit(3, 400);
+        assert_eq!(ContributorPoints::claimable(&1), 0);
+
+        run_to_block(EPOCH);
+
+        System::assert_has_event(
+            Event::EpochClosed {
+                epoch: 0,
+                revenue: 400,
+                total_points: 400,
+                revenue_per_point: FixedU128::saturating_from_integer(1),
+            }
+            .into(),
+        );
+        assert_eq!(ContributorPoints::current_epoch(), 1);
+        assert_eq!(ContributorPoints::claimable(&1), 300);
+        assert_eq!(ContributorPoints::claimable(&2), 100);
+
+        assert_ok!(ContributorPoints::claim(RuntimeOrigin::signed(1)));
+        System::assert_last_event(Event::RevenueClaimed { who: 1, amount: 300 }.into());
+        assert_eq!(Balances::free_balance(1), ENDOWMENT + 300);
+        assert_noop!(
+            ContributorPoints::claim(RuntimeOrigin::signed(1)),
+            Error::<Test>::NothingToClaim
+        );
+    });
+}
+
+#[test]
+fn points_only_share_epochs_closed_after_the_award() {
+    new_test_ext().execute_with(|| {
+        award(1, 100);
+        deposit(3, 100);
+        run_to_block(EPOCH);
+
+        award(2, 100);
+        deposit(3, 100);
+        run_to_block(2 * EPOCH);
+
+        assert_eq!(ContributorPoints::claimable(&1), 150);
+        assert_eq!(ContributorPoints::claimable(&2), 50);
+    });
+}
+
+#[test]
+fn revenue_without_points_and_remainders_roll_over() {
+    new_test_ext().execute_with(|| {
+        deposit(3, 100);
+        run_to_block(EPOCH);
+        System::assert_has_event(
+            Event::EpochClosed {
+                epoch: 0,
+                revenue: 0,
+                total_points: 0,
+                revenue_per_point: FixedU128::default(),
+            }
+            .into(),
+        );
+        assert_eq!(ContributorPoints::pending_revenue(), 100);
+
+        // 100 over 3 points: 99 is shared, 1 waits for the next epoch.
+        award(1, 1);
+        award(2, 2);
+        run_to_block(2 * EPOCH);
+        assert_eq!(ContributorPoints::pending_revenue(), 1);
+        assert_eq!(ContributorPoints::claimable(&1), 33);
+        assert_eq!(ContributorPoints::claimable(&2), 66);
+    });
+}
+
+#[test]
+fn revoked_points_keep_revenue_already_earned() {
+    new_test_ext().execute_with(|| {
+        award(1, 100);
+        award(2, 100);
+        deposit(3, 200);
+        run_to_block(EPOCH);
+
+        assert_noop!(
+            ContributorPoints::revoke_points(RuntimeOrigin::root(), 1, 101, vec![]),
+            Error::<Test>::InsufficientPoints
+        );
+        assert_ok!(ContributorPoints::revoke_points(
+            RuntimeOrigin::root(),
+            1,
+            100,
+            b"duplicate".to_vec()
+        ));
+        System::assert_last_event(
+            Event::PointsRevoked {
+                who: 1,
+                points: 100,
+                total: 0,
+                reason: b"duplicate".to_vec().try_into().unwrap(),
+            }
+            .into(),
+        );
+        assert_eq!(ContributorPoints::total_points(), 100);
+
+        deposit(3, 200);
+        run_to_block(2 * EPOCH);
+        assert_eq!(ContributorPoints::claimable(&1), 100);
+        assert_eq!(ContributorPoints::claimable(&2), 300);
+
+        assert_ok!(ContributorPoints::claim(RuntimeOrigin::signed(1)));
+        assert!(!pallet_contributor_points::Contributors::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn only_award_origin_awards_points() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ContributorPoints::award_points(RuntimeOrigin::signed(1), 1, 100, vec![]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ContributorPoints::revoke_points(RuntimeOrigin::signed(1), 1, 100, vec![]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ContributorPoints::award_points(RuntimeOrigin::root(), 1, 0, vec![]),
+            Error::<Test>::ZeroAmount
+        );
+        assert_noop!(
+            ContributorPoints::award_points(RuntimeOrigin::root(), 1, 100, vec![0; 17]),
+            Error::<Test>::ReasonTooLong
+        );
+    });
+}
+
+#[test]
+fn imbalances_routed_to_the_pool_are_shared() {
+    new_test_ext().execute_with(|| {
+        award(1, 10);
+
+        ContributorPoints::on_unbalanced(Balances::issue(50));
+
+        System::assert_last_event(Event::RevenueDeposited { from: None, amount: 50 }.into());
+        assert_eq!(Balances::free_balance(ContributorPoints::account_id()), 50);
+        run_to_block(EPOCH);
+        assert_eq!(ContributorPoints::claimable(&1), 50);
+    });
+}
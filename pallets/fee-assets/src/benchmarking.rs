@@ -0,0 +1,25 @@
+//! Benchmarks for the fee asset approvals.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+benchmarks! {
+    approve {
+        let origin = T::ApproveOrigin::try_successful_origin().map_err(|_| "no approve origin")?;
+        let asset = T::BenchmarkHelper::create_asset();
+        let rate = FixedU128::from_rational(5, 2);
+    }: _<T::RuntimeOrigin>(origin, asset, rate)
+    verify {
+        assert_eq!(FeeRates::<T>::get(asset), Some(rate));
+    }
+
+    revoke {
+        let origin = T::ApproveOrigin::try_successful_origin().map_err(|_| "no approve origin")?;
+        let asset = T::BenchmarkHelper::create_asset();
+        FeeRates::<T>::insert(asset, FixedU128::from_rational(5, 2));
+    }: _<T::RuntimeOrigin>(origin, asset)
+    verify {
+        assert!(!FeeRates::<T>::contains_key(asset));
+    }
+}
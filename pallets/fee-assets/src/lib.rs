@@ -0,0 +1,147 @@
+//! Assets approved for paying transaction fees.
+//!
+//! `ApproveOrigin` approves an asset of `Assets` with a conversion rate: the
+//! asset base units charged per base unit of native fee, so the rate covers
+//! the difference in decimals too. A rate of 2.5 charges 250 asset units for
+//! a fee of 100 native units. Revoking the approval stops fee payment in the
+//! asset at once.
+//!
+//! The pallet only keeps the rates. [`Pallet`] implements
+//! [`ConversionToAssetBalance`] for the runtime's asset fee adapter, which
+//! withdraws the converted fee and refunds the unused part the same way.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Asset setup for the benchmarks, which cannot create assets generically.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+    /// A new asset that exists in `Assets`.
+    fn create_asset() -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::tokens::{fungibles, ConversionToAssetBalance},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{UniqueSaturatedInto, Zero},
+        FixedPointNumber, FixedU128, SaturatedConversion,
+    };
+
+    pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::AssetId;
+    pub type AssetBalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The assets fees may be paid in.
+        type Assets: fungibles::Inspect<Self::AccountId>;
+
+        /// Approves assets for fees and sets their rates.
+        type ApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Asset base units charged per base unit of native fee, for each asset
+    /// approved for fees.
+    #[pallet::storage]
+    #[pallet::getter(fn fee_rate)]
+    pub type FeeRates<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, FixedU128>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fees may be paid in `asset`, at `rate` asset units per native unit.
+        Approved { asset: AssetIdOf<T>, rate: FixedU128 },
+        /// Fees may no longer be paid in `asset`.
+        Revoked { asset: AssetIdOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// `Assets` has no such asset.
+        UnknownAsset,
+        /// A rate of zero would make fees free.
+        ZeroRate,
+        /// The asset is not approved for fees.
+        NotApproved,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Approve `asset` for fees at `rate`, or change the rate of an
+        /// approved one.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::approve())]
+        pub fn approve(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            rate: FixedU128,
+        ) -> DispatchResult {
+            T::ApproveOrigin::ensure_origin(origin)?;
+            ensure!(
+                <T::Assets as fungibles::Inspect<_>>::asset_exists(asset),
+                Error::<T>::UnknownAsset
+            );
+            ensure!(!rate.is_zero(), Error::<T>::ZeroRate);
+
+            FeeRates::<T>::insert(asset, rate);
+            Self::deposit_event(Event::Approved { asset, rate });
+            Ok(())
+        }
+
+        /// Stop accepting fees in `asset`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke())]
+        pub fn revoke(origin: OriginFor<T>, asset: AssetIdOf<T>) -> DispatchResult {
+            T::ApproveOrigin::ensure_origin(origin)?;
+            ensure!(FeeRates::<T>::contains_key(asset), Error::<T>::NotApproved);
+
+            FeeRates::<T>::remove(asset);
+            Self::deposit_event(Event::Revoked { asset });
+            Ok(())
+        }
+    }
+
+    impl<T: Config, NativeBalance>
+        ConversionToAssetBalance<NativeBalance, AssetIdOf<T>, AssetBalanceOf<T>> for Pallet<T>
+    where
+        NativeBalance: UniqueSaturatedInto<u128>,
+    {
+        type Error = Error<T>;
+
+        /// `balance` in `asset` at its fee rate, rounded down.
+        fn to_asset_balance(
+            balance: NativeBalance,
+            asset: AssetIdOf<T>,
+        ) -> Result<AssetBalanceOf<T>, Error<T>> {
+            let rate = FeeRates::<T>::get(asset).ok_or(Error::<T>::NotApproved)?;
+            let balance: u128 = balance.unique_saturated_into();
+            Ok(rate.saturating_mul_int(balance).saturated_into())
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Weights for `pallet_fee_assets`, not benchmarked yet.
+//!
+//! Approving or revoking an asset is one map write behind an existence check.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_fee_assets`.
+pub trait WeightInfo {
+    fn approve() -> Weight;
+    fn revoke() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn approve() -> Weight {
+        Weight::from_parts(14_000_000, 3675)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn revoke() -> Weight {
+        Weight::from_parts(12_000_000, 3529)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn approve() -> Weight {
+        Weight::from_parts(14_000_000, 3675)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn revoke() -> Weight {
+        Weight::from_parts(12_000_000, 3529)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::tokens::ConversionToAssetBalance};
+use pallet_fee_assets::{Error, Event};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
+
+/// `fee` in `POINTS`, or `None` if fees cannot be paid in it.
+fn to_points(fee: u64) -> Option<u64> {
+    <FeeAssets as ConversionToAssetBalance<u64, u32, u64>>::to_asset_balance(fee, POINTS).ok()
+}
+
+#[test]
+fn approved_assets_convert_fees_at_their_rate() {
+    new_test_ext().execute_with(|| {
+        let rate = FixedU128::from_rational(5, 2);
+        assert_noop!(
+            FeeAssets::approve(RuntimeOrigin::signed(1), POINTS, rate),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            FeeAssets::approve(RuntimeOrigin::root(), POINTS + 1, rate),
+            Error::<Test>::UnknownAsset
+        );
+        assert_noop!(
+            FeeAssets::approve(RuntimeOrigin::root(), POINTS, FixedU128::from_u32(0)),
+            Error::<Test>::ZeroRate
+        );
+        assert_eq!(to_points(100), None);
+
+        assert_ok!(FeeAssets::approve(RuntimeOrigin::root(), POINTS, rate));
+        System::assert_last_event(Event::Approved { asset: POINTS, rate }.into());
+        assert_eq!(FeeAssets::fee_rate(POINTS), Some(rate));
+        assert_eq!(to_points(100), Some(250));
+        // Rounded down: the adapter charges at least one unit for a fee.
+        assert_eq!(to_points(1), Some(2));
+
+        let rate = FixedU128::from_rational(1, 3);
+        assert_ok!(FeeAssets::approve(RuntimeOrigin::root(), POINTS, rate));
+        assert_eq!(to_points(10), Some(3));
+    });
+}
+
+#[test]
+fn revoked_assets_no_longer_convert() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(FeeAssets::revoke(RuntimeOrigin::root(), POINTS), Error::<Test>::NotApproved);
+        assert_ok!(FeeAssets::approve(RuntimeOrigin::root(), POINTS, FixedU128::from_u32(2)));
+        assert_noop!(FeeAssets::revoke(RuntimeOrigin::signed(1), POINTS), DispatchError::BadOrigin);
+
+        assert_ok!(FeeAssets::revoke(RuntimeOrigin::root(), POINTS));
+        System::assert_last_event(Event::Revoked { asset: POINTS }.into());
+        assert_eq!(FeeAssets::fee_rate(POINTS), None);
+        assert_eq!(to_points(100), None);
+    });
+}
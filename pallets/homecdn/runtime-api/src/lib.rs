@@ -0,0 +1,39 @@
+//! Runtime API the off-chain HomeCDN router uses to pick providers from
+//! `pallet-homecdn`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// A provider's listing as the router sees it.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ProviderListing<AccountId, Balance> {
+    pub provider: AccountId,
+    /// Declared storage capacity, in GiB.
+    pub capacity_gib: u64,
+    /// Declared upstream bandwidth, in Mbit/s.
+    pub bandwidth_mbps: u32,
+    pub region: Vec<u8>,
+    /// Where the router sends requests, e.g. `https://node.example:8443`.
+    pub endpoint: Vec<u8>,
+    pub bond: Balance,
+    /// Whether traffic may be routed to the provider: it has not retired and
+    /// its bond has not been slashed below the minimum.
+    pub active: bool,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait HomeCdnApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Active providers, only those in `region` if given.
+        fn active_providers(region: Option<Vec<u8>>) -> Vec<ProviderListing<AccountId, Balance>>;
+
+        /// `who`'s listing, active or not.
+        fn provider(who: AccountId) -> Option<ProviderListing<AccountId, Balance>>;
+    }
+}
@@ -0,0 +1,89 @@
+//! Benchmarks for the HomeCDN provider registry.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Saturating};
+use sp_std::{prelude::*, vec};
+
+const SEED: u32 = 0;
+
+fn region<T: Config>() -> Vec<u8> {
+    vec![b'r'; T::MaxRegionLength::get() as usize]
+}
+
+fn endpoint<T: Config>() -> Vec<u8> {
+    vec![b'e'; T::MaxEndpointLength::get() as usize]
+}
+
+fn register<T: Config>(who: &T::AccountId) -> Result<(), &'static str> {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+    Pallet::<T>::register(
+        RawOrigin::Signed(who.clone()).into(),
+        1_024,
+        1_000,
+        region::<T>(),
+        endpoint::<T>(),
+        T::MinBond::get(),
+    )?;
+    Ok(())
+}
+
+benchmarks! {
+    register {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+        let (region, endpoint) = (region::<T>(), endpoint::<T>());
+    }: _(RawOrigin::Signed(caller.clone()), 1_024, 1_000, region, endpoint, T::MinBond::get())
+    verify {
+        assert!(Providers::<T>::contains_key(&caller));
+    }
+
+    update_listing {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller.clone()), 2_048, 2_000, region::<T>(), endpoint::<T>())
+    verify {
+        assert_eq!(Providers::<T>::get(&caller).unwrap().listing.capacity_gib, 2_048);
+    }
+
+    bond_extra {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller)?;
+        let amount = T::MinBond::get();
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_eq!(Providers::<T>::get(&caller).unwrap().bond, amount.saturating_add(amount));
+    }
+
+    retire {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!Pallet::<T>::is_active(&caller));
+    }
+
+    withdraw_bond {
+        let caller: T::AccountId = whitelisted_caller();
+        register::<T>(&caller)?;
+        Pallet::<T>::retire(RawOrigin::Signed(caller.clone()).into())?;
+        let unbonds_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::RetirementPeriod::get());
+        frame_system::Pallet::<T>::set_block_number(unbonds_at);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!Providers::<T>::contains_key(&caller));
+    }
+
+    slash {
+        let origin = T::SlashOrigin::try_successful_origin().map_err(|_| "no slash origin")?;
+        let provider: T::AccountId = account("provider", 0, SEED);
+        register::<T>(&provider)?;
+        let amount = T::MinBond::get();
+    }: _<T::RuntimeOrigin>(origin, provider.clone(), amount)
+    verify {
+        assert_eq!(Providers::<T>::get(&provider).unwrap().bond, 0u32.into());
+    }
+}
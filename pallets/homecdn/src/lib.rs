@@ -0,0 +1,390 @@
+//! HomeCDN provider registry.
+//!
+//! Home nodes list themselves as CDN and storage providers with their declared
+//! capacity, bandwidth, region and endpoint, and reserve a BBTC bond of at
+//! least `MinBond`. The off-chain CDN router reads active listings through
+//! `pallet-homecdn-runtime-api`.
+//!
+//! A provider that provably fails to serve loses part of its bond, either to
+//! `SlashOrigin` or to another pallet through [`ProviderRegistry`]. Slashed
+//! funds go to `OnSlash`. A provider whose bond falls below `MinBond` stays
+//! listed but is not routed until it tops the bond up. Retiring takes the
+//! listing out of routing at once; the bond stays slashable for
+//! `RetirementPeriod` before it can be withdrawn.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// What other pallets, e.g. storage challenges, use of the registry.
+pub trait ProviderRegistry<AccountId, Balance> {
+    /// Whether traffic may be routed to `who`.
+    fn is_active(who: &AccountId) -> bool;
+
+    /// Slash up to `amount` of `who`'s bond, returning what was slashed.
+    fn slash(who: &AccountId, amount: Balance) -> Balance;
+
+    /// Make `who` an active provider, for benchmarking pallets that need one.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_for_benchmarks(who: &AccountId);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, Imbalance, OnUnbalanced, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
+    use sp_std::prelude::*;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
+    /// What a provider declares it offers.
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Listing<Region, Endpoint> {
+        /// Storage capacity, in GiB.
+        pub capacity_gib: u64,
+        /// Upstream bandwidth, in Mbit/s.
+        pub bandwidth_mbps: u32,
+        /// Region code the router matches clients against, e.g. `eu-central`.
+        pub region: Region,
+        /// Where the router sends requests.
+        pub endpoint: Endpoint,
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ProviderStatus<BlockNumber> {
+        /// Listed and, with enough bond, routed.
+        Active,
+        /// Retired; the bond can be withdrawn from `unbonds_at`.
+        Retiring { unbonds_at: BlockNumber },
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Provider<Balance, BlockNumber, Region, Endpoint> {
+        pub listing: Listing<Region, Endpoint>,
+        /// Reserved bond, after slashes.
+        pub bond: Balance,
+        pub status: ProviderStatus<BlockNumber>,
+        pub registered_at: BlockNumber,
+    }
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency the bond is reserved in.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Receives slashed bonds.
+        type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// May slash providers for failing service.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Smallest bond a provider is routed with.
+        #[pallet::constant]
+        type MinBond: Get<BalanceOf<Self>>;
+
+        /// Blocks a retired provider's bond stays slashable.
+        #[pallet::constant]
+        type RetirementPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Most providers listed at once, retiring ones included.
+        #[pallet::constant]
+        type MaxProviders: Get<u32>;
+
+        /// Longest region code, in bytes.
+        #[pallet::constant]
+        type MaxRegionLength: Get<u32>;
+
+        /// Longest endpoint, in bytes.
+        #[pallet::constant]
+        type MaxEndpointLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    pub type RegionOf<T> = BoundedVec<u8, <T as Config>::MaxRegionLength>;
+    pub type EndpointOf<T> = BoundedVec<u8, <T as Config>::MaxEndpointLength>;
+    pub type ListingOf<T> = Listing<RegionOf<T>, EndpointOf<T>>;
+    pub type ProviderOf<T> = Provider<BalanceOf<T>, BlockNumberFor<T>, RegionOf<T>, EndpointOf<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn provider)]
+    pub type Providers<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, ProviderOf<T>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `who` listed as a provider with `bond` reserved.
+        Registered { who: T::AccountId, listing: ListingOf<T>, bond: BalanceOf<T> },
+        /// `who` changed its listing.
+        ListingUpdated { who: T::AccountId, listing: ListingOf<T> },
+        /// `who` reserved `amount` more, for a bond of `bond`.
+        BondIncreased { who: T::AccountId, amount: BalanceOf<T>, bond: BalanceOf<T> },
+        /// `who` retired; its bond can be withdrawn from `unbonds_at`.
+        Retired { who: T::AccountId, unbonds_at: BlockNumberFor<T> },
+        /// `who` withdrew its remaining `bond` and was removed.
+        Withdrawn { who: T::AccountId, bond: BalanceOf<T> },
+        /// `amount` of `who`'s bond was slashed, `bond` is left.
+        Slashed { who: T::AccountId, amount: BalanceOf<T>, bond: BalanceOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account is already listed.
+        AlreadyRegistered,
+        /// The account is not listed.
+        NotProvider,
+        /// `MaxProviders` are listed already.
+        TooManyProviders,
+        /// The bond is below `MinBond`.
+        BondTooLow,
+        /// Capacity, bandwidth or region is empty.
+        InvalidListing,
+        /// The endpoint is empty, so the provider could not be routed to.
+        EmptyEndpoint,
+        /// The region is longer than `MaxRegionLength`.
+        RegionTooLong,
+        /// The endpoint is longer than `MaxEndpointLength`.
+        EndpointTooLong,
+        /// The provider has retired.
+        Retired,
+        /// The provider has not retired, or its `RetirementPeriod` is not over.
+        StillBonded,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// List the caller as a provider, reserving `bond`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register())]
+        pub fn register(
+            origin: OriginFor<T>,
+            capacity_gib: u64,
+            bandwidth_mbps: u32,
+            region: Vec<u8>,
+            endpoint: Vec<u8>,
+            #[pallet::compact] bond: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!Providers::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
+            ensure!(Providers::<T>::count() < T::MaxProviders::get(), Error::<T>::TooManyProviders);
+            ensure!(bond >= T::MinBond::get(), Error::<T>::BondTooLow);
+            let listing = Self::listing(capacity_gib, bandwidth_mbps, region, endpoint)?;
+
+            T::Currency::reserve(&who, bond)?;
+            Providers::<T>::insert(
+                &who,
+                Provider {
+                    listing: listing.clone(),
+                    bond,
+                    status: ProviderStatus::Active,
+                    registered_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::Registered { who, listing, bond });
+            Ok(())
+        }
+
+        /// Replace the caller's listing.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::update_listing())]
+        pub fn update_listing(
+            origin: OriginFor<T>,
+            capacity_gib: u64,
+            bandwidth_mbps: u32,
+            region: Vec<u8>,
+            endpoint: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let listing = Self::listing(capacity_gib, bandwidth_mbps, region, endpoint)?;
+
+            Providers::<T>::try_mutate(&who, |maybe_provider| {
+                let provider = maybe_provider.as_mut().ok_or(Error::<T>::NotProvider)?;
+                ensure!(provider.status == ProviderStatus::Active, Error::<T>::Retired);
+                provider.listing = listing.clone();
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::ListingUpdated { who, listing });
+            Ok(())
+        }
+
+        /// Reserve `amount` more, e.g. to get routed again after a slash.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::bond_extra())]
+        pub fn bond_extra(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bond = Providers::<T>::try_mutate(&who, |maybe_provider| {
+                let provider = maybe_provider.as_mut().ok_or(Error::<T>::NotProvider)?;
+                ensure!(provider.status == ProviderStatus::Active, Error::<T>::Retired);
+                T::Currency::reserve(&who, amount)?;
+                provider.bond = provider.bond.saturating_add(amount);
+                Ok::<_, DispatchError>(provider.bond)
+            })?;
+
+            Self::deposit_event(Event::BondIncreased { who, amount, bond });
+            Ok(())
+        }
+
+        /// Stop being routed. The bond can be withdrawn after
+        /// `RetirementPeriod`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::retire())]
+        pub fn retire(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let unbonds_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::RetirementPeriod::get());
+
+            Providers::<T>::try_mutate(&who, |maybe_provider| {
+                let provider = maybe_provider.as_mut().ok_or(Error::<T>::NotProvider)?;
+                ensure!(provider.status == ProviderStatus::Active, Error::<T>::Retired);
+                provider.status = ProviderStatus::Retiring { unbonds_at };
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::Retired { who, unbonds_at });
+            Ok(())
+        }
+
+        /// Release what is left of the bond of a retired provider and remove
+        /// its listing.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::withdraw_bond())]
+        pub fn withdraw_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let provider = Providers::<T>::get(&who).ok_or(Error::<T>::NotProvider)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let unbonded = match provider.status {
+                ProviderStatus::Retiring { unbonds_at } => unbonds_at <= now,
+                ProviderStatus::Active => false,
+            };
+            ensure!(unbonded, Error::<T>::StillBonded);
+
+            T::Currency::unreserve(&who, provider.bond);
+            Providers::<T>::remove(&who);
+
+            Self::deposit_event(Event::Withdrawn { who, bond: provider.bond });
+            Ok(())
+        }
+
+        /// Slash up to `amount` of `who`'s bond for failing service.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::slash())]
+        pub fn slash(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::SlashOrigin::ensure_origin(origin)?;
+            ensure!(Providers::<T>::contains_key(&who), Error::<T>::NotProvider);
+
+            Self::do_slash(&who, amount);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether traffic may be routed to `provider`.
+        pub fn is_routable(provider: &ProviderOf<T>) -> bool {
+            provider.status == ProviderStatus::Active && provider.bond >= T::MinBond::get()
+        }
+
+        /// Routable providers, only those in `region` if given.
+        pub fn active_providers(region: Option<Vec<u8>>) -> Vec<(T::AccountId, ProviderOf<T>)> {
+            Providers::<T>::iter()
+                .filter(|(_, provider)| Self::is_routable(provider))
+                .filter(|(_, provider)| {
+                    region.as_ref().map_or(true, |region| provider.listing.region[..] == region[..])
+                })
+                .collect()
+        }
+
+        fn listing(
+            capacity_gib: u64,
+            bandwidth_mbps: u32,
+            region: Vec<u8>,
+            endpoint: Vec<u8>,
+        ) -> Result<ListingOf<T>, DispatchError> {
+            ensure!(
+                capacity_gib > 0 && bandwidth_mbps > 0 && !region.is_empty(),
+                Error::<T>::InvalidListing
+            );
+            ensure!(!endpoint.is_empty(), Error::<T>::EmptyEndpoint);
+            let region: RegionOf<T> = region.try_into().map_err(|_| Error::<T>::RegionTooLong)?;
+            let endpoint: EndpointOf<T> =
+                endpoint.try_into().map_err(|_| Error::<T>::EndpointTooLong)?;
+            Ok(Listing { capacity_gib, bandwidth_mbps, region, endpoint })
+        }
+
+        /// Slash up to `amount` of a listed provider's bond into `OnSlash`.
+        fn do_slash(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            Providers::<T>::mutate(who, |maybe_provider| {
+                let provider = match maybe_provider.as_mut() {
+                    Some(provider) => provider,
+                    None => return Zero::zero(),
+                };
+                let (imbalance, _) = T::Currency::slash_reserved(who, amount.min(provider.bond));
+                let slashed = imbalance.peek();
+                provider.bond = provider.bond.saturating_sub(slashed);
+                T::OnSlash::on_unbalanced(imbalance);
+
+                Self::deposit_event(Event::Slashed {
+                    who: who.clone(),
+                    amount: slashed,
+                    bond: provider.bond,
+                });
+                slashed
+            })
+        }
+    }
+
+    impl<T: Config> ProviderRegistry<T::AccountId, BalanceOf<T>> for Pallet<T> {
+        fn is_active(who: &T::AccountId) -> bool {
+            Providers::<T>::get(who).map_or(false, |provider| Self::is_routable(&provider))
+        }
+
+        fn slash(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+            Self::do_slash(who, amount)
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn register_for_benchmarks(who: &T::AccountId) {
+            let bond = T::MinBond::get();
+            T::Currency::make_free_balance_be(who, bond.saturating_add(bond));
+            let registered = Self::register(
+                frame_system::RawOrigin::Signed(who.clone()).into(),
+                1,
+                1,
+                b"bench".to_vec(),
+                b"https://bench".to_vec(),
+                bond,
+            );
+            assert!(registered.is_ok(), "benchmark provider registers");
+        }
+    }
+}
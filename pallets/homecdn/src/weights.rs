@@ -0,0 +1,93 @@
+//! Weights for `pallet_homecdn`, not benchmarked yet.
+//!
+//! Costs follow the provider and region entries and the bond reserve each
+//! call touches.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_homecdn`.
+pub trait WeightInfo {
+    fn register() -> Weight;
+    fn update_listing() -> Weight;
+    fn bond_extra() -> Weight;
+    fn retire() -> Weight;
+    fn withdraw_bond() -> Weight;
+    fn slash() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn register() -> Weight {
+        Weight::from_parts(38_000_000, 4617)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn update_listing() -> Weight {
+        Weight::from_parts(16_000_000, 4617)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn bond_extra() -> Weight {
+        Weight::from_parts(30_000_000, 4617)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn retire() -> Weight {
+        Weight::from_parts(15_000_000, 4617)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn withdraw_bond() -> Weight {
+        Weight::from_parts(34_000_000, 4617)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn slash() -> Weight {
+        Weight::from_parts(42_000_000, 7220)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn register() -> Weight {
+        Weight::from_parts(38_000_000, 4617)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn update_listing() -> Weight {
+        Weight::from_parts(16_000_000, 4617)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn bond_extra() -> Weight {
+        Weight::from_parts(30_000_000, 4617)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn retire() -> Weight {
+        Weight::from_parts(15_000_000, 4617)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn withdraw_bond() -> Weight {
+        Weight::from_parts(34_000_000, 4617)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn slash() -> Weight {
+        Weight::from_parts(42_000_000, 7220)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+}
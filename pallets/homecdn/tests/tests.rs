@@ -0,0 +1,185 @@
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_homecdn::{Error, Event, Listing, ProviderRegistry, ProviderStatus};
+use sp_runtime::DispatchError;
+
+fn register(who: u64, region: &[u8], bond: u64) {
+    assert_ok!(HomeCdn::register(
+        RuntimeOrigin::signed(who),
+        512,
+        100,
+        region.to_vec(),
+        endpoint(),
+        bond,
+    ));
+}
+
+fn endpoint() -> Vec<u8> {
+    b"https://node.example".to_vec()
+}
+
+fn routed(region: Option<&[u8]>) -> Vec<u64> {
+    let mut providers: Vec<u64> = HomeCdn::active_providers(region.map(|r| r.to_vec()))
+        .into_iter()
+        .map(|(who, _)| who)
+        .collect();
+    providers.sort();
+    providers
+}
+
+#[test]
+fn register_reserves_the_bond_and_lists_the_provider() {
+    new_test_ext().execute_with(|| {
+        register(1, b"eu", MIN_BOND);
+
+        System::assert_last_event(
+            Event::Registered {
+                who: 1,
+                listing: Listing {
+                    capacity_gib: 512,
+                    bandwidth_mbps: 100,
+                    region: b"eu".to_vec().try_into().unwrap(),
+                    endpoint: b"https://node.example".to_vec().try_into().unwrap(),
+                },
+                bond: MIN_BOND,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(1), MIN_BOND);
+        assert!(HomeCdn::is_active(&1));
+        assert_noop!(
+            HomeCdn::register(RuntimeOrigin::signed(1), 1, 1, b"eu".to_vec(), endpoint(), MIN_BOND),
+            Error::<Test>::AlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn register_checks_bond_and_listing() {
+    new_test_ext().execute_with(|| {
+        let signed = RuntimeOrigin::signed(1);
+        assert_noop!(
+            HomeCdn::register(signed.clone(), 1, 1, b"eu".to_vec(), endpoint(), MIN_BOND - 1),
+            Error::<Test>::BondTooLow
+        );
+        assert_noop!(
+            HomeCdn::register(signed.clone(), 0, 1, b"eu".to_vec(), endpoint(), MIN_BOND),
+            Error::<Test>::InvalidListing
+        );
+        assert_noop!(
+            HomeCdn::register(signed.clone(), 1, 1, vec![], endpoint(), MIN_BOND),
+            Error::<Test>::InvalidListing
+        );
+        assert_noop!(
+            HomeCdn::register(signed.clone(), 1, 1, b"eu".to_vec(), vec![], MIN_BOND),
+            Error::<Test>::EmptyEndpoint
+        );
+        assert_noop!(
+            HomeCdn::register(signed.clone(), 1, 1, vec![b'r'; 9], endpoint(), MIN_BOND),
+            Error::<Test>::RegionTooLong
+        );
+        assert_noop!(
+            HomeCdn::register(signed, 1, 1, b"eu".to_vec(), vec![b'e'; 33], MIN_BOND),
+            Error::<Test>::EndpointTooLong
+        );
+
+        register(2, b"eu", MIN_BOND);
+        register(3, b"eu", MIN_BOND);
+        register(4, b"eu", MIN_BOND);
+        Balances::make_free_balance_be(&5, ENDOWMENT);
+        assert_noop!(
+            HomeCdn::register(RuntimeOrigin::signed(5), 1, 1, b"eu".to_vec(), endpoint(), MIN_BOND),
+            Error::<Test>::TooManyProviders
+        );
+    });
+}
+
+#[test]
+fn router_sees_active_providers_by_region() {
+    new_test_ext().execute_with(|| {
+        register(1, b"eu", MIN_BOND);
+        register(2, b"us", MIN_BOND);
+        register(3, b"eu", MIN_BOND);
+
+        assert_eq!(routed(None), vec![1, 2, 3]);
+        assert_eq!(routed(Some(b"eu")), vec![1, 3]);
+
+        assert_noop!(
+            HomeCdn::update_listing(RuntimeOrigin::signed(3), 1, 1, b"us".to_vec(), vec![]),
+            Error::<Test>::EmptyEndpoint
+        );
+        assert_ok!(HomeCdn::update_listing(
+            RuntimeOrigin::signed(3),
+            1_024,
+            200,
+            b"us".to_vec(),
+            b"https://moved.example".to_vec(),
+        ));
+        assert_eq!(routed(Some(b"us")), vec![2, 3]);
+
+        assert_ok!(HomeCdn::retire(RuntimeOrigin::signed(2)));
+        assert_eq!(routed(Some(b"us")), vec![3]);
+        assert_noop!(
+            HomeCdn::update_listing(RuntimeOrigin::signed(2), 1, 1, b"us".to_vec(), endpoint()),
+            Error::<Test>::Retired
+        );
+    });
+}
+
+#[test]
+fn slashed_provider_is_not_routed_until_topped_up() {
+    new_test_ext().execute_with(|| {
+        register(1, b"eu", 150);
+        let issuance = Balances::total_issuance();
+
+        assert_noop!(HomeCdn::slash(RuntimeOrigin::signed(2), 1, 100), DispatchError::BadOrigin);
+        assert_ok!(HomeCdn::slash(RuntimeOrigin::root(), 1, 100));
+
+        System::assert_last_event(Event::Slashed { who: 1, amount: 100, bond: 50 }.into());
+        assert_eq!(Balances::reserved_balance(1), 50);
+        assert_eq!(Balances::total_issuance(), issuance - 100);
+        assert!(!HomeCdn::is_active(&1));
+        assert_eq!(routed(None), Vec::<u64>::new());
+
+        assert_ok!(HomeCdn::bond_extra(RuntimeOrigin::signed(1), 50));
+        System::assert_last_event(Event::BondIncreased { who: 1, amount: 50, bond: 100 }.into());
+        assert!(HomeCdn::is_active(&1));
+    });
+}
+
+#[test]
+fn slash_never_takes_more_than_the_bond() {
+    new_test_ext().execute_with(|| {
+        register(1, b"eu", MIN_BOND);
+
+        assert_eq!(<HomeCdn as ProviderRegistry<_, _>>::slash(&1, 500), MIN_BOND);
+        assert_eq!(HomeCdn::provider(1).unwrap().bond, 0);
+        assert_eq!(Balances::free_balance(1), ENDOWMENT - MIN_BOND);
+        assert_eq!(<HomeCdn as ProviderRegistry<_, _>>::slash(&2, 500), 0);
+    });
+}
+
+#[test]
+fn retired_bond_stays_slashable_until_withdrawn() {
+    new_test_ext().execute_with(|| {
+        register(1, b"eu", MIN_BOND);
+        assert_noop!(HomeCdn::withdraw_bond(RuntimeOrigin::signed(1)), Error::<Test>::StillBonded);
+
+        assert_ok!(HomeCdn::retire(RuntimeOrigin::signed(1)));
+        let unbonds_at = 1 + RETIREMENT;
+        System::assert_last_event(Event::Retired { who: 1, unbonds_at }.into());
+        assert_eq!(HomeCdn::provider(1).unwrap().status, ProviderStatus::Retiring { unbonds_at });
+        assert_noop!(HomeCdn::retire(RuntimeOrigin::signed(1)), Error::<Test>::Retired);
+
+        System::set_block_number(unbonds_at - 1);
+        assert_noop!(HomeCdn::withdraw_bond(RuntimeOrigin::signed(1)), Error::<Test>::StillBonded);
+        assert_ok!(HomeCdn::slash(RuntimeOrigin::root(), 1, 40));
+
+        System::set_block_number(unbonds_at);
+        assert_ok!(HomeCdn::withdraw_bond(RuntimeOrigin::signed(1)));
+        System::assert_last_event(Event::Withdrawn { who: 1, bond: MIN_BOND - 40 }.into());
+        assert_eq!(Balances::free_balance(1), ENDOWMENT - 40);
+        assert_eq!(HomeCdn::provider(1), None);
+        assert_eq!(pallet_homecdn::Providers::<Test>::count(), 0);
+    });
+}
@@ -0,0 +1,30 @@
+//! Benchmarks for the metadata pallet.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+benchmarks! {
+    store_metadata {
+        let k in 1 .. T::MaxKeyLength::get();
+        let v in 1 .. T::MaxValueLength::get();
+        let key = vec![1u8; k as usize];
+        let value = vec![2u8; v as usize];
+    }: _(RawOrigin::Root, key.clone(), value)
+    verify {
+        assert!(Metadata::<T>::contains_key(KeyOf::<T>::truncate_from(key)));
+    }
+
+    remove_metadata {
+        let k in 1 .. T::MaxKeyLength::get();
+        let key = vec![1u8; k as usize];
+        // Largest value, so the proof size covers the worst case.
+        let value = vec![2u8; T::MaxValueLength::get() as usize];
+        Pallet::<T>::store_metadata(RawOrigin::Root.into(), key.clone(), value)?;
+    }: _(RawOrigin::Root, key.clone())
+    verify {
+        assert!(!Metadata::<T>::contains_key(KeyOf::<T>::truncate_from(key)));
+    }
+}
@@ -1,18 +1,98 @@
+//! Root-managed key/value metadata stored on chain.
+//!
+//! Holds small chain-wide settings (service endpoints, document hashes, ...)
+//! that off-chain tooling reads by key.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{pallet_prelude::*, traits::Get};
+    use super::*;
+    use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
+    use sp_std::prelude::*;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {}
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Longest accepted key, in bytes.
+        #[pallet::constant]
+        type MaxKeyLength: Get<u32>;
+
+        /// Longest accepted value, in bytes.
+        #[pallet::constant]
+        type MaxValueLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    pub type KeyOf<T> = BoundedVec<u8, <T as Config>::MaxKeyLength>;
+    pub type ValueOf<T> = BoundedVec<u8, <T as Config>::MaxValueLength>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn metadata)]
+    pub type Metadata<T: Config> = StorageMap<_, Blake2_128Concat, KeyOf<T>, ValueOf<T>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A value was stored under `key`, replacing any previous one.
+        MetadataStored { key: KeyOf<T> },
+        /// The value under `key` was removed.
+        MetadataRemoved { key: KeyOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Only root may change metadata.
+        NotAuthorized,
+        /// The key is longer than `MaxKeyLength`.
+        KeyTooLong,
+        /// The value is longer than `MaxValueLength`.
+        ValueTooLong,
+        /// Nothing is stored under the key.
+        NotFound,
+    }
+
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Store `value` under `key`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::store_metadata(key.len() as u32, value.len() as u32))]
+        pub fn store_metadata(origin: OriginFor<T>, key: Vec<u8>, value: Vec<u8>) -> DispatchResult {
+            ensure_root(origin).map_err(|_| Error::<T>::NotAuthorized)?;
+            let key: KeyOf<T> = key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
+            let value: ValueOf<T> = value.try_into().map_err(|_| Error::<T>::ValueTooLong)?;
+
+            Metadata::<T>::insert(&key, value);
+
+            Self::deposit_event(Event::MetadataStored { key });
+            Ok(())
+        }
+
+        /// Remove the value stored under `key`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_metadata(key.len() as u32))]
+        pub fn remove_metadata(origin: OriginFor<T>, key: Vec<u8>) -> DispatchResult {
+            ensure_root(origin).map_err(|_| Error::<T>::NotAuthorized)?;
+            let key: KeyOf<T> = key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
+
+            Metadata::<T>::take(&key).ok_or(Error::<T>::NotFound)?;
+
+            Self::deposit_event(Event::MetadataRemoved { key });
+            Ok(())
+        }
+    }
 }
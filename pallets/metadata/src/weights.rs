@@ -0,0 +1,52 @@
+//! Weights for `pallet_metadata`, not benchmarked yet.
+//!
+//! Storing is a single bounded map write, linear in key and value length.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_metadata`.
+pub trait WeightInfo {
+    fn store_metadata(k: u32, v: u32) -> Weight;
+    fn remove_metadata(k: u32) -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn store_metadata(k: u32, v: u32) -> Weight {
+        Weight::from_parts(11_250_000, 0)
+            .saturating_add(Weight::from_parts(1_200, 0).saturating_mul(k.into()))
+            .saturating_add(Weight::from_parts(650, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn remove_metadata(k: u32) -> Weight {
+        Weight::from_parts(17_800_000, 3583)
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(k.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn store_metadata(k: u32, v: u32) -> Weight {
+        Weight::from_parts(11_250_000, 0)
+            .saturating_add(Weight::from_parts(1_200, 0).saturating_mul(k.into()))
+            .saturating_add(Weight::from_parts(650, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn remove_metadata(k: u32) -> Weight {
+        Weight::from_parts(17_800_000, 3583)
+            .saturating_add(Weight::from_parts(1_500, 0).saturating_mul(k.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+}
@@ -1 +1,2 @@
+mod mock;
 mod tests;
@@ -1,21 +1,65 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_ok, assert_noop};
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_metadata::{Error, Event};
+
+fn stored(key: &str) -> Option<Vec<u8>> {
+    MetadataModule::metadata(key.as_bytes().to_vec()).map(|value| value.into_inner())
+}
 
 #[test]
 fn store_metadata_works() {
-new_test_ext().execute_with(|| {
-assert_ok!(MetadataModule::store_metadata(RuntimeOrigin::root(), "key".into(), "value".into()));
-assert_eq!(MetadataModule::metadata("key".into()), Some("value".into()));
-});
+    new_test_ext().execute_with(|| {
+        assert_ok!(MetadataModule::store_metadata(RuntimeOrigin::root(), "key".into(), "value".into()));
+        assert_eq!(stored("key"), Some("value".into()));
+        System::assert_last_event(Event::MetadataStored { key: b"key".to_vec().try_into().unwrap() }.into());
+
+        // Storing again replaces the value.
+        assert_ok!(MetadataModule::store_metadata(RuntimeOrigin::root(), "key".into(), "other".into()));
+        assert_eq!(stored("key"), Some("other".into()));
+    });
 }
 
 #[test]
 fn store_metadata_fails_without_root() {
-new_test_ext().execute_with(|| {
-let user = 1;
-assert_noop!(
-MetadataModule::store_metadata(RuntimeOrigin::signed(user), "k".into(), "v".into()),
-Error::<Test>::NotAuthorized
-);
-});
+    new_test_ext().execute_with(|| {
+        let user = 1;
+        assert_noop!(
+            MetadataModule::store_metadata(RuntimeOrigin::signed(user), "k".into(), "v".into()),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn store_metadata_enforces_bounds() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MetadataModule::store_metadata(RuntimeOrigin::root(), vec![0; 9], "v".into()),
+            Error::<Test>::KeyTooLong
+        );
+        assert_noop!(
+            MetadataModule::store_metadata(RuntimeOrigin::root(), "k".into(), vec![0; 17]),
+            Error::<Test>::ValueTooLong
+        );
+        assert_ok!(MetadataModule::store_metadata(RuntimeOrigin::root(), vec![0; 8], vec![0; 16]));
+    });
+}
+
+#[test]
+fn remove_metadata_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MetadataModule::remove_metadata(RuntimeOrigin::root(), "key".into()),
+            Error::<Test>::NotFound
+        );
+        assert_ok!(MetadataModule::store_metadata(RuntimeOrigin::root(), "key".into(), "value".into()));
+        assert_noop!(
+            MetadataModule::remove_metadata(RuntimeOrigin::signed(1), "key".into()),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(MetadataModule::remove_metadata(RuntimeOrigin::root(), "key".into()));
+        assert_eq!(stored("key"), None);
+        System::assert_last_event(Event::MetadataRemoved { key: b"key".to_vec().try_into().unwrap() }.into());
+    });
 }
@@ -0,0 +1,148 @@
+//! Off-chain side of `pallet-payment-channels`.
+//!
+//! The sender keeps a [`VoucherSigner`] per channel and hands the recipient a
+//! new [`SignedVoucher`] with every payment. The recipient checks each one
+//! with a [`VoucherVerifier`], which keeps the best voucher seen; that is the
+//! one to submit with `paymentChannels.claim`.
+//!
+//! Vouchers are signed with sr25519 and carried as `MultiSignature`, like
+//! BlessChain transactions.
+
+use pallet_payment_channels::{ChannelId, Voucher};
+use sp_core::{crypto::AccountId32, sr25519, Pair, H256};
+use sp_runtime::{traits::Verify, MultiSignature};
+use std::fmt;
+
+pub type Balance = u128;
+
+/// A voucher and the sender's signature over it.
+#[derive(Clone, Debug, PartialEq, Eq, codec::Encode, codec::Decode)]
+pub struct SignedVoucher {
+    pub voucher: Voucher<H256, Balance>,
+    pub signature: MultiSignature,
+}
+
+/// Sign `voucher` with the sender's key.
+pub fn sign(pair: &sr25519::Pair, voucher: Voucher<H256, Balance>) -> SignedVoucher {
+    let signature = pair.sign(&voucher.signing_payload()).into();
+    SignedVoucher { voucher, signature }
+}
+
+/// Whether `signed` was signed by `sender`.
+pub fn verify(signed: &SignedVoucher, sender: &AccountId32) -> bool {
+    signed.signature.verify(&signed.voucher.signing_payload()[..], sender)
+}
+
+/// Issues the cumulative vouchers of one channel.
+pub struct VoucherSigner {
+    pair: sr25519::Pair,
+    genesis_hash: H256,
+    channel: ChannelId,
+    paid: Balance,
+}
+
+impl VoucherSigner {
+    /// Signer for `channel` on the chain with `genesis_hash`, which has paid
+    /// `paid` through it so far.
+    pub fn new(pair: sr25519::Pair, genesis_hash: H256, channel: ChannelId, paid: Balance) -> Self {
+        Self { pair, genesis_hash, channel, paid }
+    }
+
+    /// Total paid so far.
+    pub fn paid(&self) -> Balance {
+        self.paid
+    }
+
+    /// Pay `amount` more, returning the voucher for the new total.
+    pub fn pay(&mut self, amount: Balance) -> SignedVoucher {
+        self.paid = self.paid.saturating_add(amount);
+        let voucher =
+            Voucher { genesis_hash: self.genesis_hash, channel: self.channel, amount: self.paid };
+        sign(&self.pair, voucher)
+    }
+}
+
+/// Why a voucher was refused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoucherError {
+    /// The voucher is for another channel or chain.
+    WrongChannel,
+    /// The signature is not the sender's.
+    BadSignature,
+    /// The voucher pays nothing beyond the best one already accepted.
+    NotIncreasing,
+    /// The voucher pays more than the channel's deposit.
+    ExceedsDeposit,
+}
+
+impl fmt::Display for VoucherError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::WrongChannel => "voucher is for another channel",
+            Self::BadSignature => "voucher is not signed by the channel's sender",
+            Self::NotIncreasing => "voucher does not pay more than the last one",
+            Self::ExceedsDeposit => "voucher pays more than the channel deposit",
+        })
+    }
+}
+
+impl std::error::Error for VoucherError {}
+
+/// Checks the vouchers a recipient receives on one channel.
+pub struct VoucherVerifier {
+    sender: AccountId32,
+    genesis_hash: H256,
+    channel: ChannelId,
+    deposit: Balance,
+    best: Option<SignedVoucher>,
+}
+
+impl VoucherVerifier {
+    /// Verifier for `channel` from `sender` holding `deposit`, as read from
+    /// the chain.
+    pub fn new(
+        sender: AccountId32,
+        genesis_hash: H256,
+        channel: ChannelId,
+        deposit: Balance,
+    ) -> Self {
+        Self { sender, genesis_hash, channel, deposit, best: None }
+    }
+
+    /// The deposit went up, e.g. after a `topUp`.
+    pub fn set_deposit(&mut self, deposit: Balance) {
+        self.deposit = deposit;
+    }
+
+    /// Total paid by the best voucher so far.
+    pub fn paid(&self) -> Balance {
+        self.best.as_ref().map_or(0, |best| best.voucher.amount)
+    }
+
+    /// The voucher to claim with.
+    pub fn best(&self) -> Option<&SignedVoucher> {
+        self.best.as_ref()
+    }
+
+    /// Check `signed` and keep it as the best voucher, returning how much
+    /// more it pays than the previous best.
+    pub fn accept(&mut self, signed: SignedVoucher) -> Result<Balance, VoucherError> {
+        let voucher = &signed.voucher;
+        if voucher.channel != self.channel || voucher.genesis_hash != self.genesis_hash {
+            return Err(VoucherError::WrongChannel)
+        }
+        if voucher.amount <= self.paid() {
+            return Err(VoucherError::NotIncreasing)
+        }
+        if voucher.amount > self.deposit {
+            return Err(VoucherError::ExceedsDeposit)
+        }
+        if !verify(&signed, &self.sender) {
+            return Err(VoucherError::BadSignature)
+        }
+
+        let increase = voucher.amount - self.paid();
+        self.best = Some(signed);
+        Ok(increase)
+    }
+}
@@ -0,0 +1,50 @@
+use pallet_payment_channels::Voucher;
+use payment_channels_client::{sign, verify, VoucherError, VoucherSigner, VoucherVerifier};
+use sp_core::{sr25519, Pair, H256};
+
+const GENESIS: H256 = H256::repeat_byte(7);
+
+fn pair(seed: &str) -> sr25519::Pair {
+    sr25519::Pair::from_string(seed, None).unwrap()
+}
+
+#[test]
+fn recipient_keeps_the_best_voucher() {
+    let sender = pair("//Alice");
+    let mut signer = VoucherSigner::new(sender.clone(), GENESIS, 3, 0);
+    let mut verifier = VoucherVerifier::new(sender.public().into(), GENESIS, 3, 100);
+
+    let first = signer.pay(10);
+    let second = signer.pay(15);
+    assert_eq!(second.voucher, Voucher { genesis_hash: GENESIS, channel: 3, amount: 25 });
+    assert_eq!(verifier.accept(first.clone()), Ok(10));
+    assert_eq!(verifier.accept(second.clone()), Ok(15));
+
+    // An older voucher is worth nothing once a newer one is held.
+    assert_eq!(verifier.accept(first), Err(VoucherError::NotIncreasing));
+    assert_eq!(verifier.paid(), 25);
+    assert_eq!(verifier.best(), Some(&second));
+}
+
+#[test]
+fn forged_or_foreign_vouchers_are_refused() {
+    let sender = pair("//Alice");
+    let mut verifier = VoucherVerifier::new(sender.public().into(), GENESIS, 3, 100);
+
+    let voucher = |channel, amount| Voucher { genesis_hash: GENESIS, channel, amount };
+    let forged = sign(&pair("//Bob"), voucher(3, 10));
+    assert!(!verify(&forged, &sender.public().into()));
+    assert_eq!(verifier.accept(forged), Err(VoucherError::BadSignature));
+
+    let mut tampered = sign(&sender, voucher(3, 10));
+    tampered.voucher.amount = 50;
+    assert_eq!(verifier.accept(tampered), Err(VoucherError::BadSignature));
+
+    assert_eq!(verifier.accept(sign(&sender, voucher(4, 10))), Err(VoucherError::WrongChannel));
+    let other_chain = Voucher { genesis_hash: H256::zero(), channel: 3, amount: 10 };
+    assert_eq!(verifier.accept(sign(&sender, other_chain)), Err(VoucherError::WrongChannel));
+
+    assert_eq!(verifier.accept(sign(&sender, voucher(3, 101))), Err(VoucherError::ExceedsDeposit));
+    verifier.set_deposit(200);
+    assert_eq!(verifier.accept(sign(&sender, voucher(3, 101))), Ok(101));
+}
@@ -0,0 +1,90 @@
+//! Benchmarks for the payment channels pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Saturating};
+
+const SEED: u32 = 0;
+
+fn fund<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+/// Open a channel from `sender` to `recipient` with twice `MinDeposit`.
+fn open<T: Config>(
+    sender: &T::AccountId,
+    recipient: &T::AccountId,
+) -> Result<ChannelId, &'static str> {
+    fund::<T>(sender);
+    let id = NextChannelId::<T>::get();
+    let deposit = T::MinDeposit::get().saturating_add(T::MinDeposit::get());
+    Pallet::<T>::open(RawOrigin::Signed(sender.clone()).into(), recipient.clone(), deposit)?;
+    Ok(id)
+}
+
+benchmarks! {
+    open {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let deposit = T::MinDeposit::get();
+    }: _(RawOrigin::Signed(caller), recipient, deposit)
+    verify {
+        assert!(Channels::<T>::contains_key(0));
+    }
+
+    top_up {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = open::<T>(&caller, &account("recipient", 0, SEED))?;
+        let amount = T::MinDeposit::get();
+    }: _(RawOrigin::Signed(caller), id, amount)
+    verify {
+        assert_eq!(
+            Channels::<T>::get(id).unwrap().deposit,
+            amount.saturating_add(amount).saturating_add(amount)
+        );
+    }
+
+    // A second claim, so the recipient account already exists.
+    claim {
+        let (public, sender) = T::BenchmarkHelper::signer();
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let id = open::<T>(&sender, &caller)?;
+        let genesis_hash = Pallet::<T>::genesis_hash();
+        let voucher = |amount| Voucher { genesis_hash, channel: id, amount };
+
+        let first = T::MinDeposit::get();
+        let signature = T::BenchmarkHelper::sign(&public, &voucher(first).signing_payload());
+        Pallet::<T>::claim(RawOrigin::Signed(caller.clone()).into(), id, first, signature)?;
+
+        let amount = first.saturating_add(first);
+        let signature = T::BenchmarkHelper::sign(&public, &voucher(amount).signing_payload());
+    }: _(RawOrigin::Signed(caller), id, amount, signature)
+    verify {
+        assert_eq!(Channels::<T>::get(id).unwrap().claimed, amount);
+    }
+
+    close {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = open::<T>(&account("sender", 0, SEED), &caller)?;
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(!Channels::<T>::contains_key(id));
+    }
+
+    finalize {
+        let caller: T::AccountId = whitelisted_caller();
+        let sender: T::AccountId = account("sender", 0, SEED);
+        let id = open::<T>(&sender, &account("recipient", 0, SEED))?;
+        Pallet::<T>::close(RawOrigin::Signed(sender).into(), id)?;
+        let closes_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::ChallengePeriod::get());
+        frame_system::Pallet::<T>::set_block_number(closes_at);
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(!Channels::<T>::contains_key(id));
+    }
+}
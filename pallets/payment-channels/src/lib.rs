@@ -0,0 +1,348 @@
+//! Unidirectional payment channels.
+//!
+//! A sender opens a channel to a recipient by reserving a deposit. It then
+//! pays per request off chain by handing the recipient [`Voucher`]s: signed
+//! statements of the total paid so far. Only the latest voucher matters, so
+//! the recipient keeps the highest one and redeems it with `claim` whenever it
+//! likes; each claim pays out the difference to what was claimed before.
+//!
+//! The recipient can close the channel at any time, returning the unclaimed
+//! deposit to the sender. The sender can only ask to close: the channel then
+//! stays claimable for `ChallengePeriod` blocks, in which the recipient must
+//! redeem its latest voucher, before anyone may `finalize` it.
+//!
+//! `payment-channels-client` signs and checks vouchers off chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+pub type ChannelId = u64;
+
+/// Prefix of every voucher's signing payload, so a voucher signature cannot
+/// be mistaken for any other signed message.
+pub const VOUCHER_CONTEXT: [u8; 16] = *b"bless/voucher/v1";
+
+/// Named reserve holding the deposits of all of a sender's channels, so no
+/// other pallet's reserve is paid out or refunded by a channel.
+pub const RESERVE_ID: [u8; 8] = *b"paychan/";
+
+/// A sender's statement that `amount` in total has been paid through
+/// `channel`. Vouchers are cumulative: each replaces the previous one.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Voucher<Hash, Balance> {
+    /// Genesis hash of the chain, so vouchers do not carry over to another.
+    pub genesis_hash: Hash,
+    pub channel: ChannelId,
+    pub amount: Balance,
+}
+
+impl<Hash: Encode, Balance: Encode> Voucher<Hash, Balance> {
+    /// The bytes the sender signs.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        (VOUCHER_CONTEXT, self).encode()
+    }
+}
+
+/// Signing for the benchmarks, which cannot create signatures generically.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, AccountId, Signature> {
+    /// A new key pair and its account.
+    fn signer() -> (Public, AccountId);
+    /// `signer`'s signature over `message`.
+    fn sign(signer: &Public, message: &[u8]) -> Signature;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, NamedReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{IdentifyAccount, Saturating, Verify, Zero};
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type VoucherOf<T> = Voucher<<T as frame_system::Config>::Hash, BalanceOf<T>>;
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ChannelStatus<BlockNumber> {
+        Open,
+        /// The sender asked to close; anyone may finalize from `closes_at`.
+        Closing {
+            closes_at: BlockNumber,
+        },
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Channel<AccountId, Balance, BlockNumber> {
+        pub sender: AccountId,
+        pub recipient: AccountId,
+        /// Everything the sender reserved for the channel, claimed or not.
+        pub deposit: Balance,
+        /// Paid out to the recipient so far.
+        pub claimed: Balance,
+        pub status: ChannelStatus<BlockNumber>,
+    }
+
+    pub type ChannelOf<T> =
+        Channel<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency the deposit is reserved in, under [`RESERVE_ID`].
+        type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+
+        /// Signature on vouchers.
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+        /// Key vouchers are signed with; the sender's account is derived from it.
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Smallest deposit a channel is opened with.
+        #[pallet::constant]
+        type MinDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks the recipient has to claim after the sender asks to close.
+        #[pallet::constant]
+        type ChallengePeriod: Get<BlockNumberFor<Self>>;
+
+        type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+            Self::OffchainPublic,
+            Self::AccountId,
+            Self::OffchainSignature,
+        >;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    #[pallet::getter(fn channel)]
+    pub type Channels<T: Config> = StorageMap<_, Twox64Concat, ChannelId, ChannelOf<T>>;
+
+    #[pallet::storage]
+    pub type NextChannelId<T> = StorageValue<_, ChannelId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `sender` opened channel `id` to `recipient`, reserving `deposit`.
+        Opened {
+            id: ChannelId,
+            sender: T::AccountId,
+            recipient: T::AccountId,
+            deposit: BalanceOf<T>,
+        },
+        /// The sender reserved more, for a deposit of `deposit`.
+        ToppedUp { id: ChannelId, deposit: BalanceOf<T> },
+        /// `amount` was paid to the recipient, `claimed` in total.
+        Claimed { id: ChannelId, amount: BalanceOf<T>, claimed: BalanceOf<T> },
+        /// The sender asked to close; the recipient may claim until `closes_at`.
+        CloseRequested { id: ChannelId, closes_at: BlockNumberFor<T> },
+        /// The channel closed after paying `claimed`; `refunded` went back to
+        /// the sender.
+        Closed { id: ChannelId, claimed: BalanceOf<T>, refunded: BalanceOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No channel has this id.
+        UnknownChannel,
+        /// Senders cannot pay themselves.
+        SelfChannel,
+        /// The deposit is below `MinDeposit`.
+        DepositTooLow,
+        /// Only the sender may do this.
+        NotSender,
+        /// Only the recipient may do this.
+        NotRecipient,
+        /// Only the sender or the recipient may do this.
+        NotParticipant,
+        /// The sender has asked to close the channel.
+        Closing,
+        /// The sender has not asked to close the channel.
+        NotClosing,
+        /// The challenge period is not over.
+        ChallengePeriod,
+        /// The voucher is not signed by the sender for this channel.
+        InvalidSignature,
+        /// The voucher pays nothing beyond what was claimed.
+        NothingToClaim,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open a channel to `recipient`, reserving `deposit`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::open())]
+        pub fn open(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            #[pallet::compact] deposit: BalanceOf<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender != recipient, Error::<T>::SelfChannel);
+            ensure!(deposit >= T::MinDeposit::get(), Error::<T>::DepositTooLow);
+
+            T::Currency::reserve_named(&RESERVE_ID, &sender, deposit)?;
+            let id = NextChannelId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            Channels::<T>::insert(
+                id,
+                Channel {
+                    sender: sender.clone(),
+                    recipient: recipient.clone(),
+                    deposit,
+                    claimed: Zero::zero(),
+                    status: ChannelStatus::Open,
+                },
+            );
+
+            Self::deposit_event(Event::Opened { id, sender, recipient, deposit });
+            Ok(())
+        }
+
+        /// Reserve `amount` more for channel `id`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::top_up())]
+        pub fn top_up(
+            origin: OriginFor<T>,
+            id: ChannelId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let deposit = Channels::<T>::try_mutate(id, |maybe_channel| {
+                let channel = maybe_channel.as_mut().ok_or(Error::<T>::UnknownChannel)?;
+                ensure!(channel.sender == who, Error::<T>::NotSender);
+                ensure!(channel.status == ChannelStatus::Open, Error::<T>::Closing);
+                T::Currency::reserve_named(&RESERVE_ID, &who, amount)?;
+                channel.deposit = channel.deposit.saturating_add(amount);
+                Ok::<_, DispatchError>(channel.deposit)
+            })?;
+
+            Self::deposit_event(Event::ToppedUp { id, deposit });
+            Ok(())
+        }
+
+        /// Redeem the sender's voucher for `amount` in total. A voucher above
+        /// the deposit pays out the whole deposit.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim(
+            origin: OriginFor<T>,
+            id: ChannelId,
+            #[pallet::compact] amount: BalanceOf<T>,
+            signature: T::OffchainSignature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (paid, claimed) = Channels::<T>::try_mutate(id, |maybe_channel| {
+                let channel = maybe_channel.as_mut().ok_or(Error::<T>::UnknownChannel)?;
+                ensure!(channel.recipient == who, Error::<T>::NotRecipient);
+                let voucher = Voucher { genesis_hash: Self::genesis_hash(), channel: id, amount };
+                ensure!(
+                    signature.verify(&voucher.signing_payload()[..], &channel.sender),
+                    Error::<T>::InvalidSignature
+                );
+
+                let owed = amount.min(channel.deposit).saturating_sub(channel.claimed);
+                // Only count what actually left the sender's reserve.
+                let remainder = T::Currency::repatriate_reserved_named(
+                    &RESERVE_ID,
+                    &channel.sender,
+                    &channel.recipient,
+                    owed,
+                    BalanceStatus::Free,
+                )?;
+                let paid = owed.saturating_sub(remainder);
+                ensure!(!paid.is_zero(), Error::<T>::NothingToClaim);
+                channel.claimed = channel.claimed.saturating_add(paid);
+                Ok::<_, DispatchError>((paid, channel.claimed))
+            })?;
+
+            Self::deposit_event(Event::Claimed { id, amount: paid, claimed });
+            Ok(())
+        }
+
+        /// Close channel `id`. The recipient closes at once; the sender only
+        /// starts the `ChallengePeriod`, after which it can `finalize`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::close())]
+        pub fn close(origin: OriginFor<T>, id: ChannelId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut channel = Channels::<T>::get(id).ok_or(Error::<T>::UnknownChannel)?;
+
+            if who == channel.recipient {
+                Self::settle(id, channel);
+            } else if who == channel.sender {
+                ensure!(channel.status == ChannelStatus::Open, Error::<T>::Closing);
+                let closes_at = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::ChallengePeriod::get());
+                channel.status = ChannelStatus::Closing { closes_at };
+                Channels::<T>::insert(id, channel);
+                Self::deposit_event(Event::CloseRequested { id, closes_at });
+            } else {
+                return Err(Error::<T>::NotParticipant.into())
+            }
+            Ok(())
+        }
+
+        /// Close channel `id` once its challenge period is over.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::finalize())]
+        pub fn finalize(origin: OriginFor<T>, id: ChannelId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let channel = Channels::<T>::get(id).ok_or(Error::<T>::UnknownChannel)?;
+            let ChannelStatus::Closing { closes_at } = channel.status else {
+                return Err(Error::<T>::NotClosing.into())
+            };
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= closes_at,
+                Error::<T>::ChallengePeriod
+            );
+
+            Self::settle(id, channel);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The genesis hash vouchers on this chain are signed for.
+        pub fn genesis_hash() -> T::Hash {
+            frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero())
+        }
+
+        /// Refund what the recipient has not claimed and remove the channel.
+        fn settle(id: ChannelId, channel: ChannelOf<T>) {
+            let unclaimed = channel.deposit.saturating_sub(channel.claimed);
+            let remainder = T::Currency::unreserve_named(&RESERVE_ID, &channel.sender, unclaimed);
+            let refunded = unclaimed.saturating_sub(remainder);
+            Channels::<T>::remove(id);
+
+            Self::deposit_event(Event::Closed { id, claimed: channel.claimed, refunded });
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::mock::*;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{NamedReservableCurrency, ReservableCurrency},
+};
+use pallet_payment_channels::{ChannelStatus, Error, Event, RESERVE_ID};
+use payment_channels_client::{SignedVoucher, VoucherSigner};
+use sp_runtime::DispatchError;
+
+/// Open channel 0 from Alice to Bob, returning Alice's voucher signer.
+fn open_alice_to_bob(deposit: u128) -> VoucherSigner {
+    assert_ok!(PaymentChannels::open(
+        RuntimeOrigin::signed(account("Alice")),
+        account("Bob"),
+        deposit
+    ));
+    VoucherSigner::new(pair("Alice"), PaymentChannels::genesis_hash(), 0, 0)
+}
+
+fn claim(who: &str, signed: &SignedVoucher) -> frame_support::dispatch::DispatchResult {
+    PaymentChannels::claim(
+        RuntimeOrigin::signed(account(who)),
+        signed.voucher.channel,
+        signed.voucher.amount,
+        signed.signature.clone(),
+    )
+}
+
+#[test]
+fn recipient_claims_cumulative_vouchers() {
+    new_test_ext().execute_with(|| {
+        let mut alice = open_alice_to_bob(100);
+        System::assert_last_event(
+            Event::Opened {
+                id: 0,
+                sender: account("Alice"),
+                recipient: account("Bob"),
+                deposit: 100,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(account("Alice")), 100);
+
+        // Many off-chain payments, one on-chain claim.
+        let mut latest = alice.pay(1);
+        for _ in 0..29 {
+            latest = alice.pay(1);
+        }
+        assert_ok!(claim("Bob", &latest));
+        System::assert_last_event(Event::Claimed { id: 0, amount: 30, claimed: 30 }.into());
+        assert_eq!(Balances::free_balance(account("Bob")), ENDOWMENT + 30);
+
+        let next = alice.pay(20);
+        assert_ok!(claim("Bob", &next));
+        System::assert_last_event(Event::Claimed { id: 0, amount: 20, claimed: 50 }.into());
+
+        // Replaying an older voucher pays nothing.
+        assert_noop!(claim("Bob", &latest), Error::<Test>::NothingToClaim);
+
+        assert_ok!(PaymentChannels::close(RuntimeOrigin::signed(account("Bob")), 0));
+        System::assert_last_event(Event::Closed { id: 0, claimed: 50, refunded: 50 }.into());
+        assert_eq!(Balances::free_balance(account("Alice")), ENDOWMENT - 50);
+        assert_eq!(Balances::reserved_balance(account("Alice")), 0);
+        assert_eq!(PaymentChannels::channel(0), None);
+    });
+}
+
+#[test]
+fn closing_refunds_only_the_channel_reserve() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Balances::reserve(&account("Alice"), 50));
+        let mut alice = open_alice_to_bob(100);
+        assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &account("Alice")), 100);
+
+        assert_ok!(claim("Bob", &alice.pay(30)));
+        assert_ok!(PaymentChannels::close(RuntimeOrigin::signed(account("Bob")), 0));
+
+        System::assert_last_event(Event::Closed { id: 0, claimed: 30, refunded: 70 }.into());
+        assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &account("Alice")), 0);
+        assert_eq!(Balances::reserved_balance(account("Alice")), 50);
+    });
+}
+
+#[test]
+fn claim_counts_only_what_left_the_reserve() {
+    new_test_ext().execute_with(|| {
+        let mut alice = open_alice_to_bob(100);
+        // Something else releases most of Alice's reserve behind the channel.
+        assert_eq!(Balances::unreserve(&account("Alice"), 60), 0);
+
+        assert_ok!(claim("Bob", &alice.pay(100)));
+
+        System::assert_last_event(Event::Claimed { id: 0, amount: 40, claimed: 40 }.into());
+        assert_eq!(Balances::free_balance(account("Bob")), ENDOWMENT + 40);
+        assert_eq!(PaymentChannels::channel(0).unwrap().claimed, 40);
+    });
+}
+
+#[test]
+fn vouchers_must_be_signed_by_the_sender_for_the_channel() {
+    new_test_ext().execute_with(|| {
+        open_alice_to_bob(100);
+        let genesis_hash = PaymentChannels::genesis_hash();
+
+        let mut charlie = VoucherSigner::new(pair("Charlie"), genesis_hash, 0, 0);
+        assert_noop!(claim("Bob", &charlie.pay(10)), Error::<Test>::InvalidSignature);
+
+        let mut other_channel = VoucherSigner::new(pair("Alice"), genesis_hash, 1, 0);
+        let mut voucher = other_channel.pay(10);
+        voucher.voucher.channel = 0;
+        assert_noop!(claim("Bob", &voucher), Error::<Test>::InvalidSignature);
+
+        let mut other_chain = VoucherSigner::new(pair("Alice"), Default::default(), 0, 0);
+        assert_noop!(claim("Bob", &other_chain.pay(10)), Error::<Test>::InvalidSignature);
+
+        let mut alice = VoucherSigner::new(pair("Alice"), genesis_hash, 0, 0);
+        let valid = alice.pay(10);
+        assert_noop!(claim("Charlie", &valid), Error::<Test>::NotRecipient);
+        assert_ok!(claim("Bob", &valid));
+    });
+}
+
+#[test]
+fn voucher_above_the_deposit_pays_out_the_deposit() {
+    new_test_ext().execute_with(|| {
+        let mut alice = open_alice_to_bob(100);
+        let overdrawn = alice.pay(150);
+
+        assert_ok!(claim("Bob", &overdrawn));
+        System::assert_last_event(Event::Claimed { id: 0, amount: 100, claimed: 100 }.into());
+        assert_noop!(claim("Bob", &overdrawn), Error::<Test>::NothingToClaim);
+
+        // A top-up makes the rest of the voucher claimable.
+        assert_ok!(PaymentChannels::top_up(RuntimeOrigin::signed(account("Alice")), 0, 100));
+        System::assert_last_event(Event::ToppedUp { id: 0, deposit: 200 }.into());
+        assert_ok!(claim("Bob", &overdrawn));
+        System::assert_last_event(Event::Claimed { id: 0, amount: 50, claimed: 150 }.into());
+    });
+}
+
+#[test]
+fn recipient_can_dispute_a_close_within_the_challenge_period() {
+    new_test_ext().execute_with(|| {
+        let mut alice = open_alice_to_bob(100);
+        let unclaimed = alice.pay(40);
+
+        assert_ok!(PaymentChannels::close(RuntimeOrigin::signed(account("Alice")), 0));
+        let closes_at = 1 + CHALLENGE_PERIOD;
+        System::assert_last_event(Event::CloseRequested { id: 0, closes_at }.into());
+        assert_eq!(
+            PaymentChannels::channel(0).unwrap().status,
+            ChannelStatus::Closing { closes_at }
+        );
+        assert_noop!(
+            PaymentChannels::top_up(RuntimeOrigin::signed(account("Alice")), 0, 10),
+            Error::<Test>::Closing
+        );
+        assert_noop!(
+            PaymentChannels::finalize(RuntimeOrigin::signed(account("Alice")), 0),
+            Error::<Test>::ChallengePeriod
+        );
+
+        System::set_block_number(closes_at - 1);
+        assert_ok!(claim("Bob", &unclaimed));
+
+        System::set_block_number(closes_at);
+        assert_ok!(PaymentChannels::finalize(RuntimeOrigin::signed(account("Charlie")), 0));
+        System::assert_last_event(Event::Closed { id: 0, claimed: 40, refunded: 60 }.into());
+        assert_eq!(Balances::free_balance(account("Alice")), ENDOWMENT - 40);
+        assert_eq!(Balances::free_balance(account("Bob")), ENDOWMENT + 40);
+    });
+}
+
+#[test]
+fn only_participants_close_and_only_closing_channels_finalize() {
+    new_test_ext().execute_with(|| {
+        let alice = RuntimeOrigin::signed(account("Alice"));
+        assert_noop!(
+            PaymentChannels::open(alice.clone(), account("Alice"), 100),
+            Error::<Test>::SelfChannel
+        );
+        assert_noop!(
+            PaymentChannels::open(alice.clone(), account("Bob"), MIN_DEPOSIT - 1),
+            Error::<Test>::DepositTooLow
+        );
+        assert!(PaymentChannels::open(alice.clone(), account("Bob"), ENDOWMENT + 1).is_err());
+        open_alice_to_bob(100);
+
+        assert_noop!(
+            PaymentChannels::close(RuntimeOrigin::signed(account("Charlie")), 0),
+            Error::<Test>::NotParticipant
+        );
+        assert_noop!(
+            PaymentChannels::top_up(RuntimeOrigin::signed(account("Bob")), 0, 10),
+            Error::<Test>::NotSender
+        );
+        assert_noop!(PaymentChannels::finalize(alice.clone(), 0), Error::<Test>::NotClosing);
+        assert_noop!(PaymentChannels::close(alice, 1), Error::<Test>::UnknownChannel);
+        assert_noop!(PaymentChannels::finalize(RuntimeOrigin::none(), 0), DispatchError::BadOrigin);
+        assert_eq!(Balances::reserved_balance(account("Alice")), 100);
+    });
+}
@@ -0,0 +1,38 @@
+//! Benchmarks for the randomness pallet.
+
+use super::*;
+use codec::Decode;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Hooks;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::{crypto::KeyTypeId, sr25519::vrf::VrfSignature};
+
+benchmarks! {
+    // The ring buffer is full, so the parent hash replaces an old one.
+    on_initialize {
+        let n: BlockNumberFor<T> = (2 * RANDOM_MATERIAL_LEN).into();
+        for i in RANDOM_MATERIAL_LEN..2 * RANDOM_MATERIAL_LEN {
+            frame_system::Pallet::<T>::set_block_number(i.into());
+            Pallet::<T>::on_initialize(i.into());
+        }
+        frame_system::Pallet::<T>::set_block_number(n);
+    }: {
+        Pallet::<T>::on_initialize(n);
+    }
+    verify {
+        assert_eq!(RandomMaterial::<T>::get().len() as u32, RANDOM_MATERIAL_LEN);
+    }
+
+    // Checking a signature that fails costs as much as one that passes, and
+    // needs no author key to sign with in the runtime.
+    note_vrf_output {
+        let author = sp_io::crypto::sr25519_generate(KeyTypeId(VRF_ENGINE_ID), None);
+        let signature = VrfSignature::decode(&mut &[0u8; 96][..]).map_err(|_| "bad signature")?;
+        let n = frame_system::Pallet::<T>::block_number();
+    }: {
+        Pallet::<T>::note_vrf_output(&author, &signature, n);
+    }
+    verify {
+        assert_eq!(LastVrfBlock::<T>::get(), None);
+    }
+}
@@ -0,0 +1,180 @@
+//! On-chain randomness for the runtime's `Randomness` consumers.
+//!
+//! Two sources feed it:
+//!
+//! - **Collective flip.** The hashes of the last [`RANDOM_MATERIAL_LEN`]
+//!   blocks, mixed with the subject. Works on any chain, including dev chains
+//!   sealed without Aura authors, but is known to everyone as soon as the
+//!   parent block is, and the author of each block picks one of its inputs.
+//! - **Author VRF.** An Aura author may put a [`VRF_ENGINE_ID`] pre-runtime
+//!   digest in its block: an sr25519 VRF signature with its Aura key over
+//!   [`vrf_transcript`] of the previous VRF randomness and the block number.
+//!   A valid output is chained into [`VrfRandomness`]. The author learns the
+//!   output first but cannot choose it; its only lever is leaving it out.
+//!
+//! [`Pallet`] implements [`Randomness`](frame_support::traits::Randomness)
+//! from the VRF randomness while authors keep providing outputs, and falls
+//! back to the collective flip once none has been seen for
+//! `RANDOM_MATERIAL_LEN` blocks.
+//!
+//! Neither source suits high-stakes draws on its own: a consumer should only
+//! use a value after the block `random` reports it as known since, and should
+//! assume an author can withhold a block it dislikes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use sp_core::sr25519::vrf::VrfTranscript;
+use sp_runtime::ConsensusEngineId;
+
+/// Number of past block hashes the collective flip mixes.
+pub const RANDOM_MATERIAL_LEN: u32 = 81;
+
+/// Engine id of the pre-runtime digest carrying the author's VRF signature.
+pub const VRF_ENGINE_ID: ConsensusEngineId = *b"bvrf";
+
+/// The transcript the author of block `number` signs, over the VRF randomness
+/// as of its parent. Nodes build it the same way to produce the digest.
+pub fn vrf_transcript(previous: &[u8; 32], number: u64) -> VrfTranscript {
+    VrfTranscript::new(
+        b"BlessChain VRF",
+        &[(b"previous randomness", &previous[..]), (b"block number", &number.to_le_bytes())],
+    )
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{FindAuthor, Randomness},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_core::{
+        crypto::VrfVerifier,
+        sr25519::{self, vrf::VrfSignature},
+    };
+    use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::{Hash, One, Saturating, UniqueSaturatedInto};
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Key the author of the current block signs its VRF output with.
+        type FindAuthor: FindAuthor<sr25519::Public>;
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Hashes of the last `RANDOM_MATERIAL_LEN` blocks, as a ring buffer.
+    #[pallet::storage]
+    #[pallet::getter(fn random_material)]
+    pub type RandomMaterial<T: Config> =
+        StorageValue<_, BoundedVec<T::Hash, ConstU32<RANDOM_MATERIAL_LEN>>, ValueQuery>;
+
+    /// Every valid author VRF output so far, chained.
+    #[pallet::storage]
+    #[pallet::getter(fn vrf_randomness)]
+    pub type VrfRandomness<T: Config> = StorageValue<_, [u8; 32], ValueQuery>;
+
+    /// The last block with a valid author VRF output.
+    #[pallet::storage]
+    #[pallet::getter(fn last_vrf_block)]
+    pub type LastVrfBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::note_parent_hash(n);
+            let mut weight = T::WeightInfo::on_initialize();
+
+            let digest = frame_system::Pallet::<T>::digest();
+            let pre_runtime = digest.logs.iter().filter_map(|item| item.as_pre_runtime());
+            let signature = pre_runtime
+                .clone()
+                .find(|(id, _)| *id == VRF_ENGINE_ID)
+                .and_then(|(_, mut data)| VrfSignature::decode(&mut data).ok());
+            if let Some(signature) = signature {
+                // An output that does not verify, e.g. signed by an author the
+                // session just rotated out, leaves the randomness unchanged:
+                // the author could have left it out anyway.
+                if let Some(author) = T::FindAuthor::find_author(pre_runtime) {
+                    Self::note_vrf_output(&author, &signature, n);
+                }
+                weight.saturating_accrue(T::WeightInfo::note_vrf_output());
+            }
+            weight
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Record the parent hash of block `n` in the collective flip material.
+        fn note_parent_hash(n: BlockNumberFor<T>) {
+            let parent = frame_system::Pallet::<T>::parent_hash();
+            RandomMaterial::<T>::mutate(|material| {
+                if material.try_push(parent).is_err() {
+                    // Block 1 filled slot 0, so block `n` replaces slot `n - 1`.
+                    let index: u32 = (n.saturating_sub(One::one()) % RANDOM_MATERIAL_LEN.into())
+                        .unique_saturated_into();
+                    material[index as usize] = parent;
+                }
+            });
+        }
+
+        /// Chain `author`'s VRF output for block `n` into the randomness if
+        /// `signature` verifies. Returns whether it did.
+        pub(crate) fn note_vrf_output(
+            author: &sr25519::Public,
+            signature: &VrfSignature,
+            n: BlockNumberFor<T>,
+        ) -> bool {
+            let previous = VrfRandomness::<T>::get();
+            let transcript = vrf_transcript(&previous, n.unique_saturated_into());
+            if !author.vrf_verify(&transcript, signature) {
+                return false
+            }
+            VrfRandomness::<T>::put(blake2_256(&(previous, &signature.output).encode()));
+            LastVrfBlock::<T>::put(n);
+            true
+        }
+
+        /// Randomness from the hashes of the last `RANDOM_MATERIAL_LEN` blocks.
+        ///
+        /// The result is known to anyone once the parent block is, and each
+        /// block author picks one of the hashes. It is reported as known since
+        /// `RANDOM_MATERIAL_LEN` blocks ago, when the oldest hash was fixed.
+        pub fn collective_flip(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            let known_since = frame_system::Pallet::<T>::block_number()
+                .saturating_sub(RANDOM_MATERIAL_LEN.into());
+            let material = RandomMaterial::<T>::get();
+            ((subject, material).using_encoded(T::Hashing::hash), known_since)
+        }
+
+        /// Randomness from the author VRF outputs, or `None` if no author has
+        /// provided one in the last `RANDOM_MATERIAL_LEN` blocks.
+        pub fn author_vrf(subject: &[u8]) -> Option<(T::Hash, BlockNumberFor<T>)> {
+            let last = LastVrfBlock::<T>::get()?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if now.saturating_sub(last) >= RANDOM_MATERIAL_LEN.into() {
+                return None
+            }
+            let seed = (subject, VrfRandomness::<T>::get()).using_encoded(T::Hashing::hash);
+            Some((seed, last))
+        }
+    }
+
+    impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+        /// The author VRF randomness while it is fresh, else the collective flip.
+        fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            Self::author_vrf(subject).unwrap_or_else(|| Self::collective_flip(subject))
+        }
+    }
+}
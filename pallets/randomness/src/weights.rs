@@ -0,0 +1,48 @@
+//! Weights for `pallet_randomness`, not benchmarked yet.
+//!
+//! Costs are the seed reads plus the VRF verification on submission.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_randomness`.
+pub trait WeightInfo {
+    fn on_initialize() -> Weight;
+    fn note_vrf_output() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn on_initialize() -> Weight {
+        Weight::from_parts(6_000_000, 4131)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn note_vrf_output() -> Weight {
+        Weight::from_parts(120_000_000, 3551)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn on_initialize() -> Weight {
+        Weight::from_parts(6_000_000, 4131)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn note_vrf_output() -> Weight {
+        Weight::from_parts(120_000_000, 3551)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+}
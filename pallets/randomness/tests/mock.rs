@@ -0,0 +1,105 @@
+use codec::Encode;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, FindAuthor, Hooks},
+};
+use sp_core::{crypto::VrfSigner, sr25519, H256};
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    ConsensusEngineId, Digest, DigestItem,
+};
+
+use pallet_randomness::{vrf_transcript, VRF_ENGINE_ID};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        RandomnessSource: pallet_randomness,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    /// Key of the current block's author, as the runtime would find it from
+    /// the Aura pre-digest.
+    pub static Author: Option<sr25519::Public> = None;
+}
+
+pub struct TestAuthor;
+impl FindAuthor<sr25519::Public> for TestAuthor {
+    fn find_author<'a, I>(_digests: I) -> Option<sr25519::Public>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        Author::get()
+    }
+}
+
+impl pallet_randomness::Config for Test {
+    type FindAuthor = TestAuthor;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    sp_io::TestExternalities::new(storage)
+}
+
+/// Parent hash of block `n` in the mock chain.
+pub fn parent_of(n: u64) -> H256 {
+    H256::from_low_u64_be(n)
+}
+
+/// Start block `n` on `parent` with `logs` in its digest, as block import
+/// would, and run the pallet's hook.
+pub fn start_block(n: u64, parent: H256, logs: Vec<DigestItem>) {
+    System::initialize(&n, &parent, &Digest { logs });
+    RandomnessSource::on_initialize(n);
+}
+
+/// Start every block up to `n`, none of them with a VRF output.
+pub fn run_to_block(n: u64) {
+    for next in System::block_number() + 1..=n {
+        start_block(next, parent_of(next), vec![]);
+    }
+}
+
+/// The digest `pair` would add as author of block `n`.
+pub fn vrf_digest(pair: &sr25519::Pair, n: u64) -> DigestItem {
+    let transcript = vrf_transcript(&RandomnessSource::vrf_randomness(), n);
+    DigestItem::PreRuntime(VRF_ENGINE_ID, pair.vrf_sign(&transcript).encode())
+}
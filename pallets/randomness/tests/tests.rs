@@ -0,0 +1,142 @@
+//! Each test states the security property it pins down. Consumers of
+//! `Randomness` should read these before relying on a draw.
+
+use crate::mock::*;
+use codec::Encode;
+use frame_support::traits::Randomness;
+use pallet_randomness::{vrf_transcript, RANDOM_MATERIAL_LEN};
+use sp_core::{crypto::VrfSigner, sr25519, Pair, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn pair(name: &str) -> sr25519::Pair {
+    sr25519::Pair::from_string(&format!("//{name}"), None).unwrap()
+}
+
+/// Security: every input of the collective flip is a past block hash, so
+/// anyone who has seen the parent block, including the next author and every
+/// transaction sender, knows the value before it is used. Only use it where
+/// learning the outcome early is worth nothing, e.g. on dev chains.
+#[test]
+fn collective_flip_is_known_once_the_parent_is() {
+    new_test_ext().execute_with(|| {
+        run_to_block(5);
+
+        let material: Vec<H256> = (1..=5).map(parent_of).collect();
+        let expected = (&b"subject"[..], material).using_encoded(BlakeTwo256::hash);
+        assert_eq!(RandomnessSource::random(b"subject"), (expected, 0));
+        assert_ne!(RandomnessSource::random(b"other").0, expected);
+    });
+}
+
+/// Security: an author chooses its block's contents, and so the hash that
+/// feeds every later draw. Trying another variant costs one hash, so a block
+/// author can grind for an outcome it likes or withhold a block it does not.
+#[test]
+fn each_author_picks_an_input_of_the_collective_flip() {
+    let draw = |parent: H256| {
+        new_test_ext().execute_with(|| {
+            run_to_block(4);
+            start_block(5, parent, vec![]);
+            RandomnessSource::random(b"subject").0
+        })
+    };
+    assert_ne!(draw(H256::repeat_byte(1)), draw(H256::repeat_byte(2)));
+}
+
+#[test]
+fn collective_flip_keeps_the_last_hashes() {
+    new_test_ext().execute_with(|| {
+        run_to_block(100);
+
+        let material = RandomnessSource::random_material();
+        assert_eq!(material.len() as u32, RANDOM_MATERIAL_LEN);
+        assert!(material.contains(&parent_of(100)));
+        assert!(material.contains(&parent_of(20)));
+        assert!(!material.contains(&parent_of(19)));
+        assert_eq!(RandomnessSource::random(b"subject").1, 100 - u64::from(RANDOM_MATERIAL_LEN));
+    });
+}
+
+/// Security: the VRF output is fixed by the author's key and the transcript,
+/// so the author learns it first but cannot choose it: signing again gives the
+/// same output.
+#[test]
+fn author_vrf_output_replaces_the_collective_flip() {
+    new_test_ext().execute_with(|| {
+        let alice = pair("Alice");
+        Author::set(Some(alice.public()));
+        run_to_block(1);
+
+        let transcript = vrf_transcript(&[0; 32], 2);
+        assert_eq!(alice.vrf_sign(&transcript).output, alice.vrf_sign(&transcript).output);
+
+        start_block(2, parent_of(2), vec![vrf_digest(&alice, 2)]);
+        assert_eq!(RandomnessSource::last_vrf_block(), Some(2));
+        let randomness = RandomnessSource::vrf_randomness();
+        assert_ne!(randomness, [0; 32]);
+
+        let expected = (&b"subject"[..], randomness).using_encoded(BlakeTwo256::hash);
+        assert_eq!(RandomnessSource::random(b"subject"), (expected, 2));
+        assert_ne!(
+            RandomnessSource::random(b"subject"),
+            RandomnessSource::collective_flip(b"subject")
+        );
+
+        // Each output is chained onto the previous randomness.
+        start_block(3, parent_of(3), vec![vrf_digest(&alice, 3)]);
+        assert_eq!(RandomnessSource::last_vrf_block(), Some(3));
+        assert_ne!(RandomnessSource::vrf_randomness(), randomness);
+    });
+}
+
+/// Security: an output only counts if it verifies against the author's key
+/// over the previous randomness and the block number, so an author cannot
+/// present someone else's output or replay an old one to steer the result.
+#[test]
+fn forged_and_replayed_outputs_are_ignored() {
+    new_test_ext().execute_with(|| {
+        let alice = pair("Alice");
+        Author::set(Some(alice.public()));
+        run_to_block(1);
+
+        start_block(2, parent_of(2), vec![vrf_digest(&pair("Bob"), 2)]);
+        assert_eq!(RandomnessSource::last_vrf_block(), None);
+
+        let replayed = vrf_digest(&alice, 3);
+        start_block(3, parent_of(3), vec![replayed.clone()]);
+        assert_eq!(RandomnessSource::last_vrf_block(), Some(3));
+        let randomness = RandomnessSource::vrf_randomness();
+        start_block(4, parent_of(4), vec![replayed]);
+        assert_eq!(RandomnessSource::last_vrf_block(), Some(3));
+        assert_eq!(RandomnessSource::vrf_randomness(), randomness);
+
+        Author::set(None);
+        start_block(5, parent_of(5), vec![vrf_digest(&alice, 5)]);
+        assert_eq!(RandomnessSource::last_vrf_block(), Some(3));
+    });
+}
+
+/// Security: an author that dislikes its output can leave it out, or withhold
+/// the block, which gives it one bit of influence per block it authors. Wait
+/// for outputs from several authors before relying on a draw. Once no output
+/// has been seen for `RANDOM_MATERIAL_LEN` blocks, draws fall back to the
+/// collective flip rather than serve a stale value.
+#[test]
+fn withheld_outputs_fall_back_to_the_collective_flip() {
+    new_test_ext().execute_with(|| {
+        let alice = pair("Alice");
+        Author::set(Some(alice.public()));
+        run_to_block(1);
+        start_block(2, parent_of(2), vec![vrf_digest(&alice, 2)]);
+        let drawn = RandomnessSource::random(b"subject");
+
+        run_to_block(2 + u64::from(RANDOM_MATERIAL_LEN) - 1);
+        assert_eq!(RandomnessSource::random(b"subject"), drawn);
+
+        run_to_block(2 + u64::from(RANDOM_MATERIAL_LEN));
+        assert_eq!(
+            RandomnessSource::random(b"subject"),
+            RandomnessSource::collective_flip(b"subject")
+        );
+    });
+}
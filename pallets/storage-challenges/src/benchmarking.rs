@@ -0,0 +1,119 @@
+//! Benchmarks for the storage challenges pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_homecdn::ProviderRegistry;
+use sp_core::H256;
+use sp_std::{prelude::*, vec};
+
+const SEED: u32 = 0;
+/// Chunks of the benchmarked content, enough for a ten-level proof.
+const CHUNKS: u32 = 1_024;
+
+fn chunk<T: Config>(index: u32) -> Vec<u8> {
+    let mut chunk = vec![0u8; T::MaxChunkSize::get() as usize];
+    chunk[..4].copy_from_slice(&index.to_le_bytes());
+    chunk
+}
+
+fn leaves<T: Config>() -> Vec<H256> {
+    (0..CHUNKS).map(|index| merkle::leaf(&chunk::<T>(index))).collect()
+}
+
+fn commit<T: Config>(provider: &T::AccountId, root: H256) -> Result<(), &'static str> {
+    T::Providers::register_for_benchmarks(provider);
+    Pallet::<T>::commit(RawOrigin::Signed(provider.clone()).into(), root, CHUNKS)?;
+    Ok(())
+}
+
+fn challenge<T: Config>(provider: &T::AccountId, root: H256) -> Result<(), &'static str> {
+    let origin = T::ChallengeOrigin::try_successful_origin().map_err(|_| "no challenge origin")?;
+    Pallet::<T>::challenge(origin, provider.clone(), root)?;
+    Ok(())
+}
+
+benchmarks! {
+    commit {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Providers::register_for_benchmarks(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), H256::repeat_byte(1), CHUNKS)
+    verify {
+        assert_eq!(CommitmentCount::<T>::get(), 1);
+    }
+
+    // Worst case: another commitment moves into the freed slot.
+    release {
+        let caller: T::AccountId = whitelisted_caller();
+        commit::<T>(&caller, H256::repeat_byte(1))?;
+        let origin = RawOrigin::Signed(caller.clone()).into();
+        Pallet::<T>::commit(origin, H256::repeat_byte(2), CHUNKS)?;
+    }: _(RawOrigin::Signed(caller.clone()), H256::repeat_byte(1))
+    verify {
+        assert_eq!(CommitmentSlots::<T>::get(0), Some((caller, H256::repeat_byte(2))));
+    }
+
+    challenge {
+        let origin = T::ChallengeOrigin::try_successful_origin()
+            .map_err(|_| "no challenge origin")?;
+        let provider: T::AccountId = account("provider", 0, SEED);
+        commit::<T>(&provider, H256::repeat_byte(1))?;
+    }: _<T::RuntimeOrigin>(origin, provider, H256::repeat_byte(1))
+    verify {
+        assert!(Challenges::<T>::contains_key(0));
+    }
+
+    // Full-size chunks, each with a ten-level path.
+    respond {
+        let caller: T::AccountId = whitelisted_caller();
+        let leaves = leaves::<T>();
+        let root = merkle::root(&leaves);
+        commit::<T>(&caller, root)?;
+        challenge::<T>(&caller, root)?;
+        let proofs: Vec<ChunkProof<T>> = Challenges::<T>::get(0)
+            .ok_or("challenge issued")?
+            .chunks
+            .iter()
+            .map(|index| ChunkProof {
+                chunk: chunk::<T>(*index).try_into().expect("chunk fits"),
+                path: merkle::proof(&leaves, *index as usize)
+                    .expect("index in range")
+                    .try_into()
+                    .expect("path fits"),
+            })
+            .collect();
+        let proofs = proofs.try_into().map_err(|_| "one proof per chunk")?;
+    }: _(RawOrigin::Signed(caller.clone()), 0, proofs)
+    verify {
+        assert!(!Challenges::<T>::contains_key(0));
+    }
+
+    // Every due challenge slashes.
+    expire_challenges {
+        let c in 0 .. T::MaxChallengesPerBlock::get();
+        for i in 0 .. c {
+            let provider: T::AccountId = account("provider", i, SEED);
+            Reputation::<T>::insert(&provider, 0);
+            commit::<T>(&provider, H256::repeat_byte(1))?;
+            challenge::<T>(&provider, H256::repeat_byte(1))?;
+        }
+        let deadline = frame_system::Pallet::<T>::block_number() + T::ResponsePeriod::get();
+    }: {
+        Pallet::<T>::expire_challenges(deadline);
+    }
+    verify {
+        assert!(Challenges::<T>::iter().next().is_none());
+    }
+
+    issue_challenges {
+        for i in 0 .. T::AutoChallenges::get() {
+            commit::<T>(&account("provider", i, SEED), H256::repeat_byte(1))?;
+        }
+    }: {
+        Pallet::<T>::challenge_random(T::ChallengeInterval::get());
+    }
+    verify {
+        assert_eq!(NextChallengeId::<T>::get(), T::AutoChallenges::get());
+    }
+}
@@ -0,0 +1,457 @@
+//! Proof-of-retrievability challenges for HomeCDN providers.
+//!
+//! An active provider commits to each piece of content it stores by its
+//! [`merkle`] root and chunk count. Every `ChallengeInterval` blocks the
+//! runtime picks `AutoChallenges` commitments at random, skipping those of
+//! providers that are no longer active, and `ChallengeOrigin` may challenge one
+//! directly, e.g. after a failed delivery. A challenge names
+//! `ChunksPerChallenge` chunk indices drawn from `Randomness`; the provider
+//! has `ResponsePeriod` blocks to submit those chunks with their Merkle paths.
+//!
+//! A passed challenge restores `ReputationReward` reputation, up to
+//! [`MAX_REPUTATION`]. An unanswered one costs `ReputationPenalty`, and once
+//! reputation is below `SlashThreshold` every further failure also slashes
+//! `FailureSlash` of the provider's HomeCDN bond.
+//!
+//! Challenge indices are only as unpredictable as `Randomness`. A provider
+//! that can predict them may keep just the chunks it will be asked for, so
+//! the runtime should use a source block authors cannot bias.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod merkle;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Reputation of a provider that has never failed a challenge.
+pub const MAX_REPUTATION: u8 = 100;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*, traits::Randomness, CloneNoBound, EqNoBound, PartialEqNoBound,
+        RuntimeDebugNoBound,
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_homecdn::ProviderRegistry;
+    use sp_core::H256;
+    use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, Zero};
+    use sp_std::prelude::*;
+
+    pub type ChallengeId = u32;
+
+    /// A provider's claim to store some content.
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Commitment {
+        /// Number of chunks, i.e. leaves under the root.
+        pub chunks: u32,
+        /// Position in `CommitmentSlots`, for picking commitments at random.
+        pub slot: u32,
+    }
+
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(MaxChunks))]
+    #[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+    pub struct Challenge<AccountId, BlockNumber, MaxChunks: Get<u32>> {
+        pub provider: AccountId,
+        pub root: H256,
+        /// Chunk count of the commitment when challenged.
+        pub chunk_count: u32,
+        /// Chunk indices to prove, in order.
+        pub chunks: BoundedVec<u32, MaxChunks>,
+        /// The challenge fails when this block starts.
+        pub deadline: BlockNumber,
+    }
+
+    /// One challenged chunk and its path to the committed root.
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct ChunkProof<T: Config> {
+        pub chunk: BoundedVec<u8, T::MaxChunkSize>,
+        pub path: BoundedVec<H256, T::MaxProofDepth>,
+    }
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Balance of the provider bonds.
+        type Balance: Parameter + Member + AtLeast32BitUnsigned + Copy + MaxEncodedLen;
+
+        /// Who may commit content, and whose bond failures slash.
+        type Providers: ProviderRegistry<Self::AccountId, Self::Balance>;
+
+        /// Source of challenged commitments and chunk indices.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// May challenge a commitment outside the regular rounds.
+        type ChallengeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks between rounds of random challenges.
+        #[pallet::constant]
+        type ChallengeInterval: Get<BlockNumberFor<Self>>;
+
+        /// Commitments challenged per round.
+        #[pallet::constant]
+        type AutoChallenges: Get<u32>;
+
+        /// Chunks asked for per challenge.
+        #[pallet::constant]
+        type ChunksPerChallenge: Get<u32>;
+
+        /// Blocks a provider has to answer.
+        #[pallet::constant]
+        type ResponsePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Most challenges due in the same block.
+        #[pallet::constant]
+        type MaxChallengesPerBlock: Get<u32>;
+
+        /// Largest chunk, in bytes.
+        #[pallet::constant]
+        type MaxChunkSize: Get<u32>;
+
+        /// Longest Merkle path, which also caps a commitment at
+        /// `2^MaxProofDepth` chunks.
+        #[pallet::constant]
+        type MaxProofDepth: Get<u32>;
+
+        /// Reputation regained per passed challenge.
+        #[pallet::constant]
+        type ReputationReward: Get<u8>;
+
+        /// Reputation lost per failed challenge.
+        #[pallet::constant]
+        type ReputationPenalty: Get<u8>;
+
+        /// Failures below this reputation also slash the bond.
+        #[pallet::constant]
+        type SlashThreshold: Get<u8>;
+
+        /// Bond slashed per failure below `SlashThreshold`.
+        #[pallet::constant]
+        type FailureSlash: Get<Self::Balance>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    pub type ChallengeOf<T> = Challenge<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as Config>::ChunksPerChallenge,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn commitment)]
+    pub type Commitments<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, H256, Commitment>;
+
+    /// All commitments, densely numbered from zero.
+    #[pallet::storage]
+    pub type CommitmentSlots<T: Config> = StorageMap<_, Twox64Concat, u32, (T::AccountId, H256)>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn commitment_count)]
+    pub type CommitmentCount<T> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn challenge)]
+    pub type Challenges<T: Config> = StorageMap<_, Twox64Concat, ChallengeId, ChallengeOf<T>>;
+
+    #[pallet::storage]
+    pub type NextChallengeId<T> = StorageValue<_, ChallengeId, ValueQuery>;
+
+    /// Open challenges by the block they fail in.
+    #[pallet::storage]
+    pub type Deadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ChallengeId, T::MaxChallengesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Reputation of providers that have been challenged, see
+    /// [`Pallet::reputation`].
+    #[pallet::storage]
+    pub type Reputation<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u8>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `provider` committed to storing `chunks` chunks under `root`.
+        Committed { provider: T::AccountId, root: H256, chunks: u32 },
+        /// `provider` no longer stores the content under `root`.
+        Released { provider: T::AccountId, root: H256 },
+        /// `provider` must prove `chunks` of `root` before `deadline`.
+        ChallengeIssued {
+            id: ChallengeId,
+            provider: T::AccountId,
+            root: H256,
+            chunks: Vec<u32>,
+            deadline: BlockNumberFor<T>,
+        },
+        /// `provider` proved the challenged chunks.
+        ChallengePassed { id: ChallengeId, provider: T::AccountId, reputation: u8 },
+        /// `provider` did not answer in time; `slashed` was taken from its bond.
+        ChallengeFailed {
+            id: ChallengeId,
+            provider: T::AccountId,
+            reputation: u8,
+            slashed: T::Balance,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Only active HomeCDN providers can commit content.
+        NotActiveProvider,
+        /// The provider already committed to this root.
+        AlreadyCommitted,
+        /// The provider has not committed to this root.
+        NotCommitted,
+        /// Content must have at least one and at most `2^MaxProofDepth` chunks.
+        InvalidChunkCount,
+        /// No open challenge has this id.
+        UnknownChallenge,
+        /// The challenge is for another provider.
+        NotChallenged,
+        /// `MaxChallengesPerBlock` are already due at the deadline.
+        TooManyChallenges,
+        /// A chunk or path does not match the committed root.
+        InvalidProof,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let expired = Self::expire_challenges(n);
+            let mut weight = T::WeightInfo::expire_challenges(expired);
+            if (n % T::ChallengeInterval::get()).is_zero() {
+                Self::challenge_random(n);
+                weight.saturating_accrue(T::WeightInfo::issue_challenges());
+            }
+            weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Commit to storing the `chunks` chunks whose Merkle root is `root`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::commit())]
+        pub fn commit(origin: OriginFor<T>, root: H256, chunks: u32) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+            ensure!(T::Providers::is_active(&provider), Error::<T>::NotActiveProvider);
+            ensure!(
+                chunks > 0 && u64::from(chunks) <= 1u64 << T::MaxProofDepth::get().min(32),
+                Error::<T>::InvalidChunkCount
+            );
+            ensure!(!Commitments::<T>::contains_key(&provider, root), Error::<T>::AlreadyCommitted);
+
+            let slot = CommitmentCount::<T>::mutate(|count| {
+                let slot = *count;
+                *count = count.saturating_add(1);
+                slot
+            });
+            CommitmentSlots::<T>::insert(slot, (provider.clone(), root));
+            Commitments::<T>::insert(&provider, root, Commitment { chunks, slot });
+
+            Self::deposit_event(Event::Committed { provider, root, chunks });
+            Ok(())
+        }
+
+        /// Stop storing the content under `root`. Open challenges for it
+        /// must still be answered.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::release())]
+        pub fn release(origin: OriginFor<T>, root: H256) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+            let commitment =
+                Commitments::<T>::take(&provider, root).ok_or(Error::<T>::NotCommitted)?;
+
+            // Move the last commitment into the freed slot.
+            let last = CommitmentCount::<T>::mutate(|count| {
+                *count = count.saturating_sub(1);
+                *count
+            });
+            if let Some((moved_provider, moved_root)) = CommitmentSlots::<T>::take(last) {
+                if last != commitment.slot {
+                    Commitments::<T>::mutate(&moved_provider, moved_root, |moved| {
+                        if let Some(moved) = moved {
+                            moved.slot = commitment.slot;
+                        }
+                    });
+                    CommitmentSlots::<T>::insert(commitment.slot, (moved_provider, moved_root));
+                }
+            }
+
+            Self::deposit_event(Event::Released { provider, root });
+            Ok(())
+        }
+
+        /// Challenge `provider` to prove it still stores `root`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::challenge())]
+        pub fn challenge(
+            origin: OriginFor<T>,
+            provider: T::AccountId,
+            root: H256,
+        ) -> DispatchResult {
+            T::ChallengeOrigin::ensure_origin(origin)?;
+            let commitment =
+                Commitments::<T>::get(&provider, root).ok_or(Error::<T>::NotCommitted)?;
+
+            Self::issue(provider, root, commitment.chunks)?;
+            Ok(())
+        }
+
+        /// Answer challenge `id` with one proof per challenged chunk, in the
+        /// order they were asked for.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::respond())]
+        pub fn respond(
+            origin: OriginFor<T>,
+            id: ChallengeId,
+            proofs: BoundedVec<ChunkProof<T>, T::ChunksPerChallenge>,
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+            let challenge = Challenges::<T>::get(id).ok_or(Error::<T>::UnknownChallenge)?;
+            ensure!(challenge.provider == provider, Error::<T>::NotChallenged);
+            ensure!(proofs.len() == challenge.chunks.len(), Error::<T>::InvalidProof);
+            for (index, proof) in challenge.chunks.iter().zip(proofs.iter()) {
+                ensure!(
+                    merkle::verify(
+                        &challenge.root,
+                        challenge.chunk_count,
+                        *index,
+                        merkle::leaf(&proof.chunk),
+                        &proof.path,
+                    ),
+                    Error::<T>::InvalidProof
+                );
+            }
+
+            Challenges::<T>::remove(id);
+            Deadlines::<T>::mutate(challenge.deadline, |due| due.retain(|due| *due != id));
+            let reputation = Self::reputation(&provider).saturating_add(T::ReputationReward::get());
+            let reputation = reputation.min(MAX_REPUTATION);
+            Reputation::<T>::insert(&provider, reputation);
+
+            Self::deposit_event(Event::ChallengePassed { id, provider, reputation });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `who`'s reputation, [`MAX_REPUTATION`] until it fails a challenge.
+        pub fn reputation(who: &T::AccountId) -> u8 {
+            Reputation::<T>::get(who).unwrap_or(MAX_REPUTATION)
+        }
+
+        /// A random number drawn for `subject` and `nonce`.
+        fn random(subject: &[u8], nonce: impl Encode) -> u32 {
+            let (seed, _) = T::Randomness::random(subject);
+            let hash = blake2_256(&(seed, nonce).encode());
+            u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+        }
+
+        fn issue(provider: T::AccountId, root: H256, chunk_count: u32) -> DispatchResult {
+            let id = NextChallengeId::<T>::get();
+            let chunks: BoundedVec<u32, T::ChunksPerChallenge> = (0..T::ChunksPerChallenge::get())
+                .map(|i| Self::random(b"storage-challenges/chunk", (id, i)) % chunk_count)
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("one index per challenged chunk; qed");
+            let deadline =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ResponsePeriod::get());
+
+            Deadlines::<T>::try_append(deadline, id).map_err(|_| Error::<T>::TooManyChallenges)?;
+            NextChallengeId::<T>::put(id.wrapping_add(1));
+            Challenges::<T>::insert(
+                id,
+                Challenge {
+                    provider: provider.clone(),
+                    root,
+                    chunk_count,
+                    chunks: chunks.clone(),
+                    deadline,
+                },
+            );
+
+            Self::deposit_event(Event::ChallengeIssued {
+                id,
+                provider,
+                root,
+                chunks: chunks.into_inner(),
+                deadline,
+            });
+            Ok(())
+        }
+
+        /// Fail the challenges still open at their deadline `n`, returning
+        /// how many there were.
+        pub(crate) fn expire_challenges(n: BlockNumberFor<T>) -> u32 {
+            let expired = Deadlines::<T>::take(n);
+            for id in &expired {
+                if let Some(challenge) = Challenges::<T>::take(id) {
+                    Self::fail(*id, challenge);
+                }
+            }
+            expired.len() as u32
+        }
+
+        /// Challenge `AutoChallenges` random commitments in block `n`. Draws
+        /// that land on a retired or under-bonded provider are skipped.
+        pub(crate) fn challenge_random(n: BlockNumberFor<T>) {
+            let count = CommitmentCount::<T>::get();
+            if count == 0 {
+                return
+            }
+            for i in 0..T::AutoChallenges::get() {
+                let slot = Self::random(b"storage-challenges/slot", (n, i)) % count;
+                let Some((provider, root)) = CommitmentSlots::<T>::get(slot) else { continue };
+                if !T::Providers::is_active(&provider) {
+                    continue
+                }
+                let Some(commitment) = Commitments::<T>::get(&provider, root) else { continue };
+                if Self::issue(provider, root, commitment.chunks).is_err() {
+                    break;
+                }
+            }
+        }
+
+        fn fail(id: ChallengeId, challenge: ChallengeOf<T>) {
+            let provider = challenge.provider;
+            let reputation =
+                Self::reputation(&provider).saturating_sub(T::ReputationPenalty::get());
+            Reputation::<T>::insert(&provider, reputation);
+            let slashed = if reputation < T::SlashThreshold::get() {
+                T::Providers::slash(&provider, T::FailureSlash::get())
+            } else {
+                Zero::zero()
+            };
+
+            Self::deposit_event(Event::ChallengeFailed { id, provider, reputation, slashed });
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! The Merkle tree content roots are committed as.
+//!
+//! Leaves are the BLAKE2-256 hashes of the content's chunks, in order. Each
+//! level pairs neighbouring nodes left to right; an odd node out at the end of
+//! a level is carried up unchanged. Leaf and node hashes are prefixed
+//! differently so a node can never be passed off as a chunk.
+//!
+//! Providers use [`root`] and [`proof`] off chain; the pallet only runs
+//! [`verify`].
+
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_std::prelude::*;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// The leaf a chunk is committed as.
+pub fn leaf(chunk: &[u8]) -> H256 {
+    let mut data = Vec::with_capacity(1 + chunk.len());
+    data.push(LEAF_PREFIX);
+    data.extend_from_slice(chunk);
+    blake2_256(&data).into()
+}
+
+fn node(left: &H256, right: &H256) -> H256 {
+    let mut data = [0u8; 65];
+    data[0] = NODE_PREFIX;
+    data[1..33].copy_from_slice(left.as_bytes());
+    data[33..].copy_from_slice(right.as_bytes());
+    blake2_256(&data).into()
+}
+
+fn next_level(level: &[H256]) -> Vec<H256> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node(left, right),
+            [odd] => *odd,
+            _ => unreachable!("chunks(2) yields one or two nodes"),
+        })
+        .collect()
+}
+
+/// Root over `leaves`; zero for no leaves.
+pub fn root(leaves: &[H256]) -> H256 {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied().unwrap_or_default()
+}
+
+/// Sibling hashes from leaf `index` up to the root, or `None` if `index` is
+/// out of range.
+pub fn proof(leaves: &[H256], mut index: usize) -> Option<Vec<H256>> {
+    if index >= leaves.len() {
+        return None
+    }
+    let mut path = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            path.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(path)
+}
+
+/// Whether `leaf` is leaf `index` of the `leaf_count` leaves under `root`.
+pub fn verify(root: &H256, leaf_count: u32, index: u32, leaf: H256, path: &[H256]) -> bool {
+    if index >= leaf_count {
+        return false
+    }
+    let (mut hash, mut index, mut width) = (leaf, index, leaf_count);
+    let mut siblings = path.iter();
+    while width > 1 {
+        if index ^ 1 < width {
+            let Some(sibling) = siblings.next() else { return false };
+            hash = if index % 2 == 0 { node(&hash, sibling) } else { node(sibling, &hash) };
+        }
+        index /= 2;
+        width = (width + 1) / 2;
+    }
+    siblings.next().is_none() && hash == *root
+}
@@ -0,0 +1,101 @@
+//! Weights for `pallet_storage_challenges`, not benchmarked yet.
+//!
+//! Costs follow the commitment and challenge entries each call touches,
+//! plus hashing the submitted proof.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_storage_challenges`.
+pub trait WeightInfo {
+    fn commit() -> Weight;
+    fn release() -> Weight;
+    fn challenge() -> Weight;
+    fn respond() -> Weight;
+    fn expire_challenges(c: u32) -> Weight;
+    fn issue_challenges() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn commit() -> Weight {
+        Weight::from_parts(24_000_000, 4617)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn release() -> Weight {
+        Weight::from_parts(28_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    fn challenge() -> Weight {
+        Weight::from_parts(30_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn respond() -> Weight {
+        Weight::from_parts(180_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn expire_challenges(c: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1517)
+            .saturating_add(Weight::from_parts(52_000_000, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(4_u64.saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(4_u64.saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 4617).saturating_mul(c.into()))
+    }
+    fn issue_challenges() -> Weight {
+        Weight::from_parts(102_000_000, 17817)
+            .saturating_add(T::DbWeight::get().reads(16))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn commit() -> Weight {
+        Weight::from_parts(24_000_000, 4617)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn release() -> Weight {
+        Weight::from_parts(28_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn challenge() -> Weight {
+        Weight::from_parts(30_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn respond() -> Weight {
+        Weight::from_parts(180_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn expire_challenges(c: u32) -> Weight {
+        Weight::from_parts(3_000_000, 1517)
+            .saturating_add(Weight::from_parts(52_000_000, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(4_u64.saturating_mul(c.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes(4_u64.saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 4617).saturating_mul(c.into()))
+    }
+    fn issue_challenges() -> Weight {
+        Weight::from_parts(102_000_000, 17817)
+            .saturating_add(RocksDbWeight::get().reads(16))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+}
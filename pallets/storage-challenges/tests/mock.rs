@@ -0,0 +1,159 @@
+use codec::Encode;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, ConstU8, GenesisBuild, Hooks, Randomness},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+};
+
+use pallet_storage_challenges as storage_challenges;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        HomeCdn: pallet_homecdn,
+        StorageChallenges: storage_challenges,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = u64;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ConstU32<10>;
+    type MaxReserves = ConstU32<10>;
+    type ReserveIdentifier = [u8; 8];
+    type HoldIdentifier = ();
+    type FreezeIdentifier = ();
+    type MaxHolds = ConstU32<0>;
+    type MaxFreezes = ConstU32<0>;
+}
+
+/// Smallest bond a provider is routed with in the mock.
+pub const MIN_BOND: u64 = 100;
+/// Blocks a retired bond stays slashable in the mock.
+pub const RETIREMENT: u64 = 10;
+
+impl pallet_homecdn::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    // Slashed bonds are burned.
+    type OnSlash = ();
+    type SlashOrigin = EnsureRoot<u64>;
+    type MinBond = ConstU64<MIN_BOND>;
+    type RetirementPeriod = ConstU64<RETIREMENT>;
+    type MaxProviders = ConstU32<8>;
+    type MaxRegionLength = ConstU32<8>;
+    type MaxEndpointLength = ConstU32<32>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    /// Mixed into every random value; change it to draw different ones.
+    pub static RandomSeed: u64 = 0;
+}
+
+/// Deterministic stand-in for the runtime's randomness source.
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let seed =
+            (subject, RandomSeed::get(), System::block_number()).using_encoded(BlakeTwo256::hash);
+        (seed, System::block_number())
+    }
+}
+
+/// Blocks between rounds of random challenges in the mock.
+pub const INTERVAL: u64 = 50;
+/// Blocks a provider has to answer in the mock.
+pub const RESPONSE_PERIOD: u64 = 5;
+/// Chunks asked for per challenge in the mock.
+pub const CHUNKS_PER_CHALLENGE: u32 = 3;
+/// Bond slashed per failure below the threshold in the mock.
+pub const FAILURE_SLASH: u64 = 30;
+
+impl storage_challenges::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type Providers = HomeCdn;
+    type Randomness = TestRandomness;
+    type ChallengeOrigin = EnsureRoot<u64>;
+    type ChallengeInterval = ConstU64<INTERVAL>;
+    type AutoChallenges = ConstU32<2>;
+    type ChunksPerChallenge = ConstU32<CHUNKS_PER_CHALLENGE>;
+    type ResponsePeriod = ConstU64<RESPONSE_PERIOD>;
+    type MaxChallengesPerBlock = ConstU32<4>;
+    type MaxChunkSize = ConstU32<64>;
+    type MaxProofDepth = ConstU32<8>;
+    type ReputationReward = ConstU8<5>;
+    type ReputationPenalty = ConstU8<20>;
+    type SlashThreshold = ConstU8<70>;
+    type FailureSlash = ConstU64<FAILURE_SLASH>;
+    type WeightInfo = ();
+}
+
+/// Balance of accounts 1 to 4 at genesis.
+pub const ENDOWMENT: u64 = 1_000;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=4).map(|who| (who, ENDOWMENT)).collect(),
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        StorageChallenges::on_initialize(next);
+    }
+}
@@ -0,0 +1,325 @@
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use pallet_storage_challenges::{
+    merkle, ChunkProof, CommitmentSlots, Deadlines, Error, Event, NextChallengeId, MAX_REPUTATION,
+};
+use sp_core::H256;
+
+/// `count` chunks of distinct content, their leaves and root.
+fn content(count: u32) -> (Vec<Vec<u8>>, Vec<H256>, H256) {
+    let chunks: Vec<Vec<u8>> = (0..count).map(|i| format!("chunk {i}").into_bytes()).collect();
+    let leaves: Vec<H256> = chunks.iter().map(|chunk| merkle::leaf(chunk)).collect();
+    let root = merkle::root(&leaves);
+    (chunks, leaves, root)
+}
+
+fn register_provider(who: u64, bond: u64) {
+    assert_ok!(HomeCdn::register(
+        RuntimeOrigin::signed(who),
+        1,
+        1,
+        b"eu".to_vec(),
+        b"https://cdn".to_vec(),
+        bond
+    ));
+}
+
+fn commit(who: u64, root: H256, chunks: u32) {
+    assert_ok!(StorageChallenges::commit(RuntimeOrigin::signed(who), root, chunks));
+}
+
+fn proofs(
+    chunks: &[Vec<u8>],
+    leaves: &[H256],
+    indices: &[u32],
+) -> BoundedVec<ChunkProof<Test>, <Test as pallet_storage_challenges::Config>::ChunksPerChallenge> {
+    indices
+        .iter()
+        .map(|index| ChunkProof {
+            chunk: chunks[*index as usize].clone().try_into().unwrap(),
+            path: merkle::proof(leaves, *index as usize).unwrap().try_into().unwrap(),
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn challenged_chunks(id: u32) -> Vec<u32> {
+    StorageChallenges::challenge(id).expect("challenge is open").chunks.into_inner()
+}
+
+#[test]
+fn merkle_proofs_verify_every_leaf() {
+    for count in 1..=9u32 {
+        let (_, leaves, root) = content(count);
+        for index in 0..count {
+            let leaf = leaves[index as usize];
+            let path = merkle::proof(&leaves, index as usize).unwrap();
+            assert!(merkle::verify(&root, count, index, leaf, &path), "{count} leaves, #{index}");
+
+            assert!(!merkle::verify(&root, count, index, merkle::leaf(b"forged"), &path));
+            let mut longer = path.clone();
+            longer.push(root);
+            assert!(!merkle::verify(&root, count, index, leaf, &longer));
+            if count > 1 {
+                assert!(!merkle::verify(&root, count, (index + 1) % count, leaf, &path));
+            }
+        }
+        assert_eq!(merkle::proof(&leaves, count as usize), None);
+        assert!(!merkle::verify(&root, count, count, leaves[0], &[]));
+    }
+    // A chunk made of two child hashes does not hash to their node.
+    let (_, leaves, _) = content(2);
+    let children = [leaves[0].as_bytes(), leaves[1].as_bytes()].concat();
+    assert_ne!(merkle::leaf(&children), merkle::root(&leaves));
+}
+
+#[test]
+fn provider_passes_a_challenge_with_merkle_proofs() {
+    new_test_ext().execute_with(|| {
+        let (chunks, leaves, root) = content(7);
+        register_provider(1, MIN_BOND);
+        commit(1, root, 7);
+        System::assert_last_event(Event::Committed { provider: 1, root, chunks: 7 }.into());
+
+        assert_ok!(StorageChallenges::challenge(RuntimeOrigin::root(), 1, root));
+        let indices = challenged_chunks(0);
+        let deadline = 1 + RESPONSE_PERIOD;
+        System::assert_last_event(
+            Event::ChallengeIssued { id: 0, provider: 1, root, chunks: indices.clone(), deadline }
+                .into(),
+        );
+        assert_eq!(indices.len(), CHUNKS_PER_CHALLENGE as usize);
+        assert!(indices.iter().all(|index| *index < 7));
+
+        run_to_block(deadline - 1);
+        assert_ok!(StorageChallenges::respond(
+            RuntimeOrigin::signed(1),
+            0,
+            proofs(&chunks, &leaves, &indices)
+        ));
+
+        System::assert_last_event(
+            Event::ChallengePassed { id: 0, provider: 1, reputation: MAX_REPUTATION }.into(),
+        );
+        assert_eq!(StorageChallenges::challenge(0), None);
+        assert!(Deadlines::<Test>::get(deadline).is_empty());
+        run_to_block(deadline);
+        assert_eq!(StorageChallenges::reputation(&1), MAX_REPUTATION);
+    });
+}
+
+#[test]
+fn wrong_or_missing_proofs_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let (chunks, leaves, root) = content(64);
+        register_provider(1, MIN_BOND);
+        commit(1, root, 64);
+        assert_ok!(StorageChallenges::challenge(RuntimeOrigin::root(), 1, root));
+        let indices = challenged_chunks(0);
+
+        let mut forged = proofs(&chunks, &leaves, &indices);
+        forged[0].chunk = b"not the chunk".to_vec().try_into().unwrap();
+        assert_noop!(
+            StorageChallenges::respond(RuntimeOrigin::signed(1), 0, forged),
+            Error::<Test>::InvalidProof
+        );
+
+        // Proofs for other chunks than the challenged ones.
+        let others: Vec<u32> = indices.iter().map(|index| (index + 1) % 64).collect();
+        assert_noop!(
+            StorageChallenges::respond(
+                RuntimeOrigin::signed(1),
+                0,
+                proofs(&chunks, &leaves, &others)
+            ),
+            Error::<Test>::InvalidProof
+        );
+
+        assert_noop!(
+            StorageChallenges::respond(
+                RuntimeOrigin::signed(1),
+                0,
+                proofs(&chunks, &leaves, &indices[1..])
+            ),
+            Error::<Test>::InvalidProof
+        );
+        assert_noop!(
+            StorageChallenges::respond(
+                RuntimeOrigin::signed(2),
+                0,
+                proofs(&chunks, &leaves, &indices)
+            ),
+            Error::<Test>::NotChallenged
+        );
+        assert_noop!(
+            StorageChallenges::respond(
+                RuntimeOrigin::signed(1),
+                1,
+                proofs(&chunks, &leaves, &indices)
+            ),
+            Error::<Test>::UnknownChallenge
+        );
+
+        // Too late once the deadline block has started.
+        run_to_block(1 + RESPONSE_PERIOD);
+        assert_noop!(
+            StorageChallenges::respond(
+                RuntimeOrigin::signed(1),
+                0,
+                proofs(&chunks, &leaves, &indices)
+            ),
+            Error::<Test>::UnknownChallenge
+        );
+    });
+}
+
+#[test]
+fn failures_cost_reputation_then_bond() {
+    new_test_ext().execute_with(|| {
+        let (chunks, leaves, root) = content(16);
+        register_provider(1, 150);
+        commit(1, root, 16);
+        let fail_next = || {
+            let id = NextChallengeId::<Test>::get();
+            assert_ok!(StorageChallenges::challenge(RuntimeOrigin::root(), 1, root));
+            run_to_block(System::block_number() + RESPONSE_PERIOD);
+            id
+        };
+
+        let id = fail_next();
+        System::assert_has_event(
+            Event::ChallengeFailed { id, provider: 1, reputation: 80, slashed: 0 }.into(),
+        );
+        assert_eq!(HomeCdn::provider(1).unwrap().bond, 150);
+
+        // Below the threshold every failure slashes.
+        let id = fail_next();
+        System::assert_has_event(
+            Event::ChallengeFailed { id, provider: 1, reputation: 60, slashed: FAILURE_SLASH }
+                .into(),
+        );
+        assert_eq!(HomeCdn::provider(1).unwrap().bond, 150 - FAILURE_SLASH);
+        assert_eq!(Balances::reserved_balance(1), 150 - FAILURE_SLASH);
+
+        // Passing earns reputation back slowly.
+        assert_ok!(StorageChallenges::challenge(RuntimeOrigin::root(), 1, root));
+        let id = NextChallengeId::<Test>::get() - 1;
+        let indices = challenged_chunks(id);
+        assert_ok!(StorageChallenges::respond(
+            RuntimeOrigin::signed(1),
+            id,
+            proofs(&chunks, &leaves, &indices)
+        ));
+        assert_eq!(StorageChallenges::reputation(&1), 65);
+
+        // Once the bond is below `MinBond` the provider is no longer routed.
+        fail_next();
+        assert!(!<HomeCdn as pallet_homecdn::ProviderRegistry<_, _>>::is_active(&1));
+    });
+}
+
+#[test]
+fn rounds_challenge_random_commitments_and_chunks() {
+    let drawn = |seed: u64| {
+        RandomSeed::set(seed);
+        new_test_ext().execute_with(|| {
+            let (_, _, first) = content(64);
+            let (_, _, second) = content(32);
+            register_provider(1, MIN_BOND);
+            register_provider(2, MIN_BOND);
+            commit(1, first, 64);
+            commit(2, second, 32);
+
+            run_to_block(INTERVAL - 1);
+            assert_eq!(NextChallengeId::<Test>::get(), 0);
+            run_to_block(INTERVAL);
+            assert_eq!(NextChallengeId::<Test>::get(), 2);
+
+            (0..2)
+                .map(|id| {
+                    let challenge = StorageChallenges::challenge(id).unwrap();
+                    let committed =
+                        if challenge.provider == 1 { (first, 64) } else { (second, 32) };
+                    assert_eq!((challenge.root, challenge.chunk_count), committed);
+                    assert_eq!(challenge.deadline, INTERVAL + RESPONSE_PERIOD);
+                    assert!(challenge.chunks.iter().all(|index| *index < challenge.chunk_count));
+                    challenge.chunks.into_inner()
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    // The same randomness draws the same chunks, different randomness others.
+    assert_eq!(drawn(0), drawn(0));
+    assert_ne!(drawn(0), drawn(1));
+}
+
+#[test]
+fn rounds_skip_commitments_of_providers_that_left() {
+    let mut issued = 0;
+    for seed in 0..8 {
+        RandomSeed::set(seed);
+        new_test_ext().execute_with(|| {
+            let (_, _, first) = content(4);
+            let (_, _, second) = content(5);
+            register_provider(1, MIN_BOND);
+            register_provider(2, MIN_BOND);
+            commit(1, first, 4);
+            commit(2, second, 5);
+            assert_ok!(HomeCdn::retire(RuntimeOrigin::signed(2)));
+
+            run_to_block(INTERVAL);
+            for id in 0..NextChallengeId::<Test>::get() {
+                assert_eq!(StorageChallenges::challenge(id).unwrap().provider, 1);
+            }
+            issued += NextChallengeId::<Test>::get();
+        });
+    }
+    assert!(issued > 0);
+}
+
+#[test]
+fn only_active_providers_commit_and_release_frees_the_slot() {
+    new_test_ext().execute_with(|| {
+        let (_, _, first) = content(4);
+        let (_, _, second) = content(5);
+        assert_noop!(
+            StorageChallenges::commit(RuntimeOrigin::signed(1), first, 4),
+            Error::<Test>::NotActiveProvider
+        );
+
+        register_provider(1, MIN_BOND);
+        assert_noop!(
+            StorageChallenges::commit(RuntimeOrigin::signed(1), first, 0),
+            Error::<Test>::InvalidChunkCount
+        );
+        assert_noop!(
+            StorageChallenges::commit(RuntimeOrigin::signed(1), first, 257),
+            Error::<Test>::InvalidChunkCount
+        );
+        commit(1, first, 4);
+        commit(1, second, 5);
+        assert_noop!(
+            StorageChallenges::commit(RuntimeOrigin::signed(1), first, 4),
+            Error::<Test>::AlreadyCommitted
+        );
+
+        assert_ok!(StorageChallenges::release(RuntimeOrigin::signed(1), first));
+        System::assert_last_event(Event::Released { provider: 1, root: first }.into());
+        assert_eq!(StorageChallenges::commitment_count(), 1);
+        assert_eq!(CommitmentSlots::<Test>::get(0), Some((1, second)));
+        assert_eq!(StorageChallenges::commitment(1, second).unwrap().slot, 0);
+        assert_eq!(CommitmentSlots::<Test>::get(1), None);
+        assert_noop!(
+            StorageChallenges::release(RuntimeOrigin::signed(1), first),
+            Error::<Test>::NotCommitted
+        );
+
+        assert_ok!(HomeCdn::retire(RuntimeOrigin::signed(1)));
+        assert_noop!(
+            StorageChallenges::commit(RuntimeOrigin::signed(1), first, 4),
+            Error::<Test>::NotActiveProvider
+        );
+    });
+}
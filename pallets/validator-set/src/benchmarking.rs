@@ -0,0 +1,44 @@
+//! Benchmarks for the validator-set pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// `count` distinct validators, sorted like `Validators` keeps them.
+fn validators<T: Config>(count: u32) -> Vec<T::ValidatorId>
+where
+    T::ValidatorId: From<T::AccountId>,
+{
+    let mut validators: Vec<T::ValidatorId> =
+        (0..count).map(|i| account::<T::AccountId>("validator", i, SEED).into()).collect();
+    validators.sort();
+    validators
+}
+
+benchmarks! {
+    where_clause { where T::ValidatorId: From<T::AccountId> }
+
+    // Worst case: one slot left and the new validator sorts first, so every
+    // existing entry shifts.
+    add_validator {
+        let mut existing = validators::<T>(T::MaxValidators::get());
+        let new = existing.remove(0);
+        Validators::<T>::put(BoundedVec::truncate_from(existing));
+    }: _(RawOrigin::Root, new.clone())
+    verify {
+        assert_eq!(Validators::<T>::get().first(), Some(&new));
+    }
+
+    // Worst case: a full set and the first validator leaves.
+    remove_validator {
+        let existing = validators::<T>(T::MaxValidators::get());
+        let removed = existing[0].clone();
+        Validators::<T>::put(BoundedVec::truncate_from(existing));
+    }: _(RawOrigin::Root, removed.clone())
+    verify {
+        assert!(Validators::<T>::get().binary_search(&removed).is_err());
+    }
+}
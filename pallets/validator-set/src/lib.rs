@@ -0,0 +1,214 @@
+//! Validator set management for BlessChain.
+//!
+//! Keeps the list of accounts that author (Aura) and finalize (GRANDPA) blocks.
+//! Changes requested through `add_validator` / `remove_validator` are picked up by
+//! `pallet_session` at the next session rotation, which in turn schedules the new
+//! Aura authorities and a GRANDPA authority set change.
+//!
+//! `Validators` is kept sorted, so membership checks are a binary search and
+//! every node derives the same authority order.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use sp_std::prelude::*;
+
+pub const LOG_TARGET: &str = "runtime::validator-set";
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Identifier used by `pallet_session` for a validator.
+        type ValidatorId: Member + Parameter + MaybeSerializeDeserialize + MaxEncodedLen + Ord;
+
+        /// Origin allowed to add or remove validators.
+        type AddRemoveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Upper bound on the number of validators.
+        #[pallet::constant]
+        type MaxValidators: Get<u32>;
+
+        /// The set can never shrink below this many validators.
+        #[pallet::constant]
+        type MinValidators: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Validators that will be handed to the session pallet at the next rotation,
+    /// sorted and without duplicates.
+    #[pallet::storage]
+    #[pallet::getter(fn validators)]
+    pub type Validators<T: Config> =
+        StorageValue<_, BoundedVec<T::ValidatorId, T::MaxValidators>, ValueQuery>;
+
+    /// Set when `Validators` changed since the last session rotation.
+    #[pallet::storage]
+    pub type QueuedChange<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub initial_validators: Vec<T::ValidatorId>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { initial_validators: Vec::new() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            let mut validators = self.initial_validators.clone();
+            validators.sort();
+            validators.dedup();
+            let validators: BoundedVec<_, T::MaxValidators> =
+                validators.try_into().expect("too many initial validators");
+            assert!(
+                validators.len() as u32 >= T::MinValidators::get(),
+                "not enough initial validators"
+            );
+            Validators::<T>::put(validators);
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A validator was added; it becomes active after the next session rotation.
+        ValidatorAdded { validator: T::ValidatorId },
+        /// A validator was removed; it stops authoring after the next session rotation.
+        ValidatorRemoved { validator: T::ValidatorId },
+        /// A new validator set was handed to the session pallet.
+        NewSetQueued { session_index: u32, validators: u32 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account is already a validator.
+        AlreadyValidator,
+        /// The account is not a validator.
+        NotValidator,
+        /// `MaxValidators` reached.
+        TooManyValidators,
+        /// Removing the validator would drop the set below `MinValidators`.
+        TooFewValidators,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add a validator. Takes effect at the next session rotation.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_validator())]
+        pub fn add_validator(origin: OriginFor<T>, validator: T::ValidatorId) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+
+            Validators::<T>::try_mutate(|validators| -> DispatchResult {
+                let index = validators
+                    .binary_search(&validator)
+                    .err()
+                    .ok_or(Error::<T>::AlreadyValidator)?;
+                validators
+                    .try_insert(index, validator.clone())
+                    .map_err(|_| Error::<T>::TooManyValidators)?;
+                Ok(())
+            })?;
+            QueuedChange::<T>::put(true);
+
+            Self::deposit_event(Event::ValidatorAdded { validator });
+            Ok(())
+        }
+
+        /// Remove a validator. Takes effect at the next session rotation.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_validator())]
+        pub fn remove_validator(origin: OriginFor<T>, validator: T::ValidatorId) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+
+            Validators::<T>::try_mutate(|validators| -> DispatchResult {
+                let index =
+                    validators.binary_search(&validator).map_err(|_| Error::<T>::NotValidator)?;
+                ensure!(
+                    validators.len() as u32 > T::MinValidators::get(),
+                    Error::<T>::TooFewValidators
+                );
+                validators.remove(index);
+                Ok(())
+            })?;
+            QueuedChange::<T>::put(true);
+
+            Self::deposit_event(Event::ValidatorRemoved { validator });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Invariants of `Validators`, checked by try-runtime after every block.
+    pub fn do_try_state() -> Result<(), &'static str> {
+        let validators = Validators::<T>::get();
+        if !validators.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Err("validators are not sorted and unique")
+        }
+        if (validators.len() as u32) < T::MinValidators::get() {
+            return Err("fewer validators than MinValidators")
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
+    fn new_session(new_index: u32) -> Option<Vec<T::ValidatorId>> {
+        if !QueuedChange::<T>::take() {
+            return None
+        }
+
+        let validators = Validators::<T>::get().into_inner();
+        log::info!(
+            target: LOG_TARGET,
+            "queueing {} validators for session {}",
+            validators.len(),
+            new_index,
+        );
+        Self::deposit_event(Event::NewSetQueued {
+            session_index: new_index,
+            validators: validators.len() as u32,
+        });
+        Some(validators)
+    }
+
+    fn new_session_genesis(_new_index: u32) -> Option<Vec<T::ValidatorId>> {
+        Some(Validators::<T>::get().into_inner())
+    }
+
+    fn end_session(_end_index: u32) {}
+
+    fn start_session(_start_index: u32) {}
+}
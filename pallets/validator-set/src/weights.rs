@@ -0,0 +1,49 @@
+//! Weights for `pallet_validator_set`, not benchmarked yet.
+//!
+//! Each call reads the whole validator set (at most `MaxValidators`),
+//! writes it back and flags a queued change.
+//!
+//! The numbers are placeholders until `scripts/benchmark-weights.sh` is run on
+//! the reference machine, which overwrites this file.
+
+use core::marker::PhantomData;
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+/// Weight functions needed for `pallet_validator_set`.
+pub trait WeightInfo {
+    fn add_validator() -> Weight;
+    fn remove_validator() -> Weight;
+}
+
+/// Weights charged by the runtime, priced with its `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn add_validator() -> Weight {
+        Weight::from_parts(18_500_000, 2_534)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn remove_validator() -> Weight {
+        Weight::from_parts(17_900_000, 2_534)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+/// The same weights priced with RocksDB defaults, for tests.
+impl WeightInfo for () {
+    fn add_validator() -> Weight {
+        Weight::from_parts(18_500_000, 2_534)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn remove_validator() -> Weight {
+        Weight::from_parts(17_900_000, 2_534)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+}
@@ -0,0 +1,73 @@
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_session::SessionManager;
+use pallet_validator_set::{Error, Event};
+use sp_runtime::DispatchError;
+
+#[test]
+fn genesis_validators_are_used_for_first_sessions() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(ValidatorSet::validators().into_inner(), vec![1, 2]);
+        assert_eq!(ValidatorSet::new_session_genesis(0), Some(vec![1, 2]));
+        // Nothing changed since genesis, keep the current set.
+        assert_eq!(ValidatorSet::new_session(2), None);
+    });
+}
+
+#[test]
+fn add_validator_is_queued_for_next_session() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 3));
+        System::assert_last_event(Event::ValidatorAdded { validator: 3 }.into());
+
+        assert_eq!(ValidatorSet::new_session(2), Some(vec![1, 2, 3]));
+        System::assert_last_event(Event::NewSetQueued { session_index: 2, validators: 3 }.into());
+        // The change is only handed over once.
+        assert_eq!(ValidatorSet::new_session(3), None);
+    });
+}
+
+#[test]
+fn add_validator_requires_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::signed(1), 3),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn add_validator_rejects_duplicates_and_overflow() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 1),
+            Error::<Test>::AlreadyValidator
+        );
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 3));
+        assert_eq!(ValidatorSet::validators().into_inner(), vec![1, 2, 3, 4]);
+        assert_eq!(ValidatorSet::do_try_state(), Ok(()));
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 5),
+            Error::<Test>::TooManyValidators
+        );
+    });
+}
+
+#[test]
+fn remove_validator_respects_minimum() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::remove_validator(RuntimeOrigin::root(), 9),
+            Error::<Test>::NotValidator
+        );
+        assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::ValidatorRemoved { validator: 1 }.into());
+        assert_noop!(
+            ValidatorSet::remove_validator(RuntimeOrigin::root(), 2),
+            Error::<Test>::TooFewValidators
+        );
+        assert_eq!(ValidatorSet::new_session(2), Some(vec![2]));
+    });
+}
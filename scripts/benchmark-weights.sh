@@ -0,0 +1,63 @@
+#!/usr/bin/env bash
+# Regenerate every weight file consumed by the runtime.
+#
+# Run on the reference machine (see README, "Benchmarks and Weights") with
+# nothing else loading the CPU or disk:
+#
+#   ./scripts/benchmark-weights.sh [path/to/blesschain-node]
+#
+# Without an argument the node is built with `--features runtime-benchmarks`.
+#
+# The BlessChain pallet files declare `SubstrateWeight<T>`, which the runtime
+# already uses. To charge FRAME pallets with the generated files instead of
+# their upstream weights, declare them in blesschain-runtime/src/weights.rs and
+# point the runtime's `type WeightInfo` at them before committing.
+
+set -euo pipefail
+
+ROOT="$(cd "$(dirname "$0")/.." && pwd)"
+NODE="${1:-}"
+
+if [ -z "$NODE" ]; then
+    cargo build --release -p blesschain-node --features runtime-benchmarks \
+        --manifest-path "$ROOT/Cargo.toml"
+    TARGET_DIR="$(cargo metadata --format-version 1 --no-deps \
+        --manifest-path "$ROOT/Cargo.toml" | jq -r .target_directory)"
+    NODE="$TARGET_DIR/release/blesschain-node"
+fi
+
+benchmark() {
+    "$NODE" benchmark pallet \
+        --chain dev \
+        --pallet "$1" \
+        --extrinsic '*' \
+        --steps 50 \
+        --repeat 20 \
+        --execution wasm \
+        --wasm-execution compiled \
+        --heap-pages 4096 \
+        --output "$2" \
+        "${@:3}"
+}
+
+# FRAME pallets: the runtime implements their `WeightInfo` traits.
+benchmark pallet_balances "$ROOT/blesschain-runtime/src/weights/pallet_balances.rs"
+benchmark pallet_timestamp "$ROOT/blesschain-runtime/src/weights/pallet_timestamp.rs"
+
+# BlessChain pallets: the pallet declares `WeightInfo`, `SubstrateWeight` and `()`.
+for pallet in validator-set metadata call-filter contributor-points homecdn \
+    storage-challenges payment-channels compute-jobs randomness fee-assets content-nfts; do
+    benchmark "pallet_${pallet//-/_}" "$ROOT/pallets/$pallet/src/weights.rs" \
+        --template "$ROOT/.maintain/frame-weight-template.hbs"
+done
+
+# Block and extrinsic base weights behind `RuntimeBlockWeights`.
+"$NODE" benchmark overhead \
+    --chain dev \
+    --execution wasm \
+    --wasm-execution compiled \
+    --weight-path "$ROOT/blesschain-runtime/src/weights/"
+
+# `RocksDbWeight` needs a synced database, so it is left to the operator:
+#   blesschain-node benchmark storage --chain <chain> --state-version 1 \
+#       --weight-path blesschain-runtime/src/weights/